@@ -19,12 +19,20 @@ use walkdir::WalkDir;
 use zip::write::SimpleFileOptions;
 use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
+use crate::commands::codex_images::codex_images_dir;
+use crate::commands::scene::read_scene_content;
 use crate::models::{
     ChatMessage, ChatThread, CodexEntry, CodexEntryTag, CodexRelation, CodexRelationType, CodexTag,
     CodexTemplate, ProjectMeta, SceneCodexLink, SceneNote, Series, Snippet, StructureNode,
 };
-use crate::storage::{open_app_db, with_transaction};
-use crate::utils::{atomic_write_bytes, get_app_dir, slugify, validate_no_null_bytes};
+use crate::storage::{
+    open_app_db, project_setting_set, series_setting_get, series_setting_set, with_transaction,
+    MIN_PACKAGE_SCHEMA_VERSION,
+};
+use crate::utils::{
+    atomic_write_bytes, count_words, extract_paragraphs, get_app_dir, resolve_export_path,
+    resolve_output_path, slugify, validate_no_null_bytes, MAX_READ_FILE_BYTES,
+};
 
 const PACKAGE_EXTENSION: &str = "baa";
 const MANIFEST_VERSION: i32 = 1;
@@ -109,6 +117,9 @@ pub struct BackupPackageInfo {
 pub struct BackupImportOptions {
     pub target_series_id: Option<String>,
     pub create_series_title: Option<String>,
+    /// Overrides the book number stored in a novel package
+    #[serde(default)]
+    pub series_index: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -122,6 +133,17 @@ pub struct BackupImportResult {
     pub requires_relaunch: bool,
 }
 
+/// Outcome for one package of an `import_backups` batch
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchImportItem {
+    pub path: String,
+    pub success: bool,
+    pub project_id: Option<String>,
+    pub series_index: Option<String>,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 struct ProjectSeed {
     id: String,
@@ -343,7 +365,7 @@ fn series_by_id(series_id: &str) -> Result<Series, String> {
         .ok_or_else(|| "Series not found".to_string())
 }
 
-fn create_temp_dir(label: &str) -> Result<PathBuf, String> {
+pub(crate) fn create_temp_dir(label: &str) -> Result<PathBuf, String> {
     let app_dir = get_app_dir()?;
     let root = app_dir.join(".meta").join("tmp");
     fs::create_dir_all(&root).map_err(|e| e.to_string())?;
@@ -568,6 +590,8 @@ fn prune_series_package_db(db_path: &Path, series_id: &str) -> Result<(), String
     delete_not_in_column(&conn, "scene_metadata", "project_id", &project_ids)?;
     delete_not_in_column(&conn, "snippets", "project_id", &project_ids)?;
     delete_not_in_column(&conn, "scene_notes", "project_id", &project_ids)?;
+    delete_not_in_column(&conn, "writing_progress", "project_id", &project_ids)?;
+    delete_not_in_column(&conn, "project_settings", "project_id", &project_ids)?;
     delete_not_in_column(&conn, "chat_threads", "project_path", &project_paths)?;
     delete_not_in_column(&conn, "chat_messages", "project_path", &project_paths)?;
     delete_not_in_column(&conn, "yjs_snapshots", "project_path", &project_paths)?;
@@ -606,6 +630,7 @@ fn prune_series_package_db(db_path: &Path, series_id: &str) -> Result<(), String
     delete_where_not_equal(&conn, "codex_entry_tags", "series_id", series_id)?;
     delete_where_not_equal(&conn, "codex_templates", "series_id", series_id)?;
     delete_where_not_equal(&conn, "codex_relation_types", "series_id", series_id)?;
+    delete_where_not_equal(&conn, "series_settings", "series_id", series_id)?;
 
     conn.execute("DELETE FROM deleted_projects", [])
         .map_err(|e| format!("Failed clearing deleted projects: {e}"))?;
@@ -652,6 +677,18 @@ fn prune_novel_package_db(db_path: &Path, project_id: &str) -> Result<(), String
         "project_id",
         &[project_id.to_string()],
     )?;
+    delete_not_in_column(
+        &conn,
+        "writing_progress",
+        "project_id",
+        &[project_id.to_string()],
+    )?;
+    delete_not_in_column(
+        &conn,
+        "project_settings",
+        "project_id",
+        &[project_id.to_string()],
+    )?;
     delete_not_in_column(
         &conn,
         "chat_threads",
@@ -682,6 +719,7 @@ fn prune_novel_package_db(db_path: &Path, project_id: &str) -> Result<(), String
         params![project.series_id],
     )
     .map_err(|e| format!("Failed pruning series rows for novel package: {e}"))?;
+    delete_where_not_equal(&conn, "series_settings", "series_id", &project.series_id)?;
 
     let scene_ids = scene_ids_for_projects(&conn, &[project_id.to_string()])?;
 
@@ -995,10 +1033,37 @@ fn collect_full_snapshot_fs_entries(app_dir: &Path) -> Result<Vec<(String, PathB
         entries.push((archive_path, file));
     }
 
+    let codex_images_root = app_dir.join("CodexImages");
+    for file in collect_directory_files(&codex_images_root)? {
+        let rel = file
+            .strip_prefix(&codex_images_root)
+            .map_err(|e| format!("Failed deriving codex image relative path: {e}"))?;
+        let archive_path = format!("fs/CodexImages/{}", normalize_archive_rel(rel));
+        entries.push((archive_path, file));
+    }
+
     entries.sort_by(|a, b| a.0.cmp(&b.0));
     Ok(entries)
 }
 
+/// The series' codex image files, stored under `fs/codex_images/<series id>/`
+fn collect_codex_image_entries(series_id: &str) -> Result<Vec<(String, PathBuf)>, String> {
+    let images_dir = codex_images_dir(series_id)?;
+    let mut entries = Vec::new();
+    for file in collect_directory_files(&images_dir)? {
+        let rel = file
+            .strip_prefix(&images_dir)
+            .map_err(|e| format!("Failed deriving codex image relative path: {e}"))?;
+        let archive_path = format!(
+            "fs/codex_images/{}/{}",
+            series_id,
+            normalize_archive_rel(rel)
+        );
+        entries.push((archive_path, file));
+    }
+    Ok(entries)
+}
+
 fn collect_project_manuscript_entries(
     projects: &[ProjectSeed],
 ) -> Result<Vec<(String, PathBuf)>, String> {
@@ -1027,10 +1092,14 @@ fn collect_project_manuscript_entries(
     Ok(entries)
 }
 
-fn default_output_path(kind: BackupPackageKind, hint: Option<&str>) -> Result<PathBuf, String> {
-    let app_dir = get_app_dir()?;
-    let backups_dir = app_dir.join("backups");
+fn backups_dir() -> Result<PathBuf, String> {
+    let backups_dir = get_app_dir()?.join("backups");
     fs::create_dir_all(&backups_dir).map_err(|e| e.to_string())?;
+    Ok(backups_dir)
+}
+
+fn default_output_path(kind: BackupPackageKind, hint: Option<&str>) -> Result<PathBuf, String> {
+    let backups_dir = backups_dir()?;
 
     let ts = now_slug_timestamp();
     let file_stem = match kind {
@@ -1134,12 +1203,14 @@ fn build_package(
     let payload_db_path = temp_dir.join("payload.db");
     snapshot_live_db(&payload_db_path)?;
 
-    let (source_hint, fs_entries) = match kind {
+    // Relative output paths resolve against the exported project's folder for
+    // novel packages and against the backups folder otherwise
+    let (source_hint, fs_entries, export_project_path) = match kind {
         BackupPackageKind::FullSnapshot => {
             prune_full_snapshot_db(&payload_db_path)?;
             let app_dir = get_app_dir()?;
             let fs_entries = collect_full_snapshot_fs_entries(&app_dir)?;
-            (BackupSourceHints::default(), fs_entries)
+            (BackupSourceHints::default(), fs_entries, None)
         }
         BackupPackageKind::SeriesPackage => {
             let sid = series_id.ok_or("Series package export requires series_id")?;
@@ -1149,7 +1220,9 @@ fn build_package(
             let conn = Connection::open(&payload_db_path)
                 .map_err(|e| format!("Failed opening pruned series payload DB: {e}"))?;
             let projects = series_project_ids_and_paths(&conn, &sid)?;
-            let fs_entries = collect_project_manuscript_entries(&projects)?;
+            let mut fs_entries = collect_project_manuscript_entries(&projects)?;
+            fs_entries.extend(collect_codex_image_entries(&sid)?);
+            fs_entries.sort_by(|a, b| a.0.cmp(&b.0));
 
             (
                 BackupSourceHints {
@@ -1159,12 +1232,14 @@ fn build_package(
                     project_title: None,
                 },
                 fs_entries,
+                None,
             )
         }
         BackupPackageKind::NovelPackage => {
             let pid = project_id.ok_or("Novel package export requires project_id")?;
             let project = load_project_by_id(&pid)?;
             prune_novel_package_db(&payload_db_path, &pid)?;
+            let project_path = project.path.clone();
 
             let seed = ProjectSeed {
                 id: project.id.clone(),
@@ -1178,7 +1253,10 @@ fn build_package(
                 series_id: project.series_id,
                 series_index: project.series_index,
             };
-            let fs_entries = collect_project_manuscript_entries(&[seed])?;
+            let series_id = seed.series_id.clone();
+            let mut fs_entries = collect_project_manuscript_entries(&[seed])?;
+            fs_entries.extend(collect_codex_image_entries(&series_id)?);
+            fs_entries.sort_by(|a, b| a.0.cmp(&b.0));
 
             (
                 BackupSourceHints {
@@ -1188,6 +1266,7 @@ fn build_package(
                     project_title: Some(project.title),
                 },
                 fs_entries,
+                Some(project_path),
             )
         }
     };
@@ -1216,7 +1295,10 @@ fn build_package(
     };
 
     let output_path = match output_path {
-        Some(path) => PathBuf::from(path),
+        Some(path) => match export_project_path.as_deref() {
+            Some(project_path) => resolve_export_path(Some(project_path), &path)?,
+            None => resolve_output_path(Some(&backups_dir()?), &path)?,
+        },
         None => {
             let hint = manifest
                 .source_hints
@@ -1316,7 +1398,8 @@ fn read_manifest_file(path: &Path) -> Result<BackupManifest, String> {
         .map_err(|e| format!("Failed parsing backup manifest: {e}"))
 }
 
-fn prepare_package(path: &str, extract_fs: bool) -> Result<PreparedPackage, String> {
+/// Opens the package and checks its entries, manifest version and checksum
+fn extract_package(path: &str, extract_fs: bool) -> Result<PreparedPackage, String> {
     if !is_supported_package_path(path) {
         return Err("Unsupported backup package extension. Expected .baa".to_string());
     }
@@ -1409,7 +1492,18 @@ fn prepare_package(path: &str, extract_fs: bool) -> Result<PreparedPackage, Stri
         return Err("Package checksum mismatch".to_string());
     }
 
-    let payload_conn = Connection::open(&payload_db_path)
+    Ok(PreparedPackage {
+        manifest,
+        payload_db_path,
+        fs_root: if extract_fs { Some(fs_root) } else { None },
+        _temp_dir: temp_dir,
+    })
+}
+
+/// The payload schema must match the manifest and be one this app can restore
+fn check_payload_schema(prepared: &PreparedPackage) -> Result<(), String> {
+    let manifest = &prepared.manifest;
+    let payload_conn = Connection::open(&prepared.payload_db_path)
         .map_err(|e| format!("Failed opening payload DB from package: {e}"))?;
     let payload_schema_version: i64 = payload_conn
         .query_row("PRAGMA user_version", [], |row| row.get(0))
@@ -1423,19 +1517,21 @@ fn prepare_package(path: &str, extract_fs: bool) -> Result<PreparedPackage, Stri
     }
 
     let local_schema_version = get_local_schema_version()?;
-    if payload_schema_version != local_schema_version {
+    if payload_schema_version < MIN_PACKAGE_SCHEMA_VERSION
+        || payload_schema_version > local_schema_version
+    {
         return Err(format!(
             "Incompatible package schema {} (local app schema is {}).",
             payload_schema_version, local_schema_version
         ));
     }
+    Ok(())
+}
 
-    Ok(PreparedPackage {
-        manifest,
-        payload_db_path,
-        fs_root: if extract_fs { Some(fs_root) } else { None },
-        _temp_dir: temp_dir,
-    })
+fn prepare_package(path: &str, extract_fs: bool) -> Result<PreparedPackage, String> {
+    let prepared = extract_package(path, extract_fs)?;
+    check_payload_schema(&prepared)?;
+    Ok(prepared)
 }
 
 fn create_project_with_unique_series_index(
@@ -1660,6 +1756,16 @@ fn restore_project_artifacts(
     copy_directory_recursive(&source, &target)
 }
 
+/// Copies a package's codex images of `old_series_id` into the target series
+fn restore_codex_images(
+    fs_root: &Path,
+    old_series_id: &str,
+    target_series_id: &str,
+) -> Result<(), String> {
+    let source = fs_root.join("codex_images").join(old_series_id);
+    copy_directory_recursive(&source, &codex_images_dir(target_series_id)?)
+}
+
 fn import_project_payload(
     payload_conn: &Connection,
     app_conn: &Connection,
@@ -2027,9 +2133,114 @@ fn import_project_payload(
         }
     }
 
+    // Packages written before progress tracking existed have no such table.
+    if payload_table_exists(payload_conn, "writing_progress")? {
+        let mut stmt = payload_conn
+            .prepare(
+                r#"
+                SELECT day, total_words, updated_at
+                FROM writing_progress
+                WHERE project_id = ?1
+                "#,
+            )
+            .map_err(|e| format!("Failed preparing writing progress import query: {e}"))?;
+        let rows = stmt
+            .query_map(params![seed.id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
+            .map_err(|e| format!("Failed querying writing progress for import: {e}"))?;
+
+        for row in rows {
+            let (day, total_words, updated_at) =
+                row.map_err(|e| format!("Failed decoding writing progress import row: {e}"))?;
+            app_conn
+                .execute(
+                    r#"
+                    INSERT OR REPLACE INTO writing_progress(project_id, day, total_words, updated_at)
+                    VALUES (?1, ?2, ?3, ?4)
+                    "#,
+                    params![cloned_project.id, day, total_words, updated_at],
+                )
+                .map_err(|e| format!("Failed importing writing progress row: {e}"))?;
+        }
+    }
+
+    if payload_table_exists(payload_conn, "project_settings")? {
+        let mut stmt = payload_conn
+            .prepare(
+                "SELECT key, value_json, updated_at FROM project_settings WHERE project_id = ?1",
+            )
+            .map_err(|e| format!("Failed preparing project settings import query: {e}"))?;
+        let rows = stmt
+            .query_map(params![seed.id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
+            .map_err(|e| format!("Failed querying project settings for import: {e}"))?;
+
+        for row in rows {
+            let (key, value_json, updated_at) =
+                row.map_err(|e| format!("Failed decoding project setting import row: {e}"))?;
+            project_setting_set(app_conn, &cloned_project.id, &key, &value_json, updated_at)?;
+        }
+    }
+
     Ok((cloned_project, scene_id_map))
 }
 
+/// Copies the packaged series' settings (codex categories, link roles...) to
+/// the target series. `replace` overwrites settings the target already has,
+/// e.g. the default categories a freshly created series is seeded with.
+fn import_series_settings(
+    payload_conn: &Connection,
+    app_conn: &Connection,
+    old_series_id: &str,
+    target_series_id: &str,
+    replace: bool,
+) -> Result<(), String> {
+    if !payload_table_exists(payload_conn, "series_settings")? {
+        return Ok(());
+    }
+    let mut stmt = payload_conn
+        .prepare("SELECT key, value_json, updated_at FROM series_settings WHERE series_id = ?1")
+        .map_err(|e| format!("Failed preparing series settings import query: {e}"))?;
+    let rows = stmt
+        .query_map(params![old_series_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed querying series settings for import: {e}"))?;
+
+    for row in rows {
+        let (key, value_json, updated_at) =
+            row.map_err(|e| format!("Failed decoding series setting import row: {e}"))?;
+        if !replace && series_setting_get(app_conn, target_series_id, &key)?.is_some() {
+            continue;
+        }
+        series_setting_set(app_conn, target_series_id, &key, &value_json, updated_at)?;
+    }
+    Ok(())
+}
+
+fn payload_table_exists(conn: &Connection, table: &str) -> Result<bool, String> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+        params![table],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed checking payload table '{table}': {e}"))
+}
+
 fn upsert_codex_tag(conn: &Connection, series_id: &str, tag: &CodexTag) -> Result<(), String> {
     let payload_json = serde_json::to_string(tag).map_err(|e| e.to_string())?;
     conn.execute(
@@ -2414,14 +2625,32 @@ fn import_codex_graph(
     Ok(())
 }
 
+fn payload_column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool, String> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+        params![table, column],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed checking payload column '{table}.{column}': {e}"))
+}
+
 fn payload_series_seed(conn: &Connection) -> Result<Option<Series>, String> {
+    // Older payloads predate the cover column
+    let cover_column = if payload_column_exists(conn, "series", "cover_image")? {
+        "cover_image"
+    } else {
+        "NULL"
+    };
     conn.query_row(
-        r#"
-        SELECT id, title, description, author, genre, status, created_at, updated_at
-        FROM series
-        ORDER BY updated_at DESC
-        LIMIT 1
-        "#,
+        &format!(
+            r#"
+            SELECT id, title, description, author, genre, status, created_at, updated_at,
+                   {cover_column}
+            FROM series
+            ORDER BY updated_at DESC
+            LIMIT 1
+            "#
+        ),
         [],
         |row| {
             Ok(Series {
@@ -2431,6 +2660,7 @@ fn payload_series_seed(conn: &Connection) -> Result<Option<Series>, String> {
                 author: row.get(3)?,
                 genre: row.get(4)?,
                 status: row.get(5)?,
+                cover_image: row.get(8)?,
                 created_at: row.get(6)?,
                 updated_at: row.get(7)?,
             })
@@ -2440,6 +2670,24 @@ fn payload_series_seed(conn: &Connection) -> Result<Option<Series>, String> {
     .map_err(|e| format!("Failed reading payload series seed: {e}"))
 }
 
+/// Gives a series created on import the packaged series' cover
+fn copy_series_cover(
+    app_conn: &Connection,
+    series_id: &str,
+    payload_series: Option<&Series>,
+) -> Result<(), String> {
+    let Some(cover_image) = payload_series.and_then(|series| series.cover_image.as_ref()) else {
+        return Ok(());
+    };
+    app_conn
+        .execute(
+            "UPDATE series SET cover_image = ?1 WHERE id = ?2",
+            params![cover_image, series_id],
+        )
+        .map_err(|e| format!("Failed copying series cover: {e}"))?;
+    Ok(())
+}
+
 fn ensure_target_series_for_novel(
     app_conn: &Connection,
     payload_series: Option<&Series>,
@@ -2472,6 +2720,7 @@ fn ensure_target_series_for_novel(
         payload_series.and_then(|series| series.genre.clone()),
         payload_series.and_then(|series| series.status.clone()),
     )?;
+    copy_series_cover(app_conn, &created.id, payload_series)?;
 
     Ok(created.id)
 }
@@ -2491,6 +2740,7 @@ fn import_series_package_payload(prepared: &PreparedPackage) -> Result<BackupImp
         seed_series.genre.clone(),
         seed_series.status.clone(),
     )?;
+    copy_series_cover(&app_conn, &created_series.id, Some(&seed_series))?;
 
     let projects = series_project_ids_and_paths(&payload_conn, &seed_series.id)?;
     let fs_root = prepared
@@ -2525,6 +2775,14 @@ fn import_series_package_payload(prepared: &PreparedPackage) -> Result<BackupImp
             &project_id_map,
             &scene_id_map,
         )?;
+        import_series_settings(
+            &payload_conn,
+            app_conn,
+            &seed_series.id,
+            &created_series.id,
+            true,
+        )?;
+        restore_codex_images(fs_root, &seed_series.id, &created_series.id)?;
 
         Ok((imported_project_ids, created_series.id.clone()))
     })?;
@@ -2548,6 +2806,13 @@ fn import_novel_package_payload(
     let app_conn = open_app_db()?;
 
     let payload_series = payload_series_seed(&payload_conn)?;
+    let series_index_override = options
+        .series_index
+        .clone()
+        .filter(|index| !index.trim().is_empty());
+    // A series created for this import takes the packaged series' settings;
+    // an existing one keeps its own where both define a setting.
+    let created_target_series = options.target_series_id.is_none();
     let target_series_id =
         ensure_target_series_for_novel(&app_conn, payload_series.as_ref(), options)?;
 
@@ -2561,7 +2826,7 @@ fn import_novel_package_payload(
             "#,
         )
         .map_err(|e| format!("Failed preparing novel-project seed query: {e}"))?;
-    let seed = stmt
+    let mut seed = stmt
         .query_row([], |row| {
             Ok(ProjectSeed {
                 id: row.get(0)?,
@@ -2579,6 +2844,18 @@ fn import_novel_package_payload(
         .optional()
         .map_err(|e| format!("Failed loading novel-project seed row: {e}"))?
         .ok_or("Novel package payload has no project row")?;
+    if let Some(series_index) = series_index_override {
+        seed.series_index = series_index;
+    }
+
+    // Reject a duplicate book number up front, before any project directory
+    // or rows are created for the import.
+    crate::commands::project::ensure_unique_series_index(
+        &app_conn,
+        &target_series_id,
+        &seed.series_index,
+        None,
+    )?;
 
     let fs_root = prepared
         .fs_root
@@ -2604,6 +2881,14 @@ fn import_novel_package_payload(
             &project_map,
             &scene_map,
         )?;
+        import_series_settings(
+            &payload_conn,
+            app_conn,
+            &seed.series_id,
+            &target_series_id,
+            created_target_series,
+        )?;
+        restore_codex_images(fs_root, &seed.series_id, &target_series_id)?;
 
         Ok((cloned_project.id, target_series_id.clone()))
     })?;
@@ -2673,6 +2958,7 @@ fn import_full_snapshot_payload(prepared: &PreparedPackage) -> Result<BackupImpo
     }
     let projects_path = app_dir.join("Projects");
     let trash_path = app_dir.join("Trash");
+    let codex_images_path = app_dir.join("CodexImages");
 
     // Stage the new DB to a temporary path before touching the live DB.
     // This ensures that if the copy fails we have not yet destroyed the live data.
@@ -2719,6 +3005,15 @@ fn import_full_snapshot_payload(prepared: &PreparedPackage) -> Result<BackupImpo
             checkpoint_summary.path
         )
     })?;
+    remove_path_if_exists(&codex_images_path)?;
+    copy_directory_recursive(&incoming_fs_root.join("CodexImages"), &codex_images_path).map_err(
+        |e| {
+            format!(
+                "Full snapshot restore failed while replacing codex images: {e}. Checkpoint package: {}",
+                checkpoint_summary.path
+            )
+        },
+    )?;
     enforce_secrets_excluded_after_restore(&target_db_path)?;
 
     let emergency_dir = app_dir.join(".emergency_backups");
@@ -2782,20 +3077,372 @@ pub fn export_novel_package(
     )
 }
 
+impl From<&BackupManifest> for BackupPackageInfo {
+    fn from(manifest: &BackupManifest) -> Self {
+        Self {
+            kind: manifest.kind,
+            app_version: manifest.app_version.clone(),
+            schema_version: manifest.schema_version,
+            created_at: manifest.created_at.clone(),
+            counts: manifest.counts.clone(),
+            source_hints: manifest.source_hints.clone(),
+        }
+    }
+}
+
 #[tauri::command]
 pub fn inspect_backup_package(package_path: String) -> Result<BackupPackageInfo, String> {
     validate_no_null_bytes(&package_path, "Package path")?;
     let prepared = prepare_package(&package_path, false)?;
-    let info = BackupPackageInfo {
-        kind: prepared.manifest.kind,
-        app_version: prepared.manifest.app_version.clone(),
-        schema_version: prepared.manifest.schema_version,
-        created_at: prepared.manifest.created_at.clone(),
-        counts: prepared.manifest.counts.clone(),
-        source_hints: prepared.manifest.source_hints.clone(),
+    Ok(BackupPackageInfo::from(&prepared.manifest))
+}
+
+const REQUIRED_MANIFEST_FIELDS: [&str; 10] = [
+    "version",
+    "kind",
+    "createdAt",
+    "appVersion",
+    "schemaVersion",
+    "secretsIncluded",
+    "artifactsIncluded",
+    "counts",
+    "sourceHints",
+    "checksum",
+];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    /// True when the package would pass the checks `import_backup_package` runs
+    pub valid: bool,
+    pub kind: Option<BackupPackageKind>,
+    pub manifest_version: Option<i64>,
+    pub schema_version: Option<i64>,
+    pub app_version: Option<String>,
+    pub created_at: Option<String>,
+    pub project_title: Option<String>,
+    pub series_title: Option<String>,
+    pub author: Option<String>,
+    pub node_count: Option<i64>,
+    pub codex_count: Option<i64>,
+    pub snippet_count: Option<i64>,
+    pub missing_fields: Vec<String>,
+    pub problems: Vec<String>,
+}
+
+fn read_raw_manifest(package_path: &Path) -> Result<serde_json::Value, String> {
+    let file = fs::File::open(package_path)
+        .map_err(|e| format!("Failed opening package '{}': {e}", package_path.display()))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed reading package archive: {e}"))?;
+    let mut entry = archive
+        .by_name("manifest.json")
+        .map_err(|_| "Invalid package: missing manifest.json".to_string())?;
+    let mut raw = String::new();
+    entry
+        .read_to_string(&mut raw)
+        .map_err(|e| format!("Failed reading manifest: {e}"))?;
+    serde_json::from_str(&raw).map_err(|e| format!("Failed parsing backup manifest: {e}"))
+}
+
+fn payload_count(conn: &Connection, table: &str) -> Option<i64> {
+    conn.query_row(&format!("SELECT COUNT(1) FROM {table}"), [], |row| {
+        row.get(0)
+    })
+    .ok()
+}
+
+/// Dry run of `import_backup_package`: reports what a package contains and
+/// every problem found, without importing anything.
+#[tauri::command]
+pub fn inspect_backup(package_path: String) -> Result<BackupSummary, String> {
+    validate_no_null_bytes(&package_path, "Package path")?;
+    let mut summary = BackupSummary {
+        valid: false,
+        kind: None,
+        manifest_version: None,
+        schema_version: None,
+        app_version: None,
+        created_at: None,
+        project_title: None,
+        series_title: None,
+        author: None,
+        node_count: None,
+        codex_count: None,
+        snippet_count: None,
+        missing_fields: Vec::new(),
+        problems: Vec::new(),
+    };
+
+    // Only the field checks need the raw manifest; everything else comes from
+    // the same typed manifest `inspect_backup_package` reports.
+    match read_raw_manifest(Path::new(&package_path)) {
+        Ok(manifest) => {
+            summary.missing_fields = REQUIRED_MANIFEST_FIELDS
+                .iter()
+                .filter(|field| manifest.get(*field).map_or(true, |value| value.is_null()))
+                .map(|field| field.to_string())
+                .collect();
+            summary.kind = manifest
+                .get("kind")
+                .and_then(|kind| serde_json::from_value(kind.clone()).ok());
+            summary.manifest_version = manifest.get("version").and_then(|v| v.as_i64());
+        }
+        Err(error) => summary.problems.push(error),
+    }
+
+    // Runs the same extension/checksum/schema checks as a real import; the
+    // filesystem payload is only hashed, not extracted.
+    match prepare_package(&package_path, false) {
+        Ok(prepared) => {
+            let info = BackupPackageInfo::from(&prepared.manifest);
+            summary.kind = Some(info.kind);
+            summary.schema_version = Some(info.schema_version);
+            summary.app_version = Some(info.app_version);
+            summary.created_at = Some(info.created_at);
+            summary.project_title = info.source_hints.project_title;
+            summary.series_title = info.source_hints.series_title;
+            summary.codex_count = Some(info.counts.codex_entries);
+            summary.snippet_count = Some(info.counts.snippets);
+            if let Ok(payload) = Connection::open(&prepared.payload_db_path) {
+                summary.node_count = payload_count(&payload, "structure_nodes");
+                summary.author = payload
+                    .query_row(
+                        "SELECT author FROM projects ORDER BY updated_at DESC LIMIT 1",
+                        [],
+                        |row| row.get::<_, String>(0),
+                    )
+                    .optional()
+                    .ok()
+                    .flatten()
+                    .filter(|author| !author.trim().is_empty());
+            }
+            summary.valid = summary.missing_fields.is_empty();
+        }
+        Err(error) => {
+            if !summary.problems.contains(&error) {
+                summary.problems.push(error);
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupVerification {
+    /// True when the package restores cleanly and all its content reads back
+    pub passed: bool,
+    pub kind: Option<BackupPackageKind>,
+    pub manifest_version: Option<i64>,
+    pub manifest_supported: bool,
+    /// Every entry read back intact and the payload matches the manifest checksum
+    pub checksum_verified: bool,
+    pub schema_compatible: bool,
+    pub scenes_checked: usize,
+    pub codex_entries_checked: usize,
+    pub problems: Vec<String>,
+}
+
+/// Where a payload project's scene files sit in the extracted package
+fn packaged_manuscript_dir(
+    fs_root: &Path,
+    kind: BackupPackageKind,
+    project_id: &str,
+    project_path: &str,
+) -> Option<PathBuf> {
+    match kind {
+        BackupPackageKind::FullSnapshot => Path::new(project_path)
+            .file_name()
+            .map(|dir| fs_root.join("Projects").join(dir).join("manuscript")),
+        _ => Some(fs_root.join("projects").join(project_id).join("manuscript")),
+    }
+}
+
+fn verify_packaged_scenes(
+    payload: &Connection,
+    fs_root: &Path,
+    kind: BackupPackageKind,
+    report: &mut BackupVerification,
+) -> Result<(), String> {
+    let mut stmt = payload
+        .prepare(
+            r#"
+            SELECT n.project_id, p.path, n.title, n.scene_file, COALESCE(m.word_count, 0)
+            FROM structure_nodes n
+            JOIN projects p ON p.id = n.project_id
+            LEFT JOIN scene_metadata m ON m.scene_id = n.id
+            WHERE n.node_type = 'scene' AND n.scene_file IS NOT NULL
+            ORDER BY n.project_id, n.order_index
+            "#,
+        )
+        .map_err(|e| format!("Failed preparing packaged scene query: {e}"))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, i32>(4)?,
+            ))
+        })
+        .map_err(|e| format!("Failed querying packaged scenes: {e}"))?;
+
+    for row in rows {
+        let (project_id, project_path, title, scene_file, recorded_words) =
+            row.map_err(|e| format!("Failed decoding packaged scene row: {e}"))?;
+        report.scenes_checked += 1;
+        let Some(path) = packaged_manuscript_dir(fs_root, kind, &project_id, &project_path)
+            .map(|dir| dir.join(&scene_file))
+        else {
+            report
+                .problems
+                .push(format!("Scene '{title}': project folder not packaged"));
+            continue;
+        };
+        if !path.is_file() {
+            report
+                .problems
+                .push(format!("Scene '{title}': {scene_file} is missing"));
+            continue;
+        }
+        // Read the scene the way the editor loads it once restored
+        let content = match read_scene_content(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                report
+                    .problems
+                    .push(format!("Scene '{title}': {scene_file} is unreadable ({e})"));
+                continue;
+            }
+        };
+        if content.trim_start().starts_with('{') {
+            if let Err(e) = serde_json::from_str::<serde_json::Value>(&content) {
+                report.problems.push(format!(
+                    "Scene '{title}': {scene_file} is not valid JSON ({e})"
+                ));
+                continue;
+            }
+        }
+        let words = count_words(&extract_paragraphs(&content).join("\n\n"));
+        if words == 0 && recorded_words > 0 {
+            report.problems.push(format!(
+                "Scene '{title}': {scene_file} has no text but {recorded_words} words were recorded"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn verify_packaged_codex(
+    payload: &Connection,
+    report: &mut BackupVerification,
+) -> Result<(), String> {
+    let mut stmt = payload
+        .prepare("SELECT id, payload_json FROM codex_entries")
+        .map_err(|e| format!("Failed preparing packaged codex query: {e}"))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| format!("Failed querying packaged codex entries: {e}"))?;
+    for row in rows {
+        let (id, raw) = row.map_err(|e| format!("Failed decoding packaged codex row: {e}"))?;
+        report.codex_entries_checked += 1;
+        if let Err(e) = serde_json::from_str::<CodexEntry>(&raw) {
+            report
+                .problems
+                .push(format!("Codex entry {id}: invalid payload ({e})"));
+        }
+    }
+    Ok(())
+}
+
+/// Full restore rehearsal for a package: beyond what `inspect_backup` checks,
+/// it extracts every entry (verifying zip CRCs and the manifest checksum),
+/// runs an SQLite integrity check on the payload, and confirms that every
+/// scene file is present, loads within the scene size limit and parses, and
+/// every codex entry deserializes. Damage is listed in `problems` rather
+/// than failing the command. Nothing is imported.
+#[tauri::command]
+pub fn verify_backup(package_path: String) -> Result<BackupVerification, String> {
+    validate_no_null_bytes(&package_path, "Package path")?;
+    let mut report = BackupVerification {
+        passed: false,
+        kind: None,
+        manifest_version: None,
+        manifest_supported: false,
+        checksum_verified: false,
+        schema_compatible: false,
+        scenes_checked: 0,
+        codex_entries_checked: 0,
+        problems: Vec::new(),
     };
 
-    Ok(info)
+    if let Ok(manifest) = read_raw_manifest(Path::new(&package_path)) {
+        report.kind = manifest
+            .get("kind")
+            .and_then(|kind| serde_json::from_value(kind.clone()).ok());
+        report.manifest_version = manifest.get("version").and_then(|v| v.as_i64());
+        report.manifest_supported = report.manifest_version == Some(i64::from(MANIFEST_VERSION));
+    }
+
+    let prepared = match extract_package(&package_path, true) {
+        Ok(prepared) => prepared,
+        Err(error) => {
+            report.problems.push(error);
+            return Ok(report);
+        }
+    };
+    report.checksum_verified = true;
+    report.kind = Some(prepared.manifest.kind);
+    match check_payload_schema(&prepared) {
+        Ok(()) => report.schema_compatible = true,
+        Err(error) => report.problems.push(error),
+    }
+
+    // From here on a damaged payload is a finding, not a failed command
+    let payload = match Connection::open(&prepared.payload_db_path) {
+        Ok(payload) => payload,
+        Err(e) => {
+            report
+                .problems
+                .push(format!("Failed opening payload DB from package: {e}"));
+            return Ok(report);
+        }
+    };
+    match payload.query_row("PRAGMA integrity_check", [], |row| row.get::<_, String>(0)) {
+        Ok(integrity) if integrity == "ok" => {}
+        Ok(integrity) => report
+            .problems
+            .push(format!("Payload database is damaged: {integrity}")),
+        Err(e) => {
+            report
+                .problems
+                .push(format!("Payload database is unreadable: {e}"));
+            return Ok(report);
+        }
+    }
+
+    let Some(fs_root) = prepared.fs_root.as_deref() else {
+        report
+            .problems
+            .push("Package files were not extracted".to_string());
+        return Ok(report);
+    };
+    if let Err(error) =
+        verify_packaged_scenes(&payload, fs_root, prepared.manifest.kind, &mut report)
+    {
+        report.problems.push(error);
+    }
+    if let Err(error) = verify_packaged_codex(&payload, &mut report) {
+        report.problems.push(error);
+    }
+
+    report.passed = report.problems.is_empty();
+    Ok(report)
 }
 
 #[tauri::command]
@@ -2814,10 +3461,95 @@ pub fn import_backup_package(
     }
 }
 
-/// Upper bound on the size the renderer may slurp through `read_file_bytes`.
-/// Generous enough for full-snapshot `.baa` packages while bounding memory use
-/// and rejecting attempts to read arbitrarily large files.
-const MAX_READ_FILE_BYTES: u64 = 4 * 1024 * 1024 * 1024; // 4 GiB
+/// Package files named by `paths`; directories contribute their `.baa`
+/// files (not recursively) in name order.
+fn expand_package_paths(paths: Vec<String>) -> Result<Vec<String>, String> {
+    let mut expanded = Vec::new();
+    for path in paths {
+        validate_no_null_bytes(&path, "Package path")?;
+        let dir = Path::new(&path);
+        if !dir.is_dir() {
+            expanded.push(path);
+            continue;
+        }
+        let mut packages: Vec<String> = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read backup folder '{path}': {e}"))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|entry| entry.is_file())
+            .map(|entry| entry.to_string_lossy().to_string())
+            .filter(|entry| is_supported_package_path(entry))
+            .collect();
+        packages.sort();
+        expanded.extend(packages);
+    }
+    Ok(expanded)
+}
+
+/// Imports several novel packages into one series, numbering them after the
+/// series' highest book number in the order given. Packages that fail
+/// validation or import are reported and skipped; the rest still import.
+#[tauri::command]
+pub fn import_backups(
+    paths: Vec<String>,
+    series_id: String,
+) -> Result<Vec<BatchImportItem>, String> {
+    validate_no_null_bytes(&series_id, "Series id")?;
+    {
+        let conn = open_app_db()?;
+        if crate::commands::series::get_series(&conn, &series_id)?.is_none() {
+            return Err(format!("Series not found: {series_id}"));
+        }
+    }
+
+    let mut results = Vec::new();
+    for path in expand_package_paths(paths)? {
+        let mut item = BatchImportItem {
+            path: path.clone(),
+            success: false,
+            project_id: None,
+            series_index: None,
+            error: None,
+        };
+
+        let summary = inspect_backup(path.clone())?;
+        if !summary.valid {
+            item.error = Some(if summary.problems.is_empty() {
+                format!(
+                    "Missing manifest fields: {}",
+                    summary.missing_fields.join(", ")
+                )
+            } else {
+                summary.problems.join("; ")
+            });
+        } else if summary.kind != Some(BackupPackageKind::NovelPackage) {
+            item.error = Some("Only novel packages can be imported into a series".to_string());
+        } else {
+            let series_index = {
+                let conn = open_app_db()?;
+                crate::commands::project::next_series_index(&conn, &series_id)?
+            };
+            let options = BackupImportOptions {
+                target_series_id: Some(series_id.clone()),
+                create_series_title: None,
+                series_index: Some(series_index.clone()),
+            };
+            match import_backup_package(path, Some(options)) {
+                Ok(result) => {
+                    item.success = true;
+                    item.project_id = result.imported_project_ids.into_iter().next();
+                    item.series_index = Some(series_index);
+                }
+                Err(error) => item.error = Some(error),
+            }
+        }
+        if let Some(error) = &item.error {
+            log::warn!("Skipped backup '{}' in batch import: {error}", item.path);
+        }
+        results.push(item);
+    }
+    Ok(results)
+}
 
 /// Read a file's bytes for the renderer (e.g. to upload a `.baa` backup).
 /// The path originates from a native open dialog. We reject null bytes, refuse
@@ -1,21 +1,145 @@
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
 
 use crate::models::EmergencyBackup;
-use crate::utils::{atomic_write, get_app_dir};
+use crate::storage::open_app_db;
+use crate::utils::{atomic_write, content_hash, get_app_dir, sanitize_path_component};
 
-#[tauri::command]
-pub fn save_emergency_backup(backup: EmergencyBackup) -> Result<(), String> {
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverableBackup {
+    pub scene_id: String,
+    pub backup_count: usize,
+    pub newest_backup_id: String,
+    pub newest_timestamp: i64,
+    pub scene_exists: bool,
+    pub project_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EmergencyBackupCleanup {
+    pub removed_count: usize,
+    pub bytes_freed: u64,
+    /// Backups still on disk, expired ones that could not be removed included
+    pub remaining_count: usize,
+}
+
+fn is_unexpired(backup: &EmergencyBackup, now: i64) -> bool {
+    backup.expires_at > now
+}
+
+/// Per-scene sidecar naming the newest backup and its content hash, so a
+/// save can be deduplicated without opening every backup file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LatestBackupMarker {
+    backup_id: String,
+    content_hash: String,
+    expires_at: i64,
+}
+
+const LATEST_MARKERS_DIR: &str = "latest";
+
+fn latest_marker_path(backups_dir: &Path, scene_id: &str) -> Option<PathBuf> {
+    let name = sanitize_path_component(scene_id).ok()?;
+    Some(
+        backups_dir
+            .join(LATEST_MARKERS_DIR)
+            .join(format!("{name}.json")),
+    )
+}
+
+fn read_latest_marker(path: &Path) -> Option<LatestBackupMarker> {
+    let raw = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn read_backups(backups_dir: &Path) -> Result<Vec<EmergencyBackup>, String> {
+    let mut backups = Vec::new();
+    for entry in (fs::read_dir(backups_dir).map_err(|e| e.to_string())?).flatten() {
+        if let Ok(content) = fs::read_to_string(entry.path()) {
+            if let Ok(backup) = serde_json::from_str::<EmergencyBackup>(&content) {
+                backups.push(backup);
+            }
+        }
+    }
+    Ok(backups)
+}
+
+const DEFAULT_TTL_SECONDS: u64 = 24 * 60 * 60;
+const MAX_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+fn write_emergency_backup(mut backup: EmergencyBackup, now: i64) -> Result<(), String> {
     let app_dir = get_app_dir()?;
     let backups_dir = app_dir.join(".emergency_backups");
     fs::create_dir_all(&backups_dir).map_err(|e| e.to_string())?;
 
+    let hash = content_hash(&backup.content);
+    // Idle autosave keeps sending the same content; don't pile up copies of it.
+    let marker_path = latest_marker_path(&backups_dir, &backup.scene_id);
+    let latest = marker_path.as_deref().and_then(read_latest_marker);
+    if latest.is_some_and(|latest| {
+        latest.content_hash == hash
+            && latest.backup_id != backup.id
+            && latest.expires_at > now
+            && backups_dir
+                .join(format!("{}.json", latest.backup_id))
+                .exists()
+    }) {
+        return Ok(());
+    }
+    backup.content_hash = Some(hash.clone());
+
     let backup_path = backups_dir.join(format!("{}.json", backup.id));
     let json = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
     atomic_write(&backup_path, &json)?;
 
+    if let Some(marker_path) = marker_path {
+        let marker = LatestBackupMarker {
+            backup_id: backup.id,
+            content_hash: hash,
+            expires_at: backup.expires_at,
+        };
+        let written = fs::create_dir_all(backups_dir.join(LATEST_MARKERS_DIR))
+            .map_err(|e| e.to_string())
+            .and_then(|_| serde_json::to_string(&marker).map_err(|e| e.to_string()))
+            .and_then(|json| atomic_write(&marker_path, &json));
+        if let Err(e) = written {
+            log::warn!("Failed to record latest emergency backup: {e}");
+        }
+    }
+
     Ok(())
 }
 
+/// Saves with the client's `expires_at`, capped at `MAX_TTL_SECONDS` from now.
+#[tauri::command]
+pub fn save_emergency_backup(mut backup: EmergencyBackup) -> Result<(), String> {
+    let now = chrono::Utc::now().timestamp_millis();
+    backup.expires_at = backup.expires_at.min(now + (MAX_TTL_SECONDS * 1000) as i64);
+    write_emergency_backup(backup, now)
+}
+
+/// Saves with `expires_at` computed here from `ttl_seconds` (default 24h,
+/// clamped to 7 days); any client-supplied expiry is ignored.
+#[tauri::command]
+pub fn save_emergency_backup_with_ttl(
+    mut backup: EmergencyBackup,
+    ttl_seconds: Option<u64>,
+) -> Result<(), String> {
+    let now = chrono::Utc::now().timestamp_millis();
+    let ttl_seconds = ttl_seconds
+        .unwrap_or(DEFAULT_TTL_SECONDS)
+        .min(MAX_TTL_SECONDS);
+    backup.expires_at = now + (ttl_seconds * 1000) as i64;
+    write_emergency_backup(backup, now)
+}
+
 #[tauri::command]
 pub fn get_emergency_backup(scene_id: String) -> Result<Option<EmergencyBackup>, String> {
     let app_dir = get_app_dir()?;
@@ -25,19 +149,65 @@ pub fn get_emergency_backup(scene_id: String) -> Result<Option<EmergencyBackup>,
         return Ok(None);
     }
 
-    for entry in (fs::read_dir(&backups_dir).map_err(|e| e.to_string())?).flatten() {
-        if let Ok(content) = fs::read_to_string(entry.path()) {
-            if let Ok(backup) = serde_json::from_str::<EmergencyBackup>(&content) {
-                if backup.scene_id == scene_id
-                    && backup.expires_at > chrono::Utc::now().timestamp_millis()
-                {
-                    return Ok(Some(backup));
-                }
-            }
+    let now = chrono::Utc::now().timestamp_millis();
+    Ok(read_backups(&backups_dir)?
+        .into_iter()
+        .find(|backup| backup.scene_id == scene_id && is_unexpired(backup, now)))
+}
+
+/// Unexpired emergency backups grouped by scene, newest first, for the
+/// startup "recover unsaved work" screen.
+#[tauri::command]
+pub fn list_recoverable_backups() -> Result<Vec<RecoverableBackup>, String> {
+    let app_dir = get_app_dir()?;
+    let backups_dir = app_dir.join(".emergency_backups");
+
+    if !backups_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let mut by_scene: BTreeMap<String, Vec<EmergencyBackup>> = BTreeMap::new();
+    for backup in read_backups(&backups_dir)? {
+        if is_unexpired(&backup, now) {
+            by_scene
+                .entry(backup.scene_id.clone())
+                .or_default()
+                .push(backup);
         }
     }
 
-    Ok(None)
+    let conn = open_app_db()?;
+    let mut project_stmt = conn
+        .prepare(
+            "SELECT project_id FROM scene_metadata WHERE scene_id = ?1
+             UNION ALL
+             SELECT project_id FROM structure_nodes WHERE id = ?1 AND node_type = 'scene'
+             LIMIT 1",
+        )
+        .map_err(|e| format!("Failed to prepare scene lookup: {e}"))?;
+
+    let mut recoverable = Vec::with_capacity(by_scene.len());
+    for (scene_id, backups) in by_scene {
+        let Some(newest) = backups.iter().max_by_key(|backup| backup.timestamp) else {
+            continue;
+        };
+        let project_id: Option<String> = project_stmt
+            .query_row(params![scene_id], |row| row.get(0))
+            .optional()
+            .map_err(|e| format!("Failed to look up scene {scene_id}: {e}"))?;
+        recoverable.push(RecoverableBackup {
+            newest_backup_id: newest.id.clone(),
+            newest_timestamp: newest.timestamp,
+            backup_count: backups.len(),
+            scene_exists: project_id.is_some(),
+            project_id,
+            scene_id,
+        });
+    }
+
+    recoverable.sort_by(|a, b| b.newest_timestamp.cmp(&a.newest_timestamp));
+    Ok(recoverable)
 }
 
 #[tauri::command]
@@ -55,26 +225,61 @@ pub fn delete_emergency_backup(backup_id: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn cleanup_emergency_backups() -> Result<i32, String> {
+pub fn cleanup_emergency_backups() -> Result<EmergencyBackupCleanup, String> {
     let app_dir = get_app_dir()?;
     let backups_dir = app_dir.join(".emergency_backups");
 
+    let mut result = EmergencyBackupCleanup {
+        removed_count: 0,
+        bytes_freed: 0,
+        remaining_count: 0,
+    };
     if !backups_dir.exists() {
-        return Ok(0);
+        return Ok(result);
     }
 
     let now = chrono::Utc::now().timestamp_millis();
-    let mut cleaned = 0;
 
     for entry in (fs::read_dir(&backups_dir).map_err(|e| e.to_string())?).flatten() {
-        if let Ok(content) = fs::read_to_string(entry.path()) {
-            if let Ok(backup) = serde_json::from_str::<EmergencyBackup>(&content) {
-                if backup.expires_at < now && fs::remove_file(entry.path()).is_ok() {
-                    cleaned += 1;
-                }
+        let path = entry.path();
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(backup) = serde_json::from_str::<EmergencyBackup>(&content) else {
+            continue;
+        };
+        if backup.expires_at >= now {
+            result.remaining_count += 1;
+            continue;
+        }
+        let size = entry
+            .metadata()
+            .map(|metadata| metadata.len())
+            .unwrap_or(content.len() as u64);
+        if fs::remove_file(&path).is_ok() {
+            result.removed_count += 1;
+            result.bytes_freed += size;
+        } else {
+            result.remaining_count += 1;
+        }
+    }
+
+    // Drop sidecars whose backup has expired or is gone
+    let markers_dir = backups_dir.join(LATEST_MARKERS_DIR);
+    if let Ok(entries) = fs::read_dir(&markers_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let stale = read_latest_marker(&path).map_or(true, |marker| {
+                marker.expires_at < now
+                    || !backups_dir
+                        .join(format!("{}.json", marker.backup_id))
+                        .exists()
+            });
+            if stale {
+                let _ = fs::remove_file(&path);
             }
         }
     }
 
-    Ok(cleaned)
+    Ok(result)
 }
@@ -1,13 +1,32 @@
 // Codex commands (SQLite-backed)
 
-use rusqlite::{params, Connection};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
 
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::codex_images::{
+    codex_images_dir, resolve_entry_images, resolve_image_ref, store_entry_images,
+};
+use crate::commands::mention::invalidate_scene_mentions;
+use crate::commands::progress::record_project_progress;
+use crate::commands::project::{
+    get_linear_scenes, get_project_by_path, get_structure, LinearScene,
+};
+use crate::commands::scene::read_scene_content;
 use crate::models::{
     CodexEntry, CodexEntryTag, CodexRelation, CodexRelationType, CodexTag, CodexTemplate,
-    SceneCodexLink,
+    SceneCodexLink, StructureNode,
+};
+use crate::storage::{
+    open_app_db, series_setting_delete, series_setting_get, series_setting_set, with_transaction,
+    SERIES_LINK_ROLES,
+};
+use crate::utils::{
+    atomic_write, count_words, extract_plain_text, find_whole_word_matches,
+    rename_in_scene_content, timestamp, validate_no_null_bytes,
 };
-use crate::storage::{open_app_db, with_transaction};
-use crate::utils::validate_no_null_bytes;
 
 fn project_series_id(conn: &Connection, project_path: &str) -> Result<String, String> {
     conn.query_row(
@@ -54,20 +73,25 @@ pub fn list_codex_entries(project_path: String) -> Result<Vec<CodexEntry>, Strin
     validate_no_null_bytes(&project_path, "Project path")?;
     let conn = open_app_db()?;
     let series_id = project_series_id(&conn, &project_path)?;
-    list_payloads::<CodexEntry>(
+    let mut entries = list_payloads::<CodexEntry>(
         &conn,
         "SELECT payload_json FROM codex_entries WHERE series_id = ?1 ORDER BY updated_at DESC",
         &[&series_id],
         "codex entry",
-    )
+    )?;
+    for entry in &mut entries {
+        resolve_entry_images(&series_id, entry);
+    }
+    Ok(entries)
 }
 
-#[tauri::command]
-pub fn save_codex_entry(project_path: String, entry: CodexEntry) -> Result<(), String> {
-    validate_no_null_bytes(&project_path, "Project path")?;
-    let conn = open_app_db()?;
-    let series_id = project_series_id(&conn, &project_path)?;
-    let payload_json = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+pub(crate) fn upsert_codex_entry(
+    conn: &Connection,
+    series_id: &str,
+    entry: &CodexEntry,
+) -> Result<(), String> {
+    let entry = &store_entry_images(series_id, entry)?;
+    let payload_json = serde_json::to_string(entry).map_err(|e| e.to_string())?;
     let aliases_json = serde_json::to_string(&entry.aliases).map_err(|e| e.to_string())?;
 
     conn.execute(
@@ -97,42 +121,310 @@ pub fn save_codex_entry(project_path: String, entry: CodexEntry) -> Result<(), S
     Ok(())
 }
 
+pub(crate) fn get_codex_entry(
+    conn: &Connection,
+    series_id: &str,
+    entry_id: &str,
+) -> Result<Option<CodexEntry>, String> {
+    let payload: Option<String> = conn
+        .query_row(
+            "SELECT payload_json FROM codex_entries WHERE series_id = ?1 AND id = ?2",
+            params![series_id, entry_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to load codex entry: {e}"))?;
+
+    payload
+        .map(|payload| parse_payload::<CodexEntry>(payload, "codex entry"))
+        .transpose()
+}
+
 #[tauri::command]
-pub fn delete_codex_entry(
+pub fn save_codex_entry(project_path: String, entry: CodexEntry) -> Result<(), String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let series_id = project_series_id(&conn, &project_path)?;
+    upsert_codex_entry(&conn, &series_id, &entry)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexRenameResult {
+    pub entry_id: String,
+    pub old_name: String,
+    pub new_name: String,
+    pub renamed: bool,
+    /// Scenes that still contain the old name after the rename
+    pub scenes_with_old_name: Vec<String>,
+    pub scenes_updated: usize,
+    pub replacements: usize,
+}
+
+fn project_scene_files(
+    conn: &Connection,
+    project_path: &str,
+) -> Result<Vec<(String, String)>, String> {
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT sm.scene_id, sm.scene_file
+            FROM scene_metadata sm
+            JOIN projects p ON p.id = sm.project_id
+            WHERE p.path = ?1
+            ORDER BY sm.order_index ASC
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare scene file query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![project_path], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| format!("Failed to query scene files: {e}"))?;
+
+    let mut result = Vec::new();
+    for row in rows {
+        result.push(row.map_err(|e| format!("Failed to decode scene file row: {e}"))?);
+    }
+    Ok(result)
+}
+
+/// A scene file `rename_codex_entry` is about to rewrite
+struct SceneRewrite {
+    scene_id: String,
+    scene_file: String,
+    path: PathBuf,
+    original: String,
+    updated: String,
+}
+
+/// Renames a codex entry and reports which scenes still mention the old name.
+/// With `replace_in_scenes` the old name is replaced (whole words only) in
+/// every scene of the project; the entry and the scenes' word counts are
+/// updated in one transaction, and writing progress and the mention cache
+/// are refreshed as a scene save would.
+#[tauri::command]
+pub fn rename_codex_entry(
     project_path: String,
-    _category: String,
     entry_id: String,
-) -> Result<(), String> {
+    new_name: String,
+    replace_in_scenes: Option<bool>,
+) -> Result<CodexRenameResult, String> {
     validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&new_name, "Codex entry name")?;
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() {
+        return Err("Codex entry name cannot be empty".to_string());
+    }
+
     let conn = open_app_db()?;
     let series_id = project_series_id(&conn, &project_path)?;
+    let mut entry = get_codex_entry(&conn, &series_id, &entry_id)?
+        .ok_or_else(|| format!("Codex entry not found: {entry_id}"))?;
 
-    with_transaction(&conn, |conn| {
-        conn.execute(
+    let old_name = entry.name.clone();
+    let renamed = old_name != new_name;
+    let mut result = CodexRenameResult {
+        entry_id: entry_id.clone(),
+        old_name: old_name.clone(),
+        new_name: new_name.clone(),
+        renamed,
+        scenes_with_old_name: Vec::new(),
+        scenes_updated: 0,
+        replacements: 0,
+    };
+
+    if !renamed {
+        return Ok(result);
+    }
+
+    entry.name = new_name.clone();
+    entry.updated_at = timestamp::now_millis();
+
+    let replace = replace_in_scenes.unwrap_or(false);
+    let mut rewrites = Vec::new();
+    for (scene_id, scene_file) in project_scene_files(&conn, &project_path)? {
+        let scene_path = PathBuf::from(&project_path)
+            .join("manuscript")
+            .join(&scene_file);
+        if !scene_path.exists() {
+            continue;
+        }
+        let content = read_scene_content(&scene_path)
+            .map_err(|e| format!("Failed to read scene '{}': {e}", scene_path.display()))?;
+        if !replace {
+            if !find_whole_word_matches(&extract_plain_text(&content), &old_name).is_empty() {
+                result.scenes_with_old_name.push(scene_id);
+            }
+            continue;
+        }
+
+        let (updated, count) = rename_in_scene_content(&content, &old_name, &new_name, &entry_id);
+        if count > 0 {
+            rewrites.push(SceneRewrite {
+                scene_id,
+                scene_file,
+                path: scene_path,
+                original: content,
+                updated,
+            });
+            result.replacements += count;
+        }
+    }
+
+    // Files can't join the transaction, so a failure puts back the ones
+    // already rewritten
+    let mut written = 0;
+    let applied = rewrites
+        .iter()
+        .try_for_each(|rewrite| {
+            atomic_write(&rewrite.path, &rewrite.updated)?;
+            written += 1;
+            Ok(())
+        })
+        .and_then(|_| {
+            with_transaction(&conn, |conn| {
+                upsert_codex_entry(conn, &series_id, &entry)?;
+                for rewrite in &rewrites {
+                    conn.execute(
+                        "UPDATE scene_metadata SET word_count = ?1, updated_at = ?2 WHERE scene_id = ?3",
+                        params![
+                            count_words(&extract_plain_text(&rewrite.updated)),
+                            entry.updated_at,
+                            rewrite.scene_id
+                        ],
+                    )
+                    .map_err(|e| format!("Failed to refresh scene word count after rename: {e}"))?;
+                }
+                if !rewrites.is_empty() {
+                    let project = get_project_by_path(conn, &project_path)?;
+                    record_project_progress(conn, &project.id)?;
+                }
+                Ok(())
+            })
+        });
+    if let Err(e) = applied {
+        for rewrite in &rewrites[..written] {
+            if let Err(restore_error) = atomic_write(&rewrite.path, &rewrite.original) {
+                log::warn!(
+                    "Failed to restore scene {} after a failed rename: {restore_error}",
+                    rewrite.scene_file
+                );
+            }
+        }
+        return Err(e);
+    }
+
+    for rewrite in &rewrites {
+        if let Err(e) = invalidate_scene_mentions(&project_path, &rewrite.scene_file) {
+            log::warn!(
+                "Failed to invalidate mention cache for {}: {e}",
+                rewrite.scene_file
+            );
+        }
+    }
+    result.scenes_updated = rewrites.len();
+    Ok(result)
+}
+
+/// Deletes an entry with its relations, scene links and tag assignments.
+/// Returns how many entry rows were removed (0 when the id was unknown).
+fn delete_codex_entry_cascade(
+    conn: &Connection,
+    series_id: &str,
+    entry_id: &str,
+) -> Result<usize, String> {
+    let deleted = conn
+        .execute(
             "DELETE FROM codex_entries WHERE series_id = ?1 AND id = ?2",
             params![series_id, entry_id],
         )
         .map_err(|e| format!("Failed to delete codex entry: {e}"))?;
-        conn.execute(
-            "DELETE FROM codex_relations WHERE series_id = ?1 AND (parent_id = ?2 OR child_id = ?2)",
-            params![series_id, entry_id],
-        )
-        .map_err(|e| format!("Failed to delete dependent codex relations: {e}"))?;
-        conn.execute(
-            "DELETE FROM scene_codex_links WHERE series_id = ?1 AND codex_id = ?2",
-            params![series_id, entry_id],
-        )
-        .map_err(|e| format!("Failed to delete dependent scene links: {e}"))?;
-        conn.execute(
-            "DELETE FROM codex_entry_tags WHERE series_id = ?1 AND entry_id = ?2",
-            params![series_id, entry_id],
-        )
-        .map_err(|e| format!("Failed to delete dependent codex entry tags: {e}"))?;
+    conn.execute(
+        "DELETE FROM codex_relations WHERE series_id = ?1 AND (parent_id = ?2 OR child_id = ?2)",
+        params![series_id, entry_id],
+    )
+    .map_err(|e| format!("Failed to delete dependent codex relations: {e}"))?;
+    conn.execute(
+        "DELETE FROM scene_codex_links WHERE series_id = ?1 AND codex_id = ?2",
+        params![series_id, entry_id],
+    )
+    .map_err(|e| format!("Failed to delete dependent scene links: {e}"))?;
+    conn.execute(
+        "DELETE FROM codex_entry_tags WHERE series_id = ?1 AND entry_id = ?2",
+        params![series_id, entry_id],
+    )
+    .map_err(|e| format!("Failed to delete dependent codex entry tags: {e}"))?;
+
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub fn delete_codex_entry(
+    project_path: String,
+    _category: String,
+    entry_id: String,
+) -> Result<(), String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let series_id = project_series_id(&conn, &project_path)?;
 
+    with_transaction(&conn, |conn| {
+        delete_codex_entry_cascade(conn, &series_id, &entry_id)?;
         Ok(())
     })
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexEntryRef {
+    pub id: String,
+    #[serde(default)]
+    pub category: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexBatchDeleteResult {
+    pub deleted_count: usize,
+    pub not_found: Vec<String>,
+}
+
+/// Deletes several entries with the same cascade as `delete_codex_entry`,
+/// all in one transaction.
+#[tauri::command]
+pub fn delete_codex_entries(
+    project_path: String,
+    entries: Vec<CodexEntryRef>,
+) -> Result<CodexBatchDeleteResult, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let series_id = project_series_id(&conn, &project_path)?;
+
+    let mut seen = HashSet::new();
+    let ids: Vec<String> = entries
+        .into_iter()
+        .map(|entry| entry.id)
+        .filter(|id| seen.insert(id.clone()))
+        .collect();
+
+    with_transaction(&conn, |conn| {
+        let mut result = CodexBatchDeleteResult {
+            deleted_count: 0,
+            not_found: Vec::new(),
+        };
+        for id in ids {
+            if delete_codex_entry_cascade(conn, &series_id, &id)? > 0 {
+                result.deleted_count += 1;
+            } else {
+                result.not_found.push(id);
+            }
+        }
+        Ok(result)
+    })
+}
+
 #[tauri::command]
 pub fn list_codex_relations(project_path: String) -> Result<Vec<CodexRelation>, String> {
     validate_no_null_bytes(&project_path, "Project path")?;
@@ -443,11 +735,85 @@ pub fn list_scene_codex_links(project_path: String) -> Result<Vec<SceneCodexLink
     )
 }
 
+/// The series' allowed link roles (a JSON array series setting). When unset,
+/// any non-empty role is accepted.
+fn link_role_vocabulary(conn: &Connection, series_id: &str) -> Option<Vec<String>> {
+    series_setting_get(conn, series_id, SERIES_LINK_ROLES)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<Vec<String>>(&value).ok())
+        .filter(|roles| !roles.is_empty())
+}
+
+fn validate_link_role(conn: &Connection, series_id: &str, role: &str) -> Result<String, String> {
+    validate_no_null_bytes(role, "Link role")?;
+    let role = role.trim();
+    if role.is_empty() {
+        return Err("Scene codex link role cannot be empty".to_string());
+    }
+    if let Some(vocabulary) = link_role_vocabulary(conn, series_id) {
+        if !vocabulary
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(role))
+        {
+            return Err(format!(
+                "Unknown link role '{role}' (allowed: {})",
+                vocabulary.join(", ")
+            ));
+        }
+    }
+    Ok(role.to_string())
+}
+
+/// Distinct roles used by the series' scene codex links, sorted
+#[tauri::command]
+pub fn list_link_roles(project_path: String) -> Result<Vec<String>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let links = list_scene_codex_links(project_path)?;
+    let roles: BTreeSet<String> = links
+        .into_iter()
+        .map(|link| link.role.trim().to_string())
+        .filter(|role| !role.is_empty())
+        .collect();
+    Ok(roles.into_iter().collect())
+}
+
+/// Restricts link roles for the project's series to `roles` (e.g. pov,
+/// appears, mentioned, location); `None` or an empty list lifts the limit.
+#[tauri::command]
+pub fn set_link_role_vocabulary(
+    project_path: String,
+    roles: Option<Vec<String>>,
+) -> Result<(), String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let series_id = project_series_id(&conn, &project_path)?;
+    let mut seen = HashSet::new();
+    let roles: Vec<String> = roles
+        .unwrap_or_default()
+        .into_iter()
+        .map(|role| role.trim().to_string())
+        .filter(|role| !role.is_empty() && seen.insert(role.to_lowercase()))
+        .collect();
+    if roles.is_empty() {
+        return series_setting_delete(&conn, &series_id, SERIES_LINK_ROLES);
+    }
+    let value_json = serde_json::to_string(&roles).map_err(|e| e.to_string())?;
+    series_setting_set(
+        &conn,
+        &series_id,
+        SERIES_LINK_ROLES,
+        &value_json,
+        timestamp::now_millis(),
+    )
+}
+
 #[tauri::command]
-pub fn save_scene_codex_link(project_path: String, link: SceneCodexLink) -> Result<(), String> {
+pub fn save_scene_codex_link(project_path: String, mut link: SceneCodexLink) -> Result<(), String> {
     validate_no_null_bytes(&project_path, "Project path")?;
     let conn = open_app_db()?;
     let series_id = project_series_id(&conn, &project_path)?;
+    link.role = validate_link_role(&conn, &series_id, &link.role)?;
     let payload_json = serde_json::to_string(&link).map_err(|e| e.to_string())?;
 
     conn.execute(
@@ -490,3 +856,748 @@ pub fn delete_scene_codex_link(project_path: String, link_id: String) -> Result<
 
     Ok(())
 }
+
+/// Scenes (in reading order) that have no codex links at all, for a
+/// "tag untagged scenes" pass.
+#[tauri::command]
+pub fn scenes_without_codex_links(project_path: String) -> Result<Vec<LinearScene>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let linked: HashSet<String> = {
+        let conn = open_app_db()?;
+        let series_id = project_series_id(&conn, &project_path)?;
+        let mut stmt = conn
+            .prepare("SELECT DISTINCT scene_id FROM scene_codex_links WHERE series_id = ?1")
+            .map_err(|e| format!("Failed to prepare linked scene query: {e}"))?;
+        let rows = stmt
+            .query_map(params![series_id], |row| row.get::<_, String>(0))
+            .map_err(|e| format!("Failed to query linked scenes: {e}"))?;
+        rows.collect::<Result<_, _>>()
+            .map_err(|e| format!("Failed to decode linked scene row: {e}"))?
+    };
+
+    Ok(get_linear_scenes(project_path)?
+        .into_iter()
+        .filter(|scene| !linked.contains(&scene.scene_id))
+        .collect())
+}
+
+/// A codex name or alias that is also the title of an act, chapter or scene
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NameCollision {
+    pub codex_entry_id: String,
+    pub codex_name: String,
+    /// The entry's name or the alias that matched
+    pub matched_name: String,
+    pub node_id: String,
+    pub node_type: String,
+    pub node_title: String,
+}
+
+fn collect_titles<'a>(
+    nodes: &'a [StructureNode],
+    titles: &mut HashMap<String, Vec<&'a StructureNode>>,
+) {
+    for node in nodes {
+        let key = node.title.trim().to_lowercase();
+        if !key.is_empty() {
+            titles.entry(key).or_default().push(node);
+        }
+        collect_titles(&node.children, titles);
+    }
+}
+
+/// Codex names and aliases that equal (case-insensitively) an act, chapter
+/// or scene title, which muddles search and mention detection.
+#[tauri::command]
+pub fn check_name_collisions(project_path: String) -> Result<Vec<NameCollision>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let structure = get_structure(project_path.clone())?;
+    let mut titles = HashMap::new();
+    collect_titles(&structure, &mut titles);
+
+    let mut collisions = Vec::new();
+    for entry in list_codex_entries(project_path)? {
+        let mut seen = HashSet::new();
+        for name in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
+            let key = name.trim().to_lowercase();
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+            for node in titles.get(&key).into_iter().flatten() {
+                collisions.push(NameCollision {
+                    codex_entry_id: entry.id.clone(),
+                    codex_name: entry.name.clone(),
+                    matched_name: name.trim().to_string(),
+                    node_id: node.id.clone(),
+                    node_type: node.node_type.clone(),
+                    node_title: node.title.clone(),
+                });
+            }
+        }
+    }
+    Ok(collisions)
+}
+
+/// Accepts either a project path or a series id and returns the series id.
+pub(crate) fn resolve_series_id(
+    conn: &Connection,
+    project_path_or_series_id: &str,
+) -> Result<String, String> {
+    let from_project: Option<String> = conn
+        .query_row(
+            "SELECT series_id FROM projects WHERE path = ?1",
+            params![project_path_or_series_id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to resolve project series_id: {e}"))?;
+    if let Some(series_id) = from_project {
+        return Ok(series_id);
+    }
+
+    let series_exists: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM series WHERE id = ?1)",
+            params![project_path_or_series_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to check series existence: {e}"))?;
+    if series_exists {
+        Ok(project_path_or_series_id.to_string())
+    } else {
+        Err("No project or series found for the given identifier".to_string())
+    }
+}
+
+fn normalize_codex_name(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexEntriesById {
+    pub entries: Vec<CodexEntry>,
+    pub missing: Vec<String>,
+}
+
+/// Fetches specific entries in the order requested. Ids that don't exist in
+/// the series are reported in `missing`; repeated ids are returned once.
+#[tauri::command]
+pub fn get_codex_entries_by_ids(
+    project_path_or_series_id: String,
+    ids: Vec<String>,
+) -> Result<CodexEntriesById, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let mut seen = HashSet::new();
+    let ids: Vec<String> = ids
+        .into_iter()
+        .filter(|id| seen.insert(id.clone()))
+        .collect();
+    if ids.is_empty() {
+        return Ok(CodexEntriesById {
+            entries: Vec::new(),
+            missing: Vec::new(),
+        });
+    }
+
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+
+    let placeholders = vec!["?"; ids.len()].join(",");
+    let sql = format!(
+        "SELECT payload_json FROM codex_entries WHERE series_id = ? AND id IN ({placeholders})"
+    );
+    let mut values: Vec<&dyn rusqlite::ToSql> = Vec::with_capacity(ids.len() + 1);
+    values.push(&series_id);
+    values.extend(ids.iter().map(|id| id as &dyn rusqlite::ToSql));
+    let mut by_id: HashMap<String, CodexEntry> =
+        list_payloads::<CodexEntry>(&conn, &sql, &values, "codex entry")?
+            .into_iter()
+            .map(|entry| (entry.id.clone(), entry))
+            .collect();
+
+    let mut result = CodexEntriesById {
+        entries: Vec::with_capacity(by_id.len()),
+        missing: Vec::new(),
+    };
+    for id in ids {
+        match by_id.remove(&id) {
+            Some(mut entry) => {
+                resolve_entry_images(&series_id, &mut entry);
+                result.entries.push(entry);
+            }
+            None => result.missing.push(id),
+        }
+    }
+    Ok(result)
+}
+
+/// What a codex list row shows, without the rest of the entry
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexEntrySummary {
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub thumbnail: Option<String>,
+    pub completeness: Option<i32>,
+}
+
+/// Lightweight codex listing sorted by name. Fields are read straight from
+/// the stored JSON, so entries are never fully deserialized.
+#[tauri::command]
+pub fn list_codex_summaries(
+    project_path_or_series_id: String,
+) -> Result<Vec<CodexEntrySummary>, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    let images_dir = codex_images_dir(&series_id)?;
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT id, name, category,
+                   json_extract(payload_json, '$.thumbnail'),
+                   json_extract(payload_json, '$.completeness')
+            FROM codex_entries
+            WHERE series_id = ?1
+            ORDER BY name COLLATE NOCASE ASC, id ASC
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare codex summary query: {e}"))?;
+    let rows = stmt
+        .query_map(params![series_id], |row| {
+            Ok(CodexEntrySummary {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                category: row.get(2)?,
+                // Tolerate payloads where these hold an unexpected JSON type
+                thumbnail: row
+                    .get::<_, Option<String>>(3)
+                    .unwrap_or(None)
+                    .map(|mut thumbnail| {
+                        resolve_image_ref(&images_dir, &mut thumbnail);
+                        thumbnail
+                    }),
+                completeness: row.get::<_, Option<i32>>(4).unwrap_or(None),
+            })
+        })
+        .map_err(|e| format!("Failed to execute codex summary query: {e}"))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to decode codex summary row: {e}"))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexChanges {
+    /// Entries changed after the requested time, oldest first
+    pub entries: Vec<CodexEntry>,
+    /// Ids of every entry the series still has; cached entries missing from
+    /// this list have been deleted
+    pub entry_ids: Vec<String>,
+}
+
+/// Entry count and newest `updated_at` for a series' codex. Deleting an
+/// entry changes the count even when the newest timestamp stays the same.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexStamp {
+    pub entry_count: i64,
+    pub last_modified: Option<i64>,
+}
+
+/// Entries changed after `since_millis`, plus the ids still present so the
+/// caller can drop deleted ones, for refreshing a cached codex without
+/// reloading it.
+#[tauri::command]
+pub fn list_codex_entries_since(
+    project_path_or_series_id: String,
+    since_millis: i64,
+) -> Result<CodexChanges, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    let mut entries = list_payloads::<CodexEntry>(
+        &conn,
+        "SELECT payload_json FROM codex_entries WHERE series_id = ?1 AND updated_at > ?2 ORDER BY updated_at ASC",
+        &[&series_id, &since_millis],
+        "codex entry",
+    )?;
+    for entry in &mut entries {
+        resolve_entry_images(&series_id, entry);
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT id FROM codex_entries WHERE series_id = ?1 ORDER BY id ASC")
+        .map_err(|e| format!("Failed to prepare codex id query: {e}"))?;
+    let entry_ids = stmt
+        .query_map(params![series_id], |row| row.get(0))
+        .map_err(|e| format!("Failed to execute codex id query: {e}"))?
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|e| format!("Failed to decode codex id row: {e}"))?;
+    Ok(CodexChanges { entries, entry_ids })
+}
+
+/// Entry count and newest `updated_at` across the series' codex entries.
+/// Cheap enough to poll before deciding to refetch; either value changing
+/// means entries were added, edited or deleted.
+#[tauri::command]
+pub fn get_codex_last_modified(project_path_or_series_id: String) -> Result<CodexStamp, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    conn.query_row(
+        "SELECT COUNT(*), MAX(updated_at) FROM codex_entries WHERE series_id = ?1",
+        params![series_id],
+        |row| {
+            Ok(CodexStamp {
+                entry_count: row.get(0)?,
+                last_modified: row.get(1)?,
+            })
+        },
+    )
+    .map_err(|e| format!("Failed to read codex last modified: {e}"))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateCodexCluster {
+    pub category: String,
+    /// Normalized names/aliases shared by members of the cluster
+    pub matched_terms: Vec<String>,
+    /// True when at least two entries share a primary name (not just an alias)
+    pub name_match: bool,
+    pub entries: Vec<CodexEntry>,
+}
+
+fn find_root(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut current = index;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
+}
+
+/// Groups same-category entries whose normalized names or aliases collide.
+#[tauri::command]
+pub fn find_duplicate_codex(
+    project_path_or_series_id: String,
+) -> Result<Vec<DuplicateCodexCluster>, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    let entries = list_payloads::<CodexEntry>(
+        &conn,
+        "SELECT payload_json FROM codex_entries WHERE series_id = ?1 ORDER BY created_at ASC",
+        &[&series_id],
+        "codex entry",
+    )?;
+
+    let mut parents: Vec<usize> = (0..entries.len()).collect();
+    let mut by_term: HashMap<(String, String), Vec<usize>> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let mut terms: HashSet<String> = HashSet::new();
+        terms.insert(normalize_codex_name(&entry.name));
+        terms.extend(
+            entry
+                .aliases
+                .iter()
+                .map(|alias| normalize_codex_name(alias)),
+        );
+        for term in terms.into_iter().filter(|term| !term.is_empty()) {
+            by_term
+                .entry((entry.category.clone(), term))
+                .or_default()
+                .push(index);
+        }
+    }
+
+    let mut shared_terms: Vec<(String, Vec<usize>)> = Vec::new();
+    for ((_, term), members) in by_term {
+        if members.len() < 2 {
+            continue;
+        }
+        for pair in members.windows(2) {
+            let (a, b) = (
+                find_root(&mut parents, pair[0]),
+                find_root(&mut parents, pair[1]),
+            );
+            if a != b {
+                parents[b] = a;
+            }
+        }
+        shared_terms.push((term, members));
+    }
+
+    // After this pass every index points directly at its root.
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+    for index in 0..entries.len() {
+        let root = find_root(&mut parents, index);
+        clusters.entry(root).or_default().push(index);
+    }
+
+    let mut result: Vec<DuplicateCodexCluster> = clusters
+        .into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .map(|(root, members)| {
+            let mut matched_terms: Vec<String> = shared_terms
+                .iter()
+                .filter(|(_, term_members)| {
+                    term_members
+                        .first()
+                        .is_some_and(|first| parents[*first] == root)
+                })
+                .map(|(term, _)| term.clone())
+                .collect();
+            matched_terms.sort();
+
+            let mut names = HashSet::new();
+            let name_match = members
+                .iter()
+                .any(|index| !names.insert(normalize_codex_name(&entries[*index].name)));
+
+            DuplicateCodexCluster {
+                category: entries[members[0]].category.clone(),
+                matched_terms,
+                name_match,
+                entries: members
+                    .iter()
+                    .map(|index| entries[*index].clone())
+                    .collect(),
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| a.matched_terms.cmp(&b.matched_terms));
+    Ok(result)
+}
+
+fn push_unique(target: &mut Vec<String>, value: &str) {
+    let normalized = normalize_codex_name(value);
+    if normalized.is_empty() || target.iter().any(|v| normalize_codex_name(v) == normalized) {
+        return;
+    }
+    target.push(value.trim().to_string());
+}
+
+fn absorb_codex_entry(keep: &mut CodexEntry, duplicate: &CodexEntry) {
+    let keep_name = normalize_codex_name(&keep.name);
+    for alias in std::iter::once(&duplicate.name).chain(duplicate.aliases.iter()) {
+        if normalize_codex_name(alias) != keep_name {
+            push_unique(&mut keep.aliases, alias);
+        }
+    }
+    for tag in &duplicate.tags {
+        push_unique(&mut keep.tags, tag);
+    }
+    for reference in &duplicate.references {
+        if !keep.references.contains(reference) {
+            keep.references.push(reference.clone());
+        }
+    }
+    for (key, value) in &duplicate.attributes {
+        keep.attributes
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+    match (&mut keep.custom_fields, &duplicate.custom_fields) {
+        (Some(serde_json::Value::Object(target)), Some(serde_json::Value::Object(source))) => {
+            for (key, value) in source {
+                let slot = target.entry(key.clone()).or_insert(serde_json::Value::Null);
+                if slot.is_null() {
+                    *slot = value.clone();
+                }
+            }
+        }
+        (target @ (None | Some(serde_json::Value::Null)), Some(source)) => {
+            *target = Some(source.clone());
+        }
+        _ => {}
+    }
+    if keep.description.trim().is_empty() {
+        keep.description = duplicate.description.clone();
+    }
+    if keep.image.is_none() {
+        keep.image = duplicate.image.clone();
+        keep.thumbnail = duplicate.thumbnail.clone();
+    }
+    if let Some(gallery) = &duplicate.gallery {
+        let target = keep.gallery.get_or_insert_with(Vec::new);
+        for image in gallery {
+            if !target.contains(image) {
+                target.push(image.clone());
+            }
+        }
+    }
+}
+
+fn repoint_codex_references(
+    conn: &Connection,
+    series_id: &str,
+    keep_id: &str,
+    duplicate_id: &str,
+) -> Result<(), String> {
+    let relations = list_payloads::<CodexRelation>(
+        conn,
+        "SELECT payload_json FROM codex_relations WHERE series_id = ?1 AND (parent_id = ?2 OR child_id = ?2)",
+        &[&series_id, &duplicate_id],
+        "codex relation",
+    )?;
+    for mut relation in relations {
+        if relation.parent_id == duplicate_id {
+            relation.parent_id = keep_id.to_string();
+        }
+        if relation.child_id == duplicate_id {
+            relation.child_id = keep_id.to_string();
+        }
+        if relation.parent_id == relation.child_id {
+            conn.execute(
+                "DELETE FROM codex_relations WHERE id = ?1",
+                params![relation.id],
+            )
+            .map_err(|e| format!("Failed to drop self-relation during merge: {e}"))?;
+            continue;
+        }
+        let payload_json = serde_json::to_string(&relation).map_err(|e| e.to_string())?;
+        conn.execute(
+            "UPDATE codex_relations SET parent_id = ?1, child_id = ?2, payload_json = ?3 WHERE id = ?4",
+            params![relation.parent_id, relation.child_id, payload_json, relation.id],
+        )
+        .map_err(|e| format!("Failed to repoint codex relation during merge: {e}"))?;
+    }
+
+    let links = list_payloads::<SceneCodexLink>(
+        conn,
+        "SELECT payload_json FROM scene_codex_links WHERE series_id = ?1 AND codex_id = ?2",
+        &[&series_id, &duplicate_id],
+        "scene codex link",
+    )?;
+    for mut link in links {
+        let already_linked: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM scene_codex_links WHERE series_id = ?1 AND scene_id = ?2 AND codex_id = ?3)",
+                params![series_id, link.scene_id, keep_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check existing scene link during merge: {e}"))?;
+        if already_linked {
+            conn.execute(
+                "DELETE FROM scene_codex_links WHERE id = ?1",
+                params![link.id],
+            )
+            .map_err(|e| format!("Failed to drop duplicate scene link during merge: {e}"))?;
+            continue;
+        }
+        link.codex_id = keep_id.to_string();
+        let payload_json = serde_json::to_string(&link).map_err(|e| e.to_string())?;
+        conn.execute(
+            "UPDATE scene_codex_links SET codex_id = ?1, payload_json = ?2 WHERE id = ?3",
+            params![keep_id, payload_json, link.id],
+        )
+        .map_err(|e| format!("Failed to repoint scene link during merge: {e}"))?;
+    }
+
+    let entry_tags = list_payloads::<CodexEntryTag>(
+        conn,
+        "SELECT payload_json FROM codex_entry_tags WHERE series_id = ?1 AND entry_id = ?2",
+        &[&series_id, &duplicate_id],
+        "codex entry tag",
+    )?;
+    for mut entry_tag in entry_tags {
+        let already_tagged: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM codex_entry_tags WHERE series_id = ?1 AND entry_id = ?2 AND tag_id = ?3)",
+                params![series_id, keep_id, entry_tag.tag_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check existing entry tag during merge: {e}"))?;
+        if already_tagged {
+            conn.execute(
+                "DELETE FROM codex_entry_tags WHERE id = ?1",
+                params![entry_tag.id],
+            )
+            .map_err(|e| format!("Failed to drop duplicate entry tag during merge: {e}"))?;
+            continue;
+        }
+        entry_tag.entry_id = keep_id.to_string();
+        let payload_json = serde_json::to_string(&entry_tag).map_err(|e| e.to_string())?;
+        conn.execute(
+            "UPDATE codex_entry_tags SET entry_id = ?1, payload_json = ?2 WHERE id = ?3",
+            params![keep_id, payload_json, entry_tag.id],
+        )
+        .map_err(|e| format!("Failed to repoint entry tag during merge: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Folds `duplicate_ids` into `keep_id`: names become aliases, missing
+/// details are copied over, and relations, scene links and tags are
+/// re-pointed before the duplicates are deleted.
+#[tauri::command]
+pub fn merge_codex_entries(
+    project_path_or_series_id: String,
+    keep_id: String,
+    duplicate_ids: Vec<String>,
+) -> Result<CodexEntry, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+
+    with_transaction(&conn, |conn| {
+        let mut keep = get_codex_entry(conn, &series_id, &keep_id)?
+            .ok_or_else(|| format!("Codex entry not found: {keep_id}"))?;
+
+        for duplicate_id in duplicate_ids.iter().filter(|id| **id != keep_id) {
+            let duplicate = get_codex_entry(conn, &series_id, duplicate_id)?
+                .ok_or_else(|| format!("Codex entry not found: {duplicate_id}"))?;
+            absorb_codex_entry(&mut keep, &duplicate);
+            repoint_codex_references(conn, &series_id, &keep_id, duplicate_id)?;
+            conn.execute(
+                "DELETE FROM codex_entries WHERE series_id = ?1 AND id = ?2",
+                params![series_id, duplicate_id],
+            )
+            .map_err(|e| format!("Failed to delete merged codex entry: {e}"))?;
+        }
+
+        keep.updated_at = timestamp::now_millis();
+        upsert_codex_entry(conn, &series_id, &keep)?;
+        resolve_entry_images(&series_id, &mut keep);
+        Ok(keep)
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexAttributeMatch {
+    pub entry: CodexEntry,
+    /// Key as stored on the entry: the attribute name (original casing) or
+    /// the template field id for custom fields
+    pub field: String,
+    pub value: String,
+    /// "attributes" or "customFields"
+    pub source: String,
+}
+
+fn json_field_values(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::String(text) => vec![text.clone()],
+        serde_json::Value::Number(number) => vec![number.to_string()],
+        serde_json::Value::Bool(flag) => vec![flag.to_string()],
+        serde_json::Value::Array(items) => items.iter().flat_map(json_field_values).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn attribute_value_matches(candidate: &str, needle: &str, exact: bool) -> bool {
+    let candidate = candidate.trim().to_lowercase();
+    if exact {
+        candidate == needle
+    } else {
+        candidate.contains(needle)
+    }
+}
+
+/// Finds entries whose named attribute or custom field matches `value`.
+/// Custom fields are keyed by template field id, so `field` may name the
+/// template field or give its id. Field names and values compare
+/// case-insensitively; `mode` is "contains" (default) or "exact".
+#[tauri::command]
+pub fn search_codex_attributes(
+    project_path_or_series_id: String,
+    field: String,
+    value: String,
+    mode: Option<String>,
+) -> Result<Vec<CodexAttributeMatch>, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let field = field.trim().to_lowercase();
+    if field.is_empty() {
+        return Err("Field name cannot be empty".to_string());
+    }
+    let exact = match mode.as_deref().map(str::trim) {
+        None | Some("") | Some("contains") => false,
+        Some("exact") => true,
+        Some(other) => return Err(format!("Unsupported match mode: {other}")),
+    };
+    let needle = value.trim().to_lowercase();
+
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    let entries = list_payloads::<CodexEntry>(
+        &conn,
+        "SELECT payload_json FROM codex_entries WHERE series_id = ?1 ORDER BY name COLLATE NOCASE ASC",
+        &[&series_id],
+        "codex entry",
+    )?;
+    let templates = list_payloads::<CodexTemplate>(
+        &conn,
+        "SELECT payload_json FROM codex_templates WHERE series_id = ?1",
+        &[&series_id],
+        "codex template",
+    )?;
+    // Template id -> ids of that template's fields named `field`
+    let field_ids: HashMap<String, HashSet<String>> = templates
+        .into_iter()
+        .map(|template| {
+            let ids = template
+                .fields
+                .into_iter()
+                .filter(|candidate| candidate.name.trim().to_lowercase() == field)
+                .map(|candidate| candidate.id)
+                .collect();
+            (template.id, ids)
+        })
+        .collect();
+
+    let mut matches = Vec::new();
+    for entry in entries {
+        let custom_field_ids: HashSet<&String> = match &entry.template_id {
+            Some(template_id) => field_ids.get(template_id).into_iter().flatten().collect(),
+            None => field_ids.values().flatten().collect(),
+        };
+        let mut hit: Option<(String, String, &str)> = entry
+            .attributes
+            .iter()
+            .filter(|(key, _)| key.to_lowercase() == field)
+            .find(|(_, candidate)| attribute_value_matches(candidate, &needle, exact))
+            .map(|(key, candidate)| (key.clone(), candidate.clone(), "attributes"));
+
+        if hit.is_none() {
+            if let Some(serde_json::Value::Object(fields)) = &entry.custom_fields {
+                hit = fields
+                    .iter()
+                    .filter(|(key, _)| {
+                        key.to_lowercase() == field || custom_field_ids.contains(key)
+                    })
+                    .flat_map(|(key, raw)| {
+                        json_field_values(raw)
+                            .into_iter()
+                            .map(move |candidate| (key.clone(), candidate))
+                    })
+                    .find(|(_, candidate)| attribute_value_matches(candidate, &needle, exact))
+                    .map(|(key, candidate)| (key, candidate, "customFields"));
+            }
+        }
+
+        if let Some((field, value, source)) = hit {
+            matches.push(CodexAttributeMatch {
+                entry,
+                field,
+                value,
+                source: source.to_string(),
+            });
+        }
+    }
+
+    Ok(matches)
+}
@@ -0,0 +1,145 @@
+// Codex categories per series
+// The category set (id, display name, icon) is the series setting
+// `codex_categories`, seeded with the defaults when the series is created.
+// Series created before categories were configurable fall back to the
+// defaults. Icons are lucide icon names.
+
+use std::collections::HashSet;
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::series::get_series;
+use crate::storage::{
+    open_app_db, series_setting_get, series_setting_set, SERIES_CODEX_CATEGORIES,
+};
+use crate::utils::{now_millis, validate_no_null_bytes};
+
+const DEFAULT_CATEGORIES: [(&str, &str, &str); 5] = [
+    ("character", "Characters", "user"),
+    ("location", "Locations", "map-pin"),
+    ("item", "Items", "box"),
+    ("lore", "Lore", "book"),
+    ("subplot", "Subplots", "file-text"),
+];
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexCategory {
+    /// Stored on codex entries, e.g. "character" or "magic-system"
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+pub(crate) fn default_codex_categories() -> Vec<CodexCategory> {
+    DEFAULT_CATEGORIES
+        .iter()
+        .map(|(id, name, icon)| CodexCategory {
+            id: id.to_string(),
+            name: name.to_string(),
+            icon: Some(icon.to_string()),
+        })
+        .collect()
+}
+
+/// The series' categories in display order
+pub(crate) fn codex_categories_for_series(
+    conn: &Connection,
+    series_id: &str,
+) -> Vec<CodexCategory> {
+    series_setting_get(conn, series_id, SERIES_CODEX_CATEGORIES)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<Vec<CodexCategory>>(&value).ok())
+        .filter(|categories| !categories.is_empty())
+        .unwrap_or_else(default_codex_categories)
+}
+
+pub(crate) fn store_codex_categories(
+    conn: &Connection,
+    series_id: &str,
+    categories: &[CodexCategory],
+) -> Result<(), String> {
+    let value_json = serde_json::to_string(categories).map_err(|e| e.to_string())?;
+    series_setting_set(
+        conn,
+        series_id,
+        SERIES_CODEX_CATEGORIES,
+        &value_json,
+        now_millis(),
+    )
+}
+
+/// Display name for a category id, title-casing ids the set doesn't define
+pub(crate) fn category_display_name(categories: &[CodexCategory], category: &str) -> String {
+    if let Some(known) = categories.iter().find(|known| known.id == category) {
+        return known.name.clone();
+    }
+    let mut chars = category.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Uncategorized".to_string(),
+    }
+}
+
+/// Trims and validates a category set: ids are lowercase letters, digits and
+/// dashes, unique, and every category needs a name.
+fn normalize_categories(categories: Vec<CodexCategory>) -> Result<Vec<CodexCategory>, String> {
+    if categories.is_empty() {
+        return Err("At least one codex category is required".to_string());
+    }
+    let mut seen = HashSet::new();
+    categories
+        .into_iter()
+        .map(|category| {
+            let id = category.id.trim().to_lowercase();
+            let valid_id = !id.is_empty()
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+            if !valid_id {
+                return Err(format!(
+                    "Invalid codex category id '{}' (use lowercase letters, digits and dashes)",
+                    category.id
+                ));
+            }
+            if !seen.insert(id.clone()) {
+                return Err(format!("Duplicate codex category id '{id}'"));
+            }
+            let name = category.name.trim().to_string();
+            if name.is_empty() {
+                return Err(format!("Codex category '{id}' needs a name"));
+            }
+            let icon = category
+                .icon
+                .map(|icon| icon.trim().to_string())
+                .filter(|icon| !icon.is_empty());
+            Ok(CodexCategory { id, name, icon })
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn get_codex_categories(series_id: String) -> Result<Vec<CodexCategory>, String> {
+    validate_no_null_bytes(&series_id, "Series id")?;
+    let conn = open_app_db()?;
+    get_series(&conn, &series_id)?.ok_or_else(|| "Series not found".to_string())?;
+    Ok(codex_categories_for_series(&conn, &series_id))
+}
+
+/// Replaces the series' category set (order is display order). Entries in a
+/// category that is no longer listed keep it and show under its raw id.
+#[tauri::command]
+pub fn set_codex_categories(
+    series_id: String,
+    categories: Vec<CodexCategory>,
+) -> Result<Vec<CodexCategory>, String> {
+    validate_no_null_bytes(&series_id, "Series id")?;
+    let categories = normalize_categories(categories)?;
+    let conn = open_app_db()?;
+    get_series(&conn, &series_id)?.ok_or_else(|| "Series not found".to_string())?;
+    store_codex_categories(&conn, &series_id, &categories)?;
+    Ok(categories)
+}
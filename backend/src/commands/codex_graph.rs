@@ -0,0 +1,372 @@
+// Codex relationship graph export (GraphML for Gephi, DOT for Graphviz) and
+// the character relation matrix (CSV or markdown table)
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::codex::resolve_series_id;
+use crate::commands::series::{list_series_codex_entries, list_series_codex_relations};
+use crate::models::CodexRelationType;
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, escape_csv_field, escape_xml, resolve_export_path, validate_no_null_bytes,
+};
+
+const FALLBACK_CATEGORY_COLOR: &str = "#9ca3af";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexGraphExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RelationMatrixExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub character_count: usize,
+    /// Non-empty cells, counting each direction separately
+    pub filled_cell_count: usize,
+}
+
+struct GraphNode {
+    id: String,
+    label: String,
+    category: String,
+    color: &'static str,
+}
+
+struct GraphEdge {
+    id: String,
+    source: String,
+    target: String,
+    label: String,
+    directed: bool,
+}
+
+fn category_color(category: &str) -> &'static str {
+    match category {
+        "character" => "#3b82f6",
+        "location" => "#22c55e",
+        "item" => "#f59e0b",
+        "lore" => "#a855f7",
+        "subplot" => "#ef4444",
+        _ => FALLBACK_CATEGORY_COLOR,
+    }
+}
+
+fn load_relation_types(series_id: &str) -> Result<HashMap<String, CodexRelationType>, String> {
+    let conn = open_app_db()?;
+    let mut stmt = conn
+        .prepare("SELECT payload_json FROM codex_relation_types WHERE series_id = ?1")
+        .map_err(|e| format!("Failed to prepare relation type query: {e}"))?;
+    let rows = stmt
+        .query_map(params![series_id], |row| row.get::<_, String>(0))
+        .map_err(|e| format!("Failed to execute relation type query: {e}"))?;
+
+    let mut types = HashMap::new();
+    for row in rows {
+        let payload = row.map_err(|e| format!("Failed to decode relation type row: {e}"))?;
+        if let Ok(relation_type) = serde_json::from_str::<CodexRelationType>(&payload) {
+            types.insert(relation_type.id.clone(), relation_type);
+        }
+    }
+    Ok(types)
+}
+
+/// Nodes for every codex entry and edges for every relation whose endpoints
+/// both exist. Edges are directed only when their relation type says so.
+fn build_codex_graph(series_id: &str) -> Result<(Vec<GraphNode>, Vec<GraphEdge>), String> {
+    let mut entries = list_series_codex_entries(series_id.to_string(), None)?;
+    entries.sort_by(|a, b| {
+        a.category
+            .cmp(&b.category)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    let relations = list_series_codex_relations(series_id.to_string())?;
+    let relation_types = load_relation_types(series_id)?;
+
+    let node_ids: HashSet<&str> = entries.iter().map(|entry| entry.id.as_str()).collect();
+    let mut edges: Vec<GraphEdge> = relations
+        .iter()
+        .filter(|relation| {
+            node_ids.contains(relation.parent_id.as_str())
+                && node_ids.contains(relation.child_id.as_str())
+        })
+        .map(|relation| {
+            let relation_type = relation
+                .type_id
+                .as_ref()
+                .and_then(|type_id| relation_types.get(type_id));
+            let label = relation
+                .label
+                .as_deref()
+                .map(str::trim)
+                .filter(|label| !label.is_empty())
+                .map(str::to_string)
+                .or_else(|| relation_type.map(|relation_type| relation_type.name.clone()))
+                .unwrap_or_else(|| "related to".to_string());
+            GraphEdge {
+                id: relation.id.clone(),
+                source: relation.parent_id.clone(),
+                target: relation.child_id.clone(),
+                label,
+                directed: relation_type.is_some_and(|relation_type| relation_type.is_directional),
+            }
+        })
+        .collect();
+    edges.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let nodes = entries
+        .into_iter()
+        .map(|entry| GraphNode {
+            color: category_color(&entry.category),
+            id: entry.id,
+            label: entry.name,
+            category: entry.category,
+        })
+        .collect();
+    Ok((nodes, edges))
+}
+
+fn render_graphml(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+         \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"category\" for=\"node\" attr.name=\"category\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"color\" for=\"node\" attr.name=\"color\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"relation\" for=\"edge\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <graph id=\"codex\" edgedefault=\"undirected\">\n",
+    );
+    for node in nodes {
+        out.push_str(&format!(
+            "    <node id=\"{}\">\n      <data key=\"label\">{}</data>\n      \
+             <data key=\"category\">{}</data>\n      <data key=\"color\">{}</data>\n    </node>\n",
+            escape_xml(&node.id),
+            escape_xml(&node.label),
+            escape_xml(&node.category),
+            node.color
+        ));
+    }
+    for edge in edges {
+        out.push_str(&format!(
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\" directed=\"{}\">\n      \
+             <data key=\"relation\">{}</data>\n    </edge>\n",
+            escape_xml(&edge.id),
+            escape_xml(&edge.source),
+            escape_xml(&edge.target),
+            edge.directed,
+            escape_xml(&edge.label)
+        ));
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn dot_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+/// Always a digraph; undirected relations are drawn with `dir=none`.
+fn render_dot(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut out = String::from("digraph codex {\n  node [shape=box, style=filled];\n");
+    for node in nodes {
+        out.push_str(&format!(
+            "  {} [label={}, fillcolor={}, category={}];\n",
+            dot_string(&node.id),
+            dot_string(&node.label),
+            dot_string(node.color),
+            dot_string(&node.category)
+        ));
+    }
+    for edge in edges {
+        let dir = if edge.directed { "" } else { ", dir=none" };
+        out.push_str(&format!(
+            "  {} -> {} [label={}{dir}];\n",
+            dot_string(&edge.source),
+            dot_string(&edge.target),
+            dot_string(&edge.label)
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// The project to resolve relative output paths against, when the caller
+/// passed a project path rather than a series id
+fn project_path_for_export<'a>(
+    project_path_or_series_id: &'a str,
+    series_id: &str,
+) -> Option<&'a str> {
+    (project_path_or_series_id != series_id).then_some(project_path_or_series_id)
+}
+
+/// Writes the series codex as a graph with nodes per entry (coloured by
+/// category) and edges per relation. `format` is "graphml" or "dot".
+#[tauri::command]
+pub fn export_codex_graph(
+    project_path_or_series_id: String,
+    output_path: String,
+    format: String,
+) -> Result<CodexGraphExportResult, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    let format = match format.trim().to_lowercase().as_str() {
+        "graphml" => "graphml",
+        "dot" | "gv" => "dot",
+        other => return Err(format!("Unsupported codex graph format: {other}")),
+    };
+
+    let series_id = {
+        let conn = open_app_db()?;
+        resolve_series_id(&conn, &project_path_or_series_id)?
+    };
+    let target = resolve_export_path(
+        project_path_for_export(&project_path_or_series_id, &series_id),
+        &output_path,
+    )?;
+    let (nodes, edges) = build_codex_graph(&series_id)?;
+    let content = match format {
+        "dot" => render_dot(&nodes, &edges),
+        _ => render_graphml(&nodes, &edges),
+    };
+    atomic_write(&target, &content)?;
+
+    Ok(CodexGraphExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        node_count: nodes.len(),
+        edge_count: edges.len(),
+    })
+}
+
+/// Relation labels between characters, indexed [row][column]. Undirected
+/// relations fill both cells; directed ones only the source row.
+fn relation_matrix(nodes: &[GraphNode], edges: &[GraphEdge]) -> Vec<Vec<String>> {
+    let index: HashMap<&str, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(position, node)| (node.id.as_str(), position))
+        .collect();
+    let mut cells: Vec<Vec<BTreeSet<&str>>> = vec![vec![BTreeSet::new(); nodes.len()]; nodes.len()];
+    for edge in edges {
+        let (Some(&source), Some(&target)) = (
+            index.get(edge.source.as_str()),
+            index.get(edge.target.as_str()),
+        ) else {
+            continue;
+        };
+        cells[source][target].insert(&edge.label);
+        if !edge.directed {
+            cells[target][source].insert(&edge.label);
+        }
+    }
+    cells
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|labels| labels.into_iter().collect::<Vec<_>>().join("; "))
+                .collect()
+        })
+        .collect()
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn render_matrix_markdown(nodes: &[GraphNode], cells: &[Vec<String>]) -> String {
+    let mut out = String::from("|  |");
+    for node in nodes {
+        out.push_str(&format!(" {} |", markdown_cell(&node.label)));
+    }
+    out.push_str("\n|---|");
+    out.push_str(&"---|".repeat(nodes.len()));
+    out.push('\n');
+    for (node, row) in nodes.iter().zip(cells) {
+        out.push_str(&format!("| **{}** |", markdown_cell(&node.label)));
+        for cell in row {
+            out.push_str(&format!(" {} |", markdown_cell(cell)));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_matrix_csv(nodes: &[GraphNode], cells: &[Vec<String>]) -> String {
+    let header: Vec<String> = std::iter::once(String::new())
+        .chain(nodes.iter().map(|node| escape_csv_field(&node.label)))
+        .collect();
+    let mut out = header.join(",");
+    out.push('\n');
+    for (node, row) in nodes.iter().zip(cells) {
+        let line: Vec<String> = std::iter::once(escape_csv_field(&node.label))
+            .chain(row.iter().map(|cell| escape_csv_field(cell)))
+            .collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Writes a character-by-character table of relation types: CSV when
+/// `output_path` ends in `.csv`, a markdown table for `.md`/`.markdown`.
+#[tauri::command]
+pub fn export_relation_matrix(
+    project_path_or_series_id: String,
+    output_path: String,
+) -> Result<RelationMatrixExportResult, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    validate_no_null_bytes(&output_path, "Output path")?;
+    let extension = Path::new(&output_path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase);
+    let format = match extension.as_deref() {
+        Some("csv") => "csv",
+        Some("md") | Some("markdown") => "markdown",
+        _ => return Err("Relation matrix output must be a .csv or .md file".to_string()),
+    };
+
+    let series_id = {
+        let conn = open_app_db()?;
+        resolve_series_id(&conn, &project_path_or_series_id)?
+    };
+    let target = resolve_export_path(
+        project_path_for_export(&project_path_or_series_id, &series_id),
+        &output_path,
+    )?;
+    let (nodes, edges) = build_codex_graph(&series_id)?;
+    let characters: Vec<GraphNode> = nodes
+        .into_iter()
+        .filter(|node| node.category == "character")
+        .collect();
+    let cells = relation_matrix(&characters, &edges);
+    let content = match format {
+        "csv" => render_matrix_csv(&characters, &cells),
+        _ => render_matrix_markdown(&characters, &cells),
+    };
+    atomic_write(&target, &content)?;
+
+    Ok(RelationMatrixExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        character_count: characters.len(),
+        filled_cell_count: cells
+            .iter()
+            .flatten()
+            .filter(|cell| !cell.is_empty())
+            .count(),
+    })
+}
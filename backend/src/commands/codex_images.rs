@@ -0,0 +1,407 @@
+// Codex image storage, housekeeping and gallery management
+// Uploaded portraits live in `<app dir>/CodexImages/<series id>/`. Entries
+// store them in `image`, `thumbnail` and `gallery` as file names relative to
+// that directory: inline data URLs are written out to files when an entry is
+// saved, and names are resolved back to absolute paths for the UI. Web URLs
+// are not files and are left alone.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
+
+use crate::commands::codex::{get_codex_entry, resolve_series_id, upsert_codex_entry};
+use crate::commands::series::list_series_codex_entries;
+use crate::models::CodexEntry;
+use crate::storage::open_app_db;
+use crate::utils::{atomic_write_bytes, get_app_dir, timestamp, validate_no_null_bytes};
+
+const CODEX_IMAGES_DIR: &str = "CodexImages";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexImageGcResult {
+    pub removed_count: usize,
+    pub bytes_freed: u64,
+    pub kept_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingCodexImage {
+    pub entry_id: String,
+    pub entry_name: String,
+    /// "image", "thumbnail" or "gallery"
+    pub field: String,
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexImageValidation {
+    pub checked_count: usize,
+    pub missing_count: usize,
+    pub missing: Vec<MissingCodexImage>,
+}
+
+pub(crate) fn codex_images_dir(series_id: &str) -> Result<PathBuf, String> {
+    Ok(get_app_dir()?.join(CODEX_IMAGES_DIR).join(series_id))
+}
+
+/// Every image reference of an entry with the field it came from
+pub(crate) fn entry_image_refs(entry: &CodexEntry) -> Vec<(&'static str, &str)> {
+    let mut refs = Vec::new();
+    if let Some(image) = &entry.image {
+        refs.push(("image", image.as_str()));
+    }
+    if let Some(thumbnail) = &entry.thumbnail {
+        refs.push(("thumbnail", thumbnail.as_str()));
+    }
+    for path in entry.gallery.iter().flatten() {
+        refs.push(("gallery", path.as_str()));
+    }
+    refs
+}
+
+/// The file a reference points at, or `None` for data/web URLs and blanks
+pub(crate) fn local_image_path(images_dir: &Path, reference: &str) -> Option<PathBuf> {
+    let reference = reference.trim();
+    let lower = reference.to_lowercase();
+    if reference.is_empty()
+        || ["data:", "http://", "https://", "blob:", "asset:"]
+            .iter()
+            .any(|scheme| lower.starts_with(scheme))
+    {
+        return None;
+    }
+    if lower.starts_with("file://") {
+        return url::Url::parse(reference).ok()?.to_file_path().ok();
+    }
+    let path = Path::new(reference);
+    Some(if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        images_dir.join(path)
+    })
+}
+
+/// Lets the webview load codex images through the asset protocol
+pub fn allow_codex_image_assets(app: &AppHandle) {
+    let images_root = match get_app_dir() {
+        Ok(app_dir) => app_dir.join(CODEX_IMAGES_DIR),
+        Err(e) => {
+            log::warn!("Failed to resolve codex image directory: {e}");
+            return;
+        }
+    };
+    if let Err(e) = app
+        .asset_protocol_scope()
+        .allow_directory(&images_root, true)
+    {
+        log::warn!("Failed to allow codex image assets: {e}");
+    }
+}
+
+fn image_extension(mime: &str) -> Option<&'static str> {
+    match mime.to_ascii_lowercase().as_str() {
+        "image/png" => Some("png"),
+        "image/jpeg" | "image/jpg" => Some("jpg"),
+        "image/gif" => Some("gif"),
+        "image/webp" => Some("webp"),
+        "image/avif" => Some("avif"),
+        "image/bmp" => Some("bmp"),
+        "image/svg+xml" => Some("svg"),
+        _ => None,
+    }
+}
+
+/// A local reference inside `images_dir` as a name relative to it; anything
+/// else is returned unchanged.
+fn relative_image_ref(images_dir: &Path, reference: &str) -> String {
+    local_image_path(images_dir, reference)
+        .and_then(|path| {
+            path.strip_prefix(images_dir)
+                .ok()
+                .map(|relative| relative.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| reference.to_string())
+}
+
+/// Copies an image from another series' directory into `images_dir`, for
+/// entries copied between series. Returns the file name when it did.
+fn adopt_series_image(images_dir: &Path, reference: &str) -> Result<Option<String>, String> {
+    let Some(path) = local_image_path(images_dir, reference) else {
+        return Ok(None);
+    };
+    let from_other_series = path.parent() != Some(images_dir)
+        && path.parent().and_then(Path::parent) == images_dir.parent();
+    let Some(file_name) = path
+        .file_name()
+        .filter(|_| from_other_series && path.is_file())
+    else {
+        return Ok(None);
+    };
+    let target = images_dir.join(file_name);
+    if !target.is_file() {
+        fs::create_dir_all(images_dir)
+            .map_err(|e| format!("Failed to create codex image directory: {e}"))?;
+        fs::copy(&path, &target).map_err(|e| format!("Failed to copy codex image: {e}"))?;
+    }
+    Ok(Some(file_name.to_string_lossy().into_owned()))
+}
+
+/// Writes a base64 `data:image/...` URL to the images directory under a
+/// content-hash name and returns that name. Other references go through
+/// `relative_image_ref`, after pulling in images of other series.
+fn store_image_ref(images_dir: &Path, reference: &str) -> Result<String, String> {
+    let Some(data_url) = reference.trim().strip_prefix("data:") else {
+        let relative = relative_image_ref(images_dir, reference);
+        if relative != reference {
+            return Ok(relative);
+        }
+        return Ok(adopt_series_image(images_dir, reference)?.unwrap_or(relative));
+    };
+    let Some((header, data)) = data_url.split_once(',') else {
+        return Ok(reference.to_string());
+    };
+    let Some(mime) = header.strip_suffix(";base64") else {
+        return Ok(reference.to_string());
+    };
+    let Some(extension) = image_extension(mime) else {
+        return Ok(reference.to_string());
+    };
+    let bytes = STANDARD
+        .decode(data.trim())
+        .map_err(|e| format!("Failed to decode codex image: {e}"))?;
+
+    let file_name = format!("{:x}.{extension}", Sha256::digest(&bytes));
+    let path = images_dir.join(&file_name);
+    if !path.is_file() {
+        fs::create_dir_all(images_dir)
+            .map_err(|e| format!("Failed to create codex image directory: {e}"))?;
+        atomic_write_bytes(&path, &bytes)?;
+    }
+    Ok(file_name)
+}
+
+/// Copy of `entry` ready for storage: inline images written to the series'
+/// image directory and every local reference made relative to it.
+pub(crate) fn store_entry_images(
+    series_id: &str,
+    entry: &CodexEntry,
+) -> Result<CodexEntry, String> {
+    let images_dir = codex_images_dir(series_id)?;
+    let mut stored = entry.clone();
+    for slot in [&mut stored.image, &mut stored.thumbnail] {
+        if let Some(reference) = slot.as_mut() {
+            *reference = store_image_ref(&images_dir, reference)?;
+        }
+    }
+    for reference in stored.gallery.iter_mut().flatten() {
+        *reference = store_image_ref(&images_dir, reference)?;
+    }
+    Ok(stored)
+}
+
+/// Turns a stored relative image name into an absolute path the UI can load
+pub(crate) fn resolve_image_ref(images_dir: &Path, reference: &mut String) {
+    if let Some(path) = local_image_path(images_dir, reference) {
+        *reference = path.to_string_lossy().into_owned();
+    }
+}
+
+/// `resolve_image_ref` for every image of an entry
+pub(crate) fn resolve_entry_images(series_id: &str, entry: &mut CodexEntry) {
+    let Ok(images_dir) = codex_images_dir(series_id) else {
+        return;
+    };
+    let references = entry
+        .image
+        .iter_mut()
+        .chain(entry.thumbnail.iter_mut())
+        .chain(entry.gallery.iter_mut().flatten());
+    for reference in references {
+        resolve_image_ref(&images_dir, reference);
+    }
+}
+
+fn load_series_entries(
+    project_path_or_series_id: &str,
+) -> Result<(String, Vec<CodexEntry>), String> {
+    validate_no_null_bytes(project_path_or_series_id, "Project path or series id")?;
+    let series_id = {
+        let conn = open_app_db()?;
+        resolve_series_id(&conn, project_path_or_series_id)?
+    };
+    let entries = list_series_codex_entries(series_id.clone(), None)?;
+    Ok((series_id, entries))
+}
+
+/// Deletes files in the series' codex image directory that no entry
+/// references any more.
+#[tauri::command]
+pub fn gc_codex_images(project_path_or_series_id: String) -> Result<CodexImageGcResult, String> {
+    let (series_id, entries) = load_series_entries(&project_path_or_series_id)?;
+    let images_dir = codex_images_dir(&series_id)?;
+    let mut result = CodexImageGcResult {
+        removed_count: 0,
+        bytes_freed: 0,
+        kept_count: 0,
+    };
+    if !images_dir.is_dir() {
+        return Ok(result);
+    }
+
+    let referenced: HashSet<PathBuf> = entries
+        .iter()
+        .flat_map(entry_image_refs)
+        .filter_map(|(_, reference)| local_image_path(&images_dir, reference))
+        .collect();
+
+    let dir_entries = fs::read_dir(&images_dir)
+        .map_err(|e| format!("Failed to read codex image directory: {e}"))?;
+    for dir_entry in dir_entries.flatten() {
+        let path = dir_entry.path();
+        let Ok(metadata) = dir_entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        if referenced.contains(&path) {
+            result.kept_count += 1;
+            continue;
+        }
+        match fs::remove_file(&path) {
+            Ok(()) => {
+                result.removed_count += 1;
+                result.bytes_freed += metadata.len();
+            }
+            Err(e) => log::warn!(
+                "Failed to remove unused codex image {}: {e}",
+                path.display()
+            ),
+        }
+    }
+    Ok(result)
+}
+
+/// Lists entries whose image, thumbnail or gallery points at a missing file
+#[tauri::command]
+pub fn validate_codex_images(
+    project_path_or_series_id: String,
+) -> Result<CodexImageValidation, String> {
+    let (series_id, entries) = load_series_entries(&project_path_or_series_id)?;
+    let images_dir = codex_images_dir(&series_id)?;
+
+    let mut checked_count = 0;
+    let mut missing = Vec::new();
+    for entry in &entries {
+        for (field, reference) in entry_image_refs(entry) {
+            let Some(path) = local_image_path(&images_dir, reference) else {
+                continue;
+            };
+            checked_count += 1;
+            if !path.is_file() {
+                missing.push(MissingCodexImage {
+                    entry_id: entry.id.clone(),
+                    entry_name: entry.name.clone(),
+                    field: field.to_string(),
+                    path: reference.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(CodexImageValidation {
+        checked_count,
+        missing_count: missing.len(),
+        missing,
+    })
+}
+
+/// Rewrites an entry's gallery in the given order. `ordered_paths` must be a
+/// permutation of the current gallery.
+#[tauri::command]
+pub fn reorder_codex_gallery(
+    project_path_or_series_id: String,
+    entry_id: String,
+    ordered_paths: Vec<String>,
+) -> Result<CodexEntry, String> {
+    validate_no_null_bytes(&project_path_or_series_id, "Project path or series id")?;
+    validate_no_null_bytes(&entry_id, "Codex entry id")?;
+    let conn = open_app_db()?;
+    let series_id = resolve_series_id(&conn, &project_path_or_series_id)?;
+    let mut entry = get_codex_entry(&conn, &series_id, &entry_id)?
+        .ok_or_else(|| format!("Codex entry not found: {entry_id}"))?;
+
+    let images_dir = codex_images_dir(&series_id)?;
+    let ordered_paths: Vec<String> = ordered_paths
+        .iter()
+        .map(|path| relative_image_ref(&images_dir, path))
+        .collect();
+    let mut current = entry.gallery.clone().unwrap_or_default();
+    let mut requested = ordered_paths.clone();
+    current.sort();
+    requested.sort();
+    if current != requested {
+        return Err("Ordered paths must contain exactly the entry's gallery images".to_string());
+    }
+
+    entry.gallery = Some(ordered_paths);
+    entry.updated_at = timestamp::now_millis();
+    upsert_codex_entry(&conn, &series_id, &entry)?;
+    resolve_entry_images(&series_id, &mut entry);
+    Ok(entry)
+}
+
+/// Makes a gallery image the entry's main image and thumbnail. The previous
+/// main image and thumbnail are kept in the gallery (once each) so they are
+/// not garbage collected.
+#[tauri::command]
+pub fn set_primary_codex_image(entry_id: String, path: String) -> Result<CodexEntry, String> {
+    validate_no_null_bytes(&entry_id, "Codex entry id")?;
+    validate_no_null_bytes(&path, "Image path")?;
+    let conn = open_app_db()?;
+    let series_id: String = conn
+        .query_row(
+            "SELECT series_id FROM codex_entries WHERE id = ?1",
+            [&entry_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| format!("Codex entry not found: {entry_id}"))?;
+    let mut entry = get_codex_entry(&conn, &series_id, &entry_id)?
+        .ok_or_else(|| format!("Codex entry not found: {entry_id}"))?;
+
+    let images_dir = codex_images_dir(&series_id)?;
+    let path = relative_image_ref(&images_dir, &path);
+    let gallery = entry.gallery.get_or_insert_with(Vec::new);
+    if !gallery.contains(&path) {
+        return Err("Image is not in the entry's gallery".to_string());
+    }
+    if let Some(file) = local_image_path(&images_dir, &path) {
+        if !file.is_file() {
+            return Err(format!("Image file not found: {path}"));
+        }
+    }
+    for previous in [entry.image.take(), entry.thumbnail.take()]
+        .into_iter()
+        .flatten()
+    {
+        if previous != path && !gallery.contains(&previous) {
+            gallery.push(previous);
+        }
+    }
+
+    entry.image = Some(path.clone());
+    entry.thumbnail = Some(path);
+    entry.updated_at = timestamp::now_millis();
+    upsert_codex_entry(&conn, &series_id, &entry)?;
+    resolve_entry_images(&series_id, &mut entry);
+    Ok(entry)
+}
@@ -0,0 +1,258 @@
+// Data root commands (relocating the app data directory)
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::storage::{open_app_db, with_transaction};
+use crate::utils::{
+    default_app_dir, get_app_dir, set_configured_data_root, validate_no_null_bytes,
+    DATA_ROOT_CONFIG_FILE,
+};
+
+/// Columns that store absolute paths under the data root
+const PATH_COLUMNS: [(&str, &str); 10] = [
+    ("projects", "path"),
+    ("recent_projects", "project_path"),
+    ("deleted_projects", "original_path"),
+    ("deleted_projects", "trash_path"),
+    ("chat_threads", "project_path"),
+    ("chat_messages", "project_path"),
+    ("search_index", "project_path"),
+    ("search_sync_state", "project_path"),
+    ("yjs_snapshots", "project_path"),
+    ("yjs_update_log", "project_path"),
+];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DataRootInfo {
+    pub path: String,
+    pub default_path: String,
+    pub is_default: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DataRootMigrationResult {
+    pub old_path: String,
+    pub new_path: String,
+    pub files_copied: usize,
+    pub paths_updated: usize,
+}
+
+fn ensure_writable_dir(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path)
+        .map_err(|e| format!("Failed to create data directory '{}': {e}", path.display()))?;
+    let probe = path.join(format!(".write-test-{}", uuid::Uuid::new_v4()));
+    fs::write(&probe, b"ok")
+        .map_err(|e| format!("Data directory '{}' is not writable: {e}", path.display()))?;
+    let _ = fs::remove_file(&probe);
+    Ok(())
+}
+
+fn is_empty_dir(path: &Path) -> Result<bool, String> {
+    if !path.exists() {
+        return Ok(true);
+    }
+    let mut entries = fs::read_dir(path)
+        .map_err(|e| format!("Failed to read target directory '{}': {e}", path.display()))?;
+    Ok(entries.next().is_none())
+}
+
+fn resolve_target(new_path: &str) -> Result<PathBuf, String> {
+    validate_no_null_bytes(new_path, "Data root path")?;
+    let target = PathBuf::from(new_path.trim());
+    if !target.is_absolute() {
+        return Err("Data root must be an absolute path".to_string());
+    }
+    Ok(target)
+}
+
+#[tauri::command]
+pub fn get_data_root() -> Result<DataRootInfo, String> {
+    let current = get_app_dir()?;
+    let default = default_app_dir()?;
+    Ok(DataRootInfo {
+        is_default: current == default,
+        path: current.to_string_lossy().to_string(),
+        default_path: default.to_string_lossy().to_string(),
+    })
+}
+
+/// Points the app at an existing data directory without moving anything.
+/// Pass `None` to go back to the default location.
+#[tauri::command]
+pub fn set_data_root(new_path: Option<String>) -> Result<DataRootInfo, String> {
+    match new_path.filter(|path| !path.trim().is_empty()) {
+        Some(path) => {
+            let target = resolve_target(&path)?;
+            ensure_writable_dir(&target)?;
+            if target == default_app_dir()? {
+                set_configured_data_root(None)?;
+            } else {
+                set_configured_data_root(Some(&target))?;
+            }
+        }
+        None => set_configured_data_root(None)?,
+    }
+    get_data_root()
+}
+
+fn copy_data_dir(source: &Path, target: &Path) -> Result<usize, String> {
+    let mut copied = 0usize;
+    for entry in WalkDir::new(source) {
+        let entry = entry.map_err(|e| format!("Failed walking '{}': {e}", source.display()))?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .map_err(|e| format!("Failed deriving relative path while copying data: {e}"))?;
+        if relative.as_os_str() == DATA_ROOT_CONFIG_FILE {
+            continue;
+        }
+        let destination = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&destination).map_err(|e| e.to_string())?;
+        } else if entry.file_type().is_file() {
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            fs::copy(entry.path(), &destination).map_err(|e| {
+                format!(
+                    "Failed copying '{}' to '{}': {e}",
+                    entry.path().display(),
+                    destination.display()
+                )
+            })?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
+/// `value` moved from `old_root` to `new_root`, if it lies under `old_root`.
+/// Compares whole path components so `/data/App2` is not inside `/data/App`.
+fn relocated_path(value: &str, old_root: &Path, new_root: &Path) -> Option<String> {
+    let rest = Path::new(value).strip_prefix(old_root).ok()?;
+    let moved = if rest.as_os_str().is_empty() {
+        new_root.to_path_buf()
+    } else {
+        new_root.join(rest)
+    };
+    Some(moved.to_string_lossy().into_owned())
+}
+
+fn rewrite_stored_paths(old_root: &str, new_root: &str) -> Result<usize, String> {
+    let old_root = Path::new(old_root);
+    let new_root = Path::new(new_root);
+    let conn = open_app_db()?;
+    with_transaction(&conn, |conn| {
+        // chat_messages references chat_threads by path; defer the check to COMMIT.
+        conn.execute_batch("PRAGMA defer_foreign_keys = ON")
+            .map_err(|e| format!("Failed to defer foreign keys: {e}"))?;
+        let mut updated = 0usize;
+        for (table, column) in PATH_COLUMNS {
+            let rows = {
+                let mut stmt = conn
+                    .prepare(&format!(
+                        "SELECT rowid, {column} FROM {table} WHERE {column} IS NOT NULL"
+                    ))
+                    .map_err(|e| format!("Failed to read paths in {table}.{column}: {e}"))?;
+                let rows = stmt
+                    .query_map([], |row| {
+                        Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+                    })
+                    .map_err(|e| format!("Failed to read paths in {table}.{column}: {e}"))?;
+                rows.collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("Failed to read paths in {table}.{column}: {e}"))?
+            };
+            let sql = format!("UPDATE {table} SET {column} = ?1 WHERE rowid = ?2");
+            for (rowid, value) in rows {
+                let Some(moved) = relocated_path(&value, old_root, new_root) else {
+                    continue;
+                };
+                updated += conn
+                    .execute(&sql, params![moved, rowid])
+                    .map_err(|e| format!("Failed to update paths in {table}.{column}: {e}"))?;
+            }
+        }
+        Ok(updated)
+    })
+}
+
+fn remove_old_data(old_dir: &Path) -> Result<(), String> {
+    let entries =
+        fs::read_dir(old_dir).map_err(|e| format!("Failed to read old data directory: {e}"))?;
+    for entry in entries.flatten() {
+        if entry.file_name() == DATA_ROOT_CONFIG_FILE {
+            continue;
+        }
+        let path = entry.path();
+        let result = if path.is_dir() {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        result.map_err(|e| format!("Failed to remove old data '{}': {e}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Moves all app data (database, projects, backups) to `new_path` and
+/// rewrites stored absolute paths. The target must be empty unless
+/// `overwrite` is set.
+#[tauri::command]
+pub fn migrate_data_root(
+    new_path: String,
+    overwrite: Option<bool>,
+) -> Result<DataRootMigrationResult, String> {
+    let target = resolve_target(&new_path)?;
+    let current = get_app_dir()?;
+    if target == current {
+        return Err("Data is already stored at that location".to_string());
+    }
+    if target.starts_with(&current) || current.starts_with(&target) {
+        return Err("New data root cannot be inside the current one (or vice versa)".to_string());
+    }
+    if !overwrite.unwrap_or(false) && !is_empty_dir(&target)? {
+        return Err("[E_TARGET_NOT_EMPTY] Target directory is not empty".to_string());
+    }
+    ensure_writable_dir(&target)?;
+
+    {
+        let conn = open_app_db()?;
+        conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+            .map_err(|e| format!("Failed to checkpoint database before migration: {e}"))?;
+    }
+
+    let files_copied = copy_data_dir(&current, &target)?;
+    let default = default_app_dir()?;
+    if target == default {
+        set_configured_data_root(None)?;
+    } else {
+        set_configured_data_root(Some(&target))?;
+    }
+
+    let old_root = current.to_string_lossy().to_string();
+    let new_root = target.to_string_lossy().to_string();
+    let paths_updated = match rewrite_stored_paths(&old_root, &new_root) {
+        Ok(count) => count,
+        Err(e) => {
+            let previous = (current != default).then_some(current.as_path());
+            let _ = set_configured_data_root(previous);
+            return Err(e);
+        }
+    };
+
+    remove_old_data(&current)?;
+
+    Ok(DataRootMigrationResult {
+        old_path: old_root,
+        new_path: new_root,
+        files_copied,
+        paths_updated,
+    })
+}
@@ -0,0 +1,355 @@
+// Scheduled Google Drive backups
+// Each enabled project gets a worker thread that uploads a fresh package every
+// `interval_minutes` through `upload_backup_to_drive`, skipping runs where
+// nothing changed. Schedules live in secure storage so they resume on launch.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+use walkdir::WalkDir;
+
+use crate::commands::google_drive::upload_backup_to_drive;
+use crate::commands::project::get_project_by_path;
+use crate::commands::security::{get_secret_for_account, store_secret_for_account};
+use crate::storage::open_app_db;
+use crate::utils::{now_millis, validate_no_null_bytes};
+
+const SCHEDULE_NAMESPACE: &str = "drive";
+const SCHEDULE_PROVIDER: &str = "google";
+const SCHEDULE_ACCOUNT: &str = "auto-backup-schedules";
+pub const AUTO_BACKUP_EVENT: &str = "drive-auto-backup";
+const MIN_INTERVAL_MINUTES: u64 = 5;
+const MAX_INTERVAL_MINUTES: u64 = 7 * 24 * 60;
+
+/// Serialises read-modify-write of the persisted schedule list across workers
+static SCHEDULE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PersistedSchedule {
+    project_path: String,
+    client_id: String,
+    #[serde(default)]
+    client_secret: Option<String>,
+    interval_minutes: u64,
+    #[serde(default)]
+    last_backup_at: Option<i64>,
+    /// Newest change stamp included in the last successful upload
+    #[serde(default)]
+    last_change_stamp: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupStatus {
+    pub project_path: String,
+    pub interval_minutes: u64,
+    pub last_backup_at: Option<i64>,
+}
+
+/// Payload of the `drive-auto-backup` event
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupEvent {
+    pub project_path: String,
+    pub success: bool,
+    pub file_id: Option<String>,
+    pub error: Option<String>,
+    pub timestamp: i64,
+}
+
+/// Stop flags for the running workers, keyed by project path
+#[derive(Default)]
+pub struct AutoBackupScheduler {
+    workers: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl AutoBackupScheduler {
+    fn start(&self, app: AppHandle, schedule: PersistedSchedule) {
+        let stop = Arc::new(AtomicBool::new(false));
+        if let Ok(mut workers) = self.workers.lock() {
+            if let Some(previous) = workers.insert(schedule.project_path.clone(), stop.clone()) {
+                previous.store(true, Ordering::SeqCst);
+            }
+        }
+        thread::spawn(move || run_worker(app, schedule, stop));
+    }
+
+    fn stop(&self, project_path: &str) -> bool {
+        let Ok(mut workers) = self.workers.lock() else {
+            return false;
+        };
+        match workers.remove(project_path) {
+            Some(stop) => {
+                stop.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn load_schedules() -> Result<Vec<PersistedSchedule>, String> {
+    let conn = open_app_db()?;
+    match get_secret_for_account(
+        &conn,
+        SCHEDULE_NAMESPACE,
+        SCHEDULE_PROVIDER,
+        SCHEDULE_ACCOUNT,
+    )? {
+        Some(payload) => serde_json::from_str(&payload)
+            .map_err(|e| format!("Failed to parse auto-backup schedules: {e}")),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn store_schedules(schedules: &[PersistedSchedule]) -> Result<(), String> {
+    let payload = serde_json::to_string(schedules)
+        .map_err(|e| format!("Failed to serialize auto-backup schedules: {e}"))?;
+    let conn = open_app_db()?;
+    store_secret_for_account(
+        &conn,
+        SCHEDULE_NAMESPACE,
+        SCHEDULE_PROVIDER,
+        SCHEDULE_ACCOUNT,
+        &payload,
+    )
+    .map_err(|e| format!("Failed to store auto-backup schedules: {e}"))
+}
+
+fn update_schedules<T>(update: impl FnOnce(&mut Vec<PersistedSchedule>) -> T) -> Result<T, String> {
+    let _guard = SCHEDULE_LOCK
+        .lock()
+        .map_err(|_| "Auto-backup schedule lock poisoned".to_string())?;
+    let mut schedules = load_schedules()?;
+    let result = update(&mut schedules);
+    store_schedules(&schedules)?;
+    Ok(result)
+}
+
+fn max_updated_at(conn: &Connection, sql: &str, key: &str) -> Result<Option<i64>, String> {
+    conn.query_row(sql, params![key], |row| row.get(0))
+        .map_err(|e| format!("Failed to read change stamp: {e}"))
+}
+
+fn newest_file_mtime(root: &Path) -> Option<i64> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .filter_map(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .max()
+}
+
+/// Newest modification time (ms) across the project's rows, its series codex
+/// and the files under the project folder.
+fn project_change_stamp(project_path: &str) -> Result<i64, String> {
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, project_path)?;
+    let mut newest = project.updated_at;
+    for table in [
+        "structure_nodes",
+        "scene_metadata",
+        "scene_notes",
+        "snippets",
+    ] {
+        let sql = format!("SELECT MAX(updated_at) FROM {table} WHERE project_id = ?1");
+        if let Some(stamp) = max_updated_at(&conn, &sql, &project.id)? {
+            newest = newest.max(stamp);
+        }
+    }
+    if let Some(stamp) = max_updated_at(
+        &conn,
+        "SELECT MAX(updated_at) FROM codex_entries WHERE series_id = ?1",
+        &project.series_id,
+    )? {
+        newest = newest.max(stamp);
+    }
+    if let Some(stamp) = newest_file_mtime(Path::new(project_path)) {
+        newest = newest.max(stamp);
+    }
+    Ok(newest)
+}
+
+/// Sleeps for `duration` in short ticks; returns false once stopped.
+fn sleep_unless_stopped(stop: &AtomicBool, duration: Duration) -> bool {
+    let tick = Duration::from_secs(1);
+    let mut remaining = duration;
+    while !remaining.is_zero() {
+        if stop.load(Ordering::SeqCst) {
+            return false;
+        }
+        let step = remaining.min(tick);
+        thread::sleep(step);
+        remaining -= step;
+    }
+    !stop.load(Ordering::SeqCst)
+}
+
+/// Runs one scheduled backup. Returns `None` when nothing changed.
+fn run_scheduled_backup(schedule: &PersistedSchedule) -> Option<AutoBackupEvent> {
+    let project_path = schedule.project_path.clone();
+    let failed = |error: String| AutoBackupEvent {
+        project_path: project_path.clone(),
+        success: false,
+        file_id: None,
+        error: Some(error),
+        timestamp: now_millis(),
+    };
+
+    let stamp = match project_change_stamp(&schedule.project_path) {
+        Ok(stamp) => stamp,
+        Err(e) => return Some(failed(e)),
+    };
+    let last_stamp = load_schedules()
+        .ok()
+        .and_then(|schedules| {
+            schedules
+                .into_iter()
+                .find(|s| s.project_path == schedule.project_path)
+        })
+        .and_then(|s| s.last_change_stamp);
+    if last_stamp.is_some_and(|last| stamp <= last) {
+        return None;
+    }
+
+    let uploaded = tauri::async_runtime::block_on(upload_backup_to_drive(
+        schedule.client_id.clone(),
+        schedule.client_secret.clone(),
+        schedule.project_path.clone(),
+    ));
+    match uploaded {
+        Ok(result) => {
+            let timestamp = now_millis();
+            let recorded = update_schedules(|schedules| {
+                if let Some(entry) = schedules
+                    .iter_mut()
+                    .find(|s| s.project_path == schedule.project_path)
+                {
+                    entry.last_backup_at = Some(timestamp);
+                    entry.last_change_stamp = Some(stamp);
+                }
+            });
+            if let Err(e) = recorded {
+                log::warn!(
+                    "Failed to record auto-backup for {}: {e}",
+                    schedule.project_path
+                );
+            }
+            Some(AutoBackupEvent {
+                project_path,
+                success: true,
+                file_id: Some(result.file_id),
+                error: None,
+                timestamp,
+            })
+        }
+        Err(e) => Some(failed(e)),
+    }
+}
+
+fn run_worker(app: AppHandle, schedule: PersistedSchedule, stop: Arc<AtomicBool>) {
+    let interval = Duration::from_secs(schedule.interval_minutes * 60);
+    while sleep_unless_stopped(&stop, interval) {
+        if let Some(event) = run_scheduled_backup(&schedule) {
+            if let Some(error) = &event.error {
+                log::warn!("Auto-backup of {} failed: {error}", schedule.project_path);
+            }
+            if let Err(e) = app.emit(AUTO_BACKUP_EVENT, &event) {
+                log::warn!("Failed to emit auto-backup event: {e}");
+            }
+        }
+    }
+}
+
+/// Restarts every persisted schedule. Called once from app setup.
+pub fn resume_auto_backups(app: &AppHandle) {
+    let schedules = match load_schedules() {
+        Ok(schedules) => schedules,
+        Err(e) => {
+            log::warn!("Failed to load auto-backup schedules: {e}");
+            return;
+        }
+    };
+    let scheduler = app.state::<AutoBackupScheduler>();
+    for schedule in schedules {
+        scheduler.start(app.clone(), schedule);
+    }
+}
+
+/// Uploads the project to Drive every `interval_minutes` while it has changes.
+/// Replaces any existing schedule for the project.
+#[tauri::command]
+pub fn enable_auto_backup(
+    app: AppHandle,
+    scheduler: State<'_, AutoBackupScheduler>,
+    client_id: String,
+    client_secret: Option<String>,
+    project_path: String,
+    interval_minutes: u64,
+) -> Result<AutoBackupStatus, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    if client_id.trim().is_empty() {
+        return Err("Google client_id is required".to_string());
+    }
+    if !(MIN_INTERVAL_MINUTES..=MAX_INTERVAL_MINUTES).contains(&interval_minutes) {
+        return Err(format!(
+            "Auto-backup interval must be between {MIN_INTERVAL_MINUTES} and {MAX_INTERVAL_MINUTES} minutes"
+        ));
+    }
+    {
+        let conn = open_app_db()?;
+        get_project_by_path(&conn, &project_path)?;
+    }
+
+    let schedule = update_schedules(|schedules| {
+        let previous = schedules
+            .iter()
+            .position(|s| s.project_path == project_path)
+            .map(|index| schedules.remove(index));
+        let schedule = PersistedSchedule {
+            project_path: project_path.clone(),
+            client_id,
+            client_secret,
+            interval_minutes,
+            last_backup_at: previous.as_ref().and_then(|s| s.last_backup_at),
+            last_change_stamp: previous.and_then(|s| s.last_change_stamp),
+        };
+        schedules.push(schedule.clone());
+        schedule
+    })?;
+
+    let status = AutoBackupStatus {
+        project_path: schedule.project_path.clone(),
+        interval_minutes: schedule.interval_minutes,
+        last_backup_at: schedule.last_backup_at,
+    };
+    scheduler.start(app, schedule);
+    Ok(status)
+}
+
+/// Stops and forgets the project's auto-backup schedule. Returns whether one
+/// was enabled.
+#[tauri::command]
+pub fn disable_auto_backup(
+    scheduler: State<'_, AutoBackupScheduler>,
+    project_path: String,
+) -> Result<bool, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let stopped = scheduler.stop(&project_path);
+    let removed = update_schedules(|schedules| {
+        let before = schedules.len();
+        schedules.retain(|s| s.project_path != project_path);
+        schedules.len() != before
+    })?;
+    Ok(stopped || removed)
+}
@@ -0,0 +1,994 @@
+// Manuscript exports (plain text, streamed scene by scene)
+// Whole-book DOCX/EPUB/PDF are rendered by the frontend's DocumentExportService;
+// single chapters can be written as PDF here for sharing with readers.
+// Book-length exports can run as background jobs that report progress through
+// `export-progress` events and finish with `export-complete` or `export-error`.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::commands::project::{
+    find_node, get_project_by_path, get_structure, get_structure_with_counts,
+    StructureNodeWithCounts,
+};
+use crate::commands::scene::read_scene_content;
+use crate::models::StructureNode;
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, count_words, escape_csv_field, extract_paragraphs, render_markdown,
+    resolve_export_path, validate_no_null_bytes, write_docx, write_pdf, DocBlock,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ManuscriptExportResult {
+    pub output_path: String,
+    pub scenes_written: usize,
+    pub bytes_written: u64,
+}
+
+pub const EXPORT_PROGRESS_EVENT: &str = "export-progress";
+pub const EXPORT_COMPLETE_EVENT: &str = "export-complete";
+pub const EXPORT_ERROR_EVENT: &str = "export-error";
+const EXPORT_CANCELLED: &str = "Export cancelled";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProgressEvent {
+    pub job_id: String,
+    pub percent: u8,
+    pub scenes_written: usize,
+    pub scene_count: usize,
+    pub current_chapter: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCompleteEvent {
+    pub job_id: String,
+    pub result: ManuscriptExportResult,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportErrorEvent {
+    pub job_id: String,
+    pub error: String,
+    pub cancelled: bool,
+}
+
+/// Cancel flags of running export jobs, keyed by job id
+#[derive(Default)]
+pub struct ExportJobs {
+    jobs: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl ExportJobs {
+    fn register(&self, job_id: &str) -> Arc<AtomicBool> {
+        let cancel = Arc::new(AtomicBool::new(false));
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.insert(job_id.to_string(), cancel.clone());
+        }
+        cancel
+    }
+
+    fn finish(&self, job_id: &str) {
+        if let Ok(mut jobs) = self.jobs.lock() {
+            jobs.remove(job_id);
+        }
+    }
+
+    fn cancel(&self, job_id: &str) -> bool {
+        let Ok(jobs) = self.jobs.lock() else {
+            return false;
+        };
+        match jobs.get(job_id) {
+            Some(cancel) => {
+                cancel.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Called after each scene with the number written so far and the chapter
+/// being written; an error stops the export.
+type SceneProgress<'a> = &'a mut dyn FnMut(usize, Option<&str>) -> Result<(), String>;
+
+const DEFAULT_SCENE_BREAK: &str = "* * *";
+
+fn default_scene_break() -> String {
+    DEFAULT_SCENE_BREAK.to_string()
+}
+
+/// Options shared by the manuscript exporters
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ManuscriptExportOptions {
+    /// Marker between sibling scenes, e.g. "* * *" or "#". Blank means an
+    /// empty line.
+    #[serde(default = "default_scene_break")]
+    pub scene_break: String,
+    /// Reading copy: omit scene titles, keep the scene breaks
+    #[serde(default)]
+    pub hide_scene_titles: bool,
+    /// Strip "Chapter 3:" style numbering from chapter titles
+    #[serde(default)]
+    pub hide_chapter_numbers: bool,
+}
+
+impl Default for ManuscriptExportOptions {
+    fn default() -> Self {
+        Self {
+            scene_break: default_scene_break(),
+            hide_scene_titles: false,
+            hide_chapter_numbers: false,
+        }
+    }
+}
+
+/// Value of an upper- or lowercase roman numeral in canonical form ("IV", not
+/// "IIII"); None for anything else, so words like "Mild" or "Mix" never count.
+fn roman_numeral_value(token: &str) -> Option<u32> {
+    let upper = token.to_ascii_uppercase();
+    if token.is_empty() || (token != upper && token != token.to_ascii_lowercase()) {
+        return None;
+    }
+    let digit = |c: char| match c {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    };
+    let digits = upper.chars().map(digit).collect::<Option<Vec<i64>>>()?;
+    let mut value = 0;
+    for (index, &current) in digits.iter().enumerate() {
+        match digits.get(index + 1) {
+            Some(&next) if next > current => value -= current,
+            _ => value += current,
+        }
+    }
+    let value = u32::try_from(value).ok().filter(|&v| v > 0 && v < 4000)?;
+    (to_roman_numeral(value) == upper).then_some(value)
+}
+
+fn to_roman_numeral(mut value: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut numeral = String::new();
+    for (amount, symbol) in NUMERALS {
+        while value >= amount {
+            numeral.push_str(symbol);
+            value -= amount;
+        }
+    }
+    numeral
+}
+
+fn is_number_delimiter(c: char) -> bool {
+    matches!(c, ':' | '.' | '-' | '\u{2013}' | '\u{2014}')
+}
+
+/// Drops a leading chapter number: "Chapter 3: The Storm", "Chapter IV The
+/// Storm" and "3. The Storm" become "The Storm". Roman numerals are only
+/// recognised after the word "Chapter", and a number without that word must
+/// be followed by punctuation. The title is kept as-is when nothing would be
+/// left, e.g. a bare "Chapter IV".
+fn strip_chapter_number(title: &str) -> String {
+    let trimmed = title.trim();
+    let mut rest = trimmed;
+    let has_chapter_word = rest
+        .get(..7)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("chapter"));
+    if has_chapter_word {
+        let after = &rest[7..];
+        if !after.starts_with(char::is_whitespace) {
+            return trimmed.to_string();
+        }
+        rest = after.trim_start();
+    }
+
+    let token_end = rest
+        .find(|c: char| c.is_whitespace() || is_number_delimiter(c))
+        .unwrap_or(rest.len());
+    let token = &rest[..token_end];
+    let is_number = !token.is_empty()
+        && (token.chars().all(|c| c.is_ascii_digit())
+            || (has_chapter_word && roman_numeral_value(token).is_some()));
+    let delimiter = rest[token_end..].chars().next();
+    let delimited = match delimiter {
+        Some(c) if is_number_delimiter(c) => true,
+        Some(c) => has_chapter_word && c.is_whitespace(),
+        None => false,
+    };
+    if !is_number || !delimited {
+        return trimmed.to_string();
+    }
+
+    let stripped =
+        rest[token_end..].trim_start_matches(|c: char| c.is_whitespace() || is_number_delimiter(c));
+    if stripped.is_empty() {
+        trimmed.to_string()
+    } else {
+        stripped.to_string()
+    }
+}
+
+/// Receives the manuscript writer's output: plain text for manuscript and
+/// `.txt` exports, or document blocks for the docx/markdown renderers
+trait ManuscriptSink {
+    fn heading(&mut self, level: u8, text: &str) -> Result<(), String>;
+    fn paragraph(&mut self, text: &str) -> Result<(), String>;
+    /// A scene break with a blank marker
+    fn blank_line(&mut self) -> Result<(), String>;
+}
+
+struct TextSink<W: Write> {
+    out: W,
+    bytes_written: u64,
+    wrote_any: bool,
+}
+
+impl<W: Write> TextSink<W> {
+    fn new(out: W) -> Self {
+        Self {
+            out,
+            bytes_written: 0,
+            wrote_any: false,
+        }
+    }
+
+    fn write_str(&mut self, value: &str) -> Result<(), String> {
+        self.out
+            .write_all(value.as_bytes())
+            .map_err(|e| format!("Failed to write manuscript export: {e}"))?;
+        self.bytes_written += value.len() as u64;
+        Ok(())
+    }
+
+    fn write_block(&mut self, value: &str) -> Result<(), String> {
+        if self.wrote_any {
+            self.write_str("\n\n")?;
+        }
+        self.wrote_any = true;
+        self.write_str(value)
+    }
+
+    fn finish(mut self) -> Result<(W, u64), String> {
+        if self.wrote_any {
+            self.write_str("\n")?;
+        }
+        self.out
+            .flush()
+            .map_err(|e| format!("Failed to flush manuscript export: {e}"))?;
+        Ok((self.out, self.bytes_written))
+    }
+}
+
+impl<W: Write> ManuscriptSink for TextSink<W> {
+    fn heading(&mut self, _level: u8, text: &str) -> Result<(), String> {
+        self.write_block(text)
+    }
+
+    fn paragraph(&mut self, text: &str) -> Result<(), String> {
+        self.write_block(text)
+    }
+
+    fn blank_line(&mut self) -> Result<(), String> {
+        // One extra blank line on top of the usual paragraph gap
+        if self.wrote_any {
+            self.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl ManuscriptSink for Vec<DocBlock> {
+    fn heading(&mut self, level: u8, text: &str) -> Result<(), String> {
+        self.push(DocBlock::Heading(level, text.to_string()));
+        Ok(())
+    }
+
+    fn paragraph(&mut self, text: &str) -> Result<(), String> {
+        self.push(DocBlock::Paragraph(text.to_string()));
+        Ok(())
+    }
+
+    fn blank_line(&mut self) -> Result<(), String> {
+        if !self.is_empty() {
+            self.push(DocBlock::Paragraph(String::new()));
+        }
+        Ok(())
+    }
+}
+
+struct ManuscriptWriter<'a, S: ManuscriptSink> {
+    project_path: &'a str,
+    options: &'a ManuscriptExportOptions,
+    out: S,
+    scenes_written: usize,
+    words_written: i32,
+    current_chapter: Option<String>,
+    on_scene: Option<SceneProgress<'a>>,
+}
+
+impl<'a, S: ManuscriptSink> ManuscriptWriter<'a, S> {
+    fn new(project_path: &'a str, options: &'a ManuscriptExportOptions, out: S) -> Self {
+        Self {
+            project_path,
+            options,
+            out,
+            scenes_written: 0,
+            words_written: 0,
+            current_chapter: None,
+            on_scene: None,
+        }
+    }
+
+    fn write_scene(&mut self, node: &StructureNode) -> Result<(), String> {
+        let Some(file) = &node.file else {
+            return Ok(());
+        };
+        let path = PathBuf::from(self.project_path)
+            .join("manuscript")
+            .join(file);
+        let content = read_scene_content(&path)
+            .map_err(|e| format!("Failed to read scene '{}': {e}", path.display()))?;
+
+        if !self.options.hide_scene_titles {
+            self.out.heading(2, &node.title)?;
+        }
+        for paragraph in extract_paragraphs(&content) {
+            self.words_written += count_words(&paragraph);
+            self.out.paragraph(&paragraph)?;
+        }
+        self.scenes_written += 1;
+        if let Some(on_scene) = self.on_scene.as_mut() {
+            on_scene(self.scenes_written, self.current_chapter.as_deref())?;
+        }
+        Ok(())
+    }
+
+    fn write_scene_break(&mut self) -> Result<(), String> {
+        let marker = self.options.scene_break.trim();
+        if marker.is_empty() {
+            return self.out.blank_line();
+        }
+        self.out.paragraph(marker)
+    }
+
+    fn write_chapter_heading(&mut self, chapter: &StructureNode) -> Result<(), String> {
+        self.current_chapter = Some(chapter.title.clone());
+        if !self.options.hide_chapter_numbers {
+            return self.out.heading(1, &chapter.title);
+        }
+        let title = strip_chapter_number(&chapter.title);
+        if title.is_empty() {
+            return Ok(());
+        }
+        self.out.heading(1, &title)
+    }
+
+    fn write_nodes(&mut self, nodes: &[StructureNode]) -> Result<(), String> {
+        let mut previous_was_scene = false;
+        for node in nodes {
+            let is_scene = node.node_type == "scene";
+            if is_scene && previous_was_scene {
+                self.write_scene_break()?;
+            }
+            previous_was_scene = is_scene;
+
+            match node.node_type.as_str() {
+                "scene" => self.write_scene(node)?,
+                "act" => {
+                    self.out.heading(1, &node.title.to_uppercase())?;
+                    self.write_nodes(&node.children)?;
+                }
+                "chapter" => {
+                    self.write_chapter_heading(node)?;
+                    self.write_nodes(&node.children)?;
+                }
+                _ => {
+                    self.out.heading(1, &node.title)?;
+                    self.write_nodes(&node.children)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the given scenes in order, separated by scene breaks. With
+    /// `chapter_headers` a chapter heading replaces the break whenever the
+    /// selection moves into a different chapter.
+    fn write_selection(
+        &mut self,
+        scenes: &[(&StructureNode, Option<&StructureNode>)],
+        chapter_headers: bool,
+    ) -> Result<(), String> {
+        let mut current_chapter: Option<&str> = None;
+        for (position, &(scene, chapter)) in scenes.iter().enumerate() {
+            let chapter_id = chapter.map(|chapter| chapter.id.as_str());
+            let new_chapter = chapter_headers && chapter_id != current_chapter;
+            current_chapter = chapter_id;
+            match chapter.filter(|_| new_chapter) {
+                Some(chapter) => self.write_chapter_heading(chapter)?,
+                None if position > 0 => self.write_scene_break()?,
+                None => {}
+            }
+            self.write_scene(scene)?;
+        }
+        Ok(())
+    }
+}
+
+fn load_export_source(project_path: &str) -> Result<(String, Vec<StructureNode>), String> {
+    validate_no_null_bytes(project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, project_path)?;
+    let structure = get_structure(project_path.to_string())?;
+    Ok((project.path, structure))
+}
+
+/// Builds the whole manuscript as plain text in memory. Intended for small
+/// previews; use [`export_manuscript_to_file`] for full books.
+#[tauri::command]
+pub fn export_manuscript_text(
+    project_path: String,
+    options: Option<ManuscriptExportOptions>,
+) -> Result<String, String> {
+    let (root, structure) = load_export_source(&project_path)?;
+    let options = options.unwrap_or_default();
+    let mut writer = ManuscriptWriter::new(&root, &options, TextSink::new(Vec::new()));
+    writer.write_nodes(&structure)?;
+    let (buffer, _) = writer.out.finish()?;
+    String::from_utf8(buffer).map_err(|e| format!("Manuscript export is not valid UTF-8: {e}"))
+}
+
+fn temp_export_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("export");
+    path.with_file_name(format!(".{file_name}.{}.tmp", uuid::Uuid::new_v4()))
+}
+
+fn count_scenes(nodes: &[StructureNode]) -> usize {
+    nodes
+        .iter()
+        .map(|node| usize::from(node.node_type == "scene") + count_scenes(&node.children))
+        .sum()
+}
+
+/// Streams the manuscript into a temp file next to `target` and moves it into
+/// place once complete, so a failed or cancelled export leaves nothing behind.
+fn write_manuscript_file<'a>(
+    root: &'a str,
+    structure: &[StructureNode],
+    options: &'a ManuscriptExportOptions,
+    target: &Path,
+    on_scene: Option<SceneProgress<'a>>,
+) -> Result<ManuscriptExportResult, String> {
+    if target.is_dir() {
+        return Err("Target path is a directory".to_string());
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create export directory: {e}"))?;
+    }
+
+    let temp_path = temp_export_path(target);
+    let file =
+        File::create(&temp_path).map_err(|e| format!("Failed to create export file: {e}"))?;
+    let mut writer = ManuscriptWriter::new(root, options, TextSink::new(BufWriter::new(file)));
+    writer.on_scene = on_scene;
+
+    let written = writer.write_nodes(structure).and_then(|_| {
+        let scenes = writer.scenes_written;
+        let (out, bytes) = writer.out.finish()?;
+        out.into_inner()
+            .map_err(|e| format!("Failed to flush manuscript export: {e}"))?
+            .sync_all()
+            .map_err(|e| format!("Failed to sync manuscript export: {e}"))?;
+        Ok((scenes, bytes))
+    });
+
+    let (scenes_written, bytes_written) = match written {
+        Ok(result) => result,
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+    };
+
+    fs::rename(&temp_path, target).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to move export into place: {e}")
+    })?;
+
+    Ok(ManuscriptExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        scenes_written,
+        bytes_written,
+    })
+}
+
+/// Streams the manuscript to `output_path` one scene at a time, so memory use
+/// stays bounded by the largest scene rather than the whole book.
+#[tauri::command]
+pub fn export_manuscript_to_file(
+    project_path: String,
+    output_path: String,
+    options: Option<ManuscriptExportOptions>,
+) -> Result<ManuscriptExportResult, String> {
+    let (root, structure) = load_export_source(&project_path)?;
+    let target = resolve_export_path(Some(&root), &output_path)?;
+    let options = options.unwrap_or_default();
+    write_manuscript_file(&root, &structure, &options, &target, None)
+}
+
+fn run_manuscript_export_job(
+    app: AppHandle,
+    job_id: String,
+    cancel: Arc<AtomicBool>,
+    source: (String, Vec<StructureNode>),
+    options: ManuscriptExportOptions,
+    target: PathBuf,
+) {
+    let (root, structure) = source;
+    let scene_count = count_scenes(&structure);
+    let mut on_scene = |scenes_written: usize, chapter: Option<&str>| {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(EXPORT_CANCELLED.to_string());
+        }
+        let progress = ExportProgressEvent {
+            job_id: job_id.clone(),
+            percent: (scenes_written * 100 / scene_count.max(1)).min(100) as u8,
+            scenes_written,
+            scene_count,
+            current_chapter: chapter.map(str::to_string),
+        };
+        if let Err(e) = app.emit(EXPORT_PROGRESS_EVENT, &progress) {
+            log::warn!("Failed to emit export progress: {e}");
+        }
+        Ok(())
+    };
+    let result = write_manuscript_file(&root, &structure, &options, &target, Some(&mut on_scene));
+    app.state::<ExportJobs>().finish(&job_id);
+
+    let emitted = match result {
+        Ok(result) => app.emit(
+            EXPORT_COMPLETE_EVENT,
+            &ExportCompleteEvent { job_id, result },
+        ),
+        Err(error) => {
+            let cancelled = cancel.load(Ordering::SeqCst);
+            if !cancelled {
+                log::warn!("Manuscript export job {job_id} failed: {error}");
+            }
+            app.emit(
+                EXPORT_ERROR_EVENT,
+                &ExportErrorEvent {
+                    job_id,
+                    error,
+                    cancelled,
+                },
+            )
+        }
+    };
+    if let Err(e) = emitted {
+        log::warn!("Failed to emit export result: {e}");
+    }
+}
+
+/// Same export as [`export_manuscript_to_file`] on a background thread.
+/// Returns the job id at once; progress and the outcome arrive as events.
+#[tauri::command]
+pub fn start_manuscript_export(
+    app: AppHandle,
+    jobs: State<'_, ExportJobs>,
+    project_path: String,
+    output_path: String,
+    options: Option<ManuscriptExportOptions>,
+) -> Result<String, String> {
+    let source = load_export_source(&project_path)?;
+    let target = resolve_export_path(Some(&source.0), &output_path)?;
+    let options = options.unwrap_or_default();
+
+    let job_id = uuid::Uuid::new_v4().to_string();
+    let cancel = jobs.register(&job_id);
+    let worker_job_id = job_id.clone();
+    thread::spawn(move || {
+        run_manuscript_export_job(app, worker_job_id, cancel, source, options, target)
+    });
+    Ok(job_id)
+}
+
+/// Stops a running export after the current scene and removes its partial
+/// output. Returns whether the job was still running.
+#[tauri::command]
+pub fn cancel_export(jobs: State<'_, ExportJobs>, job_id: String) -> Result<bool, String> {
+    validate_no_null_bytes(&job_id, "Job id")?;
+    Ok(jobs.cancel(&job_id))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterWordCountExportResult {
+    pub output_path: String,
+    pub chapter_count: usize,
+}
+
+fn collect_chapter_rows(
+    nodes: &[StructureNodeWithCounts],
+    act_title: &str,
+    rows: &mut Vec<[String; 4]>,
+) {
+    for node in nodes {
+        match node.node_type.as_str() {
+            "act" => collect_chapter_rows(&node.children, &node.title, rows),
+            "chapter" => rows.push([
+                act_title.to_string(),
+                node.title.clone(),
+                node.scene_count.to_string(),
+                node.word_count.to_string(),
+            ]),
+            _ => {}
+        }
+    }
+}
+
+/// Writes one `act,chapter,scene_count,word_count` row per chapter in reading
+/// order. Chapters outside an act have an empty act column.
+#[tauri::command]
+pub fn export_chapter_wordcounts_csv(
+    project_path: String,
+    output_path: String,
+) -> Result<ChapterWordCountExportResult, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let target = resolve_export_path(Some(&project_path), &output_path)?;
+    let structure = get_structure_with_counts(project_path)?;
+
+    let mut rows = Vec::new();
+    collect_chapter_rows(&structure, "", &mut rows);
+    let mut content = String::from("act,chapter,scene_count,word_count\n");
+    for row in &rows {
+        let fields: Vec<String> = row.iter().map(|field| escape_csv_field(field)).collect();
+        content.push_str(&fields.join(","));
+        content.push('\n');
+    }
+
+    if target.is_dir() {
+        return Err("Target path is a directory".to_string());
+    }
+    atomic_write(&target, &content)?;
+
+    Ok(ChapterWordCountExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        chapter_count: rows.len(),
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterPdfExportResult {
+    pub output_path: String,
+    pub scene_count: usize,
+    pub page_count: usize,
+}
+
+fn chapter_pdf_blocks(
+    project_path: &str,
+    nodes: &[StructureNode],
+    blocks: &mut Vec<DocBlock>,
+    scene_count: &mut usize,
+) -> Result<(), String> {
+    for node in nodes {
+        if node.node_type != "scene" {
+            chapter_pdf_blocks(project_path, &node.children, blocks, scene_count)?;
+            continue;
+        }
+        if *scene_count > 0 {
+            blocks.push(DocBlock::Paragraph(DEFAULT_SCENE_BREAK.to_string()));
+        }
+        *scene_count += 1;
+        let title = node.title.trim();
+        let heading = if title.is_empty() {
+            format!("Scene {scene_count}")
+        } else {
+            format!("Scene {scene_count}: {title}")
+        };
+        blocks.push(DocBlock::Heading(3, heading));
+
+        let Some(file) = &node.file else {
+            continue;
+        };
+        let path = Path::new(project_path).join("manuscript").join(file);
+        let content = read_scene_content(&path)
+            .map_err(|e| format!("Failed to read scene '{}': {e}", path.display()))?;
+        blocks.extend(
+            extract_paragraphs(&content)
+                .into_iter()
+                .map(DocBlock::Paragraph),
+        );
+    }
+    Ok(())
+}
+
+/// Renders one chapter as a standalone PDF (title, numbered scenes separated
+/// by scene breaks, "Page N of M" footers), e.g. to hand to a critique group.
+#[tauri::command]
+pub fn export_chapter_pdf(
+    project_path: String,
+    chapter_id: String,
+    output_path: String,
+) -> Result<ChapterPdfExportResult, String> {
+    validate_no_null_bytes(&chapter_id, "Chapter id")?;
+    let (root, structure) = load_export_source(&project_path)?;
+    let chapter = find_node(&structure, &chapter_id)
+        .ok_or_else(|| format!("Chapter not found: {chapter_id}"))?;
+    if chapter.node_type != "chapter" {
+        return Err(format!(
+            "Node '{}' is a {}, not a chapter",
+            chapter.title, chapter.node_type
+        ));
+    }
+
+    let target = resolve_export_path(Some(&root), &output_path)?;
+    if target.is_dir() {
+        return Err("Target path is a directory".to_string());
+    }
+
+    let mut blocks = vec![DocBlock::Heading(1, chapter.title.clone())];
+    let mut scene_count = 0;
+    chapter_pdf_blocks(&root, &chapter.children, &mut blocks, &mut scene_count)?;
+    let page_count = write_pdf(&target, &blocks)?;
+
+    Ok(ChapterPdfExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        scene_count,
+        page_count,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub word_count: i32,
+}
+
+/// Document formats for scene-level exports
+fn scene_export_format(format: &str) -> Result<&'static str, String> {
+    match format.trim().to_lowercase().as_str() {
+        "markdown" | "md" => Ok("markdown"),
+        "txt" | "text" => Ok("txt"),
+        "docx" => Ok("docx"),
+        other => Err(format!("Unsupported scene export format: {other}")),
+    }
+}
+
+fn read_scene_paragraphs(root: &str, scene: &StructureNode) -> Result<Vec<String>, String> {
+    let path = scene
+        .file
+        .as_ref()
+        .map(|file| Path::new(root).join("manuscript").join(file))
+        .filter(|path| path.is_file())
+        .ok_or_else(|| format!("Scene file missing for '{}'", scene.title))?;
+    let content = read_scene_content(&path)
+        .map_err(|e| format!("Failed to read scene '{}': {e}", path.display()))?;
+    Ok(extract_paragraphs(&content))
+}
+
+fn write_scene_document(target: &Path, format: &str, blocks: &[DocBlock]) -> Result<(), String> {
+    if target.is_dir() {
+        return Err("Target path is a directory".to_string());
+    }
+    match format {
+        "docx" => write_docx(target, blocks),
+        "markdown" => atomic_write(target, &render_markdown(blocks)),
+        _ => {
+            let mut text = blocks
+                .iter()
+                .map(|block| match block {
+                    DocBlock::Heading(_, text)
+                    | DocBlock::Paragraph(text)
+                    | DocBlock::Bullet(text) => text.as_str(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            text.push('\n');
+            atomic_write(target, &text)
+        }
+    }
+}
+
+/// Writes one scene's prose to `output_path` as "markdown", "txt" or "docx",
+/// headed by the scene title when `include_title` is set.
+#[tauri::command]
+pub fn export_scene(
+    project_path: String,
+    scene_id: String,
+    output_path: String,
+    format: String,
+    include_title: Option<bool>,
+) -> Result<SceneExportResult, String> {
+    validate_no_null_bytes(&scene_id, "Scene id")?;
+    let format = scene_export_format(&format)?;
+    let (root, structure) = load_export_source(&project_path)?;
+    let target = resolve_export_path(Some(&root), &output_path)?;
+    let scene = find_node(&structure, &scene_id)
+        .filter(|node| node.node_type == "scene")
+        .ok_or_else(|| format!("Scene not found: {scene_id}"))?;
+
+    let paragraphs = read_scene_paragraphs(&root, scene)?;
+    let word_count = paragraphs
+        .iter()
+        .map(|paragraph| count_words(paragraph))
+        .sum();
+    let mut blocks = Vec::new();
+    if include_title.unwrap_or(false) {
+        blocks.push(DocBlock::Heading(1, scene.title.clone()));
+    }
+    blocks.extend(paragraphs.into_iter().map(DocBlock::Paragraph));
+    write_scene_document(&target, format, &blocks)?;
+
+    Ok(SceneExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        word_count,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectedScenesExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub scene_count: usize,
+    pub word_count: i32,
+}
+
+/// Maps every scene id to the scene and the chapter containing it
+fn index_scenes<'a>(
+    nodes: &'a [StructureNode],
+    chapter: Option<&'a StructureNode>,
+    index: &mut HashMap<&'a str, (&'a StructureNode, Option<&'a StructureNode>)>,
+) {
+    for node in nodes {
+        match node.node_type.as_str() {
+            "scene" => {
+                index.insert(node.id.as_str(), (node, chapter));
+            }
+            "chapter" => index_scenes(&node.children, Some(node), index),
+            _ => index_scenes(&node.children, chapter, index),
+        }
+    }
+}
+
+/// Concatenates the given scenes, in the order given, into one document
+/// (e.g. a sampler for beta readers). Scenes are separated by the options'
+/// scene break; with `include_chapter_headers` a chapter heading is written
+/// whenever the selection moves into a different chapter.
+#[tauri::command]
+pub fn export_selected_scenes(
+    project_path: String,
+    scene_ids: Vec<String>,
+    output_path: String,
+    format: String,
+    include_chapter_headers: Option<bool>,
+    options: Option<ManuscriptExportOptions>,
+) -> Result<SelectedScenesExportResult, String> {
+    if scene_ids.is_empty() {
+        return Err("Select at least one scene to export".to_string());
+    }
+    let format = scene_export_format(&format)?;
+    let options = options.unwrap_or_default();
+    let include_chapter_headers = include_chapter_headers.unwrap_or(false);
+    let (root, structure) = load_export_source(&project_path)?;
+    let target = resolve_export_path(Some(&root), &output_path)?;
+    let mut scenes = HashMap::new();
+    index_scenes(&structure, None, &mut scenes);
+    let selection = scene_ids
+        .iter()
+        .map(|scene_id| {
+            validate_no_null_bytes(scene_id, "Scene id")?;
+            scenes
+                .get(scene_id.as_str())
+                .copied()
+                .ok_or_else(|| format!("Scene not found: {scene_id}"))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let word_count = if format == "txt" {
+        // Same text layout as the manuscript export, blank scene breaks included
+        if target.is_dir() {
+            return Err("Target path is a directory".to_string());
+        }
+        let mut writer = ManuscriptWriter::new(&root, &options, TextSink::new(Vec::new()));
+        writer.write_selection(&selection, include_chapter_headers)?;
+        let word_count = writer.words_written;
+        let (buffer, _) = writer.out.finish()?;
+        let text = String::from_utf8(buffer)
+            .map_err(|e| format!("Scene export is not valid UTF-8: {e}"))?;
+        atomic_write(&target, &text)?;
+        word_count
+    } else {
+        let mut writer = ManuscriptWriter::new(&root, &options, Vec::new());
+        writer.write_selection(&selection, include_chapter_headers)?;
+        write_scene_document(&target, format, &writer.out)?;
+        writer.words_written
+    };
+
+    Ok(SelectedScenesExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        scene_count: scene_ids.len(),
+        word_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_delimited_chapter_numbers() {
+        assert_eq!(strip_chapter_number("Chapter 3: The Storm"), "The Storm");
+        assert_eq!(strip_chapter_number("chapter IV The Storm"), "The Storm");
+        assert_eq!(strip_chapter_number("Chapter xii - Home"), "Home");
+        assert_eq!(strip_chapter_number("3. The Storm"), "The Storm");
+    }
+
+    #[test]
+    fn keeps_words_made_of_numeral_letters() {
+        assert_eq!(strip_chapter_number("Chapter Mild"), "Chapter Mild");
+        assert_eq!(
+            strip_chapter_number("Chapter Mix: Party"),
+            "Chapter Mix: Party"
+        );
+        assert_eq!(
+            strip_chapter_number("Chapter Civil War"),
+            "Chapter Civil War"
+        );
+        assert_eq!(
+            strip_chapter_number("Chapter IIII: Four"),
+            "Chapter IIII: Four"
+        );
+        assert_eq!(strip_chapter_number("Chapters of Life"), "Chapters of Life");
+        assert_eq!(strip_chapter_number("1984 Revisited"), "1984 Revisited");
+    }
+
+    #[test]
+    fn never_returns_an_empty_title() {
+        assert_eq!(strip_chapter_number("Chapter IV"), "Chapter IV");
+        assert_eq!(strip_chapter_number("Chapter 12:"), "Chapter 12:");
+        assert_eq!(strip_chapter_number("1984"), "1984");
+    }
+}
@@ -0,0 +1,450 @@
+// Google Drive backup commands
+// Uploads .baa novel packages into an app-created Drive folder using the
+// `drive.file` scope requested by `google_oauth_connect`.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use reqwest::{Client, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::backup::{
+    create_temp_dir, export_novel_package, import_backup_package, BackupImportOptions,
+    BackupImportResult,
+};
+use crate::commands::google_oauth::{google_oauth_get_access_token, http_client};
+use crate::commands::project::get_project_by_path;
+use crate::storage::open_app_db;
+use crate::utils::{slugify, validate_no_null_bytes, MAX_READ_FILE_BYTES};
+
+const DRIVE_FILES_ENDPOINT: &str = "https://www.googleapis.com/drive/v3/files";
+const DRIVE_UPLOAD_ENDPOINT: &str = "https://www.googleapis.com/upload/drive/v3/files";
+const DRIVE_FOLDER_MIME: &str = "application/vnd.google-apps.folder";
+const BACKUP_FOLDER_NAME: &str = "Become An Author Backups";
+const BACKUP_MIME: &str = "application/octet-stream";
+const MULTIPART_BOUNDARY: &str = "baa-drive-upload-boundary";
+/// Request timeout for package uploads and downloads, which can take much
+/// longer than the shared client's API-call timeout
+const DRIVE_TRANSFER_TIMEOUT_SECS: u64 = 600;
+
+/// Marker app properties so we only ever touch files this app created
+const FOLDER_PROPERTY: &str = "baaBackupFolder";
+const BACKUP_PROPERTY: &str = "baaBackup";
+const PROJECT_ID_PROPERTY: &str = "projectId";
+const PROJECT_TITLE_PROPERTY: &str = "projectTitle";
+/// Drive limit for one app property, key and value bytes combined
+const MAX_APP_PROPERTY_BYTES: usize = 124;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveBackupUploadResult {
+    pub file_id: String,
+    pub file_name: String,
+    pub size_bytes: u64,
+    /// True when an existing Drive backup for this project was replaced
+    pub updated_existing: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DriveBackupInfo {
+    pub file_id: String,
+    pub name: String,
+    pub modified_time: Option<String>,
+    pub size_bytes: Option<u64>,
+    pub project_id: Option<String>,
+    pub project_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveBackupFile {
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    modified_time: Option<String>,
+    /// Drive reports sizes as decimal strings
+    #[serde(default)]
+    size: Option<String>,
+    #[serde(default)]
+    app_properties: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveBackupFileList {
+    #[serde(default)]
+    files: Vec<DriveBackupFile>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DriveFile {
+    id: String,
+    #[serde(default)]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DriveFileList {
+    #[serde(default)]
+    files: Vec<DriveFile>,
+}
+
+async fn drive_access_token(
+    client_id: &str,
+    client_secret: Option<String>,
+) -> Result<String, String> {
+    google_oauth_get_access_token(client_id.to_string(), client_secret)
+        .await
+        .map_err(|e| format!("[E_DRIVE_RECONNECT] Google session could not be refreshed: {e}"))?
+        .ok_or_else(|| "[E_DRIVE_RECONNECT] Google Drive is not connected".to_string())
+}
+
+/// Maps a 401 to the reconnect error and other failures to the response body.
+async fn ensure_drive_success(response: Response, action: &str) -> Result<Response, String> {
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(
+            "[E_DRIVE_RECONNECT] Google Drive rejected the access token; reconnect your Google account"
+                .to_string(),
+        );
+    }
+    if !status.is_success() {
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown Google Drive error".to_string());
+        return Err(format!("Failed to {action}: {status} {body}"));
+    }
+    Ok(response)
+}
+
+fn drive_query_literal(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+async fn find_drive_files(
+    client: &Client,
+    token: &str,
+    query: &str,
+) -> Result<Vec<DriveFile>, String> {
+    let response = client
+        .get(DRIVE_FILES_ENDPOINT)
+        .bearer_auth(token)
+        .query(&[
+            ("q", query),
+            ("fields", "files(id,name)"),
+            ("spaces", "drive"),
+        ])
+        .send()
+        .await
+        .map_err(|e| format!("Drive search request failed: {e}"))?;
+    let response = ensure_drive_success(response, "search Google Drive").await?;
+    let list: DriveFileList = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Drive search response: {e}"))?;
+    Ok(list.files)
+}
+
+/// Truncates `value` on a UTF-8 boundary so `key` + `value` fit in one
+/// Drive app property.
+fn app_property_value(key: &str, value: &str) -> String {
+    let mut end = MAX_APP_PROPERTY_BYTES.saturating_sub(key.len());
+    if value.len() <= end {
+        return value.to_string();
+    }
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    value[..end].to_string()
+}
+
+async fn ensure_backup_folder(client: &Client, token: &str) -> Result<String, String> {
+    let query = format!(
+        "mimeType = '{DRIVE_FOLDER_MIME}' and trashed = false and \
+         appProperties has {{ key='{FOLDER_PROPERTY}' and value='1' }}"
+    );
+    if let Some(folder) = find_drive_files(client, token, &query)
+        .await?
+        .into_iter()
+        .next()
+    {
+        return Ok(folder.id);
+    }
+
+    let metadata = serde_json::json!({
+        "name": BACKUP_FOLDER_NAME,
+        "mimeType": DRIVE_FOLDER_MIME,
+        "appProperties": { FOLDER_PROPERTY: "1" },
+    });
+    let response = client
+        .post(DRIVE_FILES_ENDPOINT)
+        .bearer_auth(token)
+        .query(&[("fields", "id,name")])
+        .json(&metadata)
+        .send()
+        .await
+        .map_err(|e| format!("Drive folder request failed: {e}"))?;
+    let response = ensure_drive_success(response, "create the Drive backup folder").await?;
+    let folder: DriveFile = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Drive folder response: {e}"))?;
+    Ok(folder.id)
+}
+
+fn multipart_related_body(metadata: &serde_json::Value, data: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(data.len() + 512);
+    body.extend_from_slice(
+        format!(
+            "--{MULTIPART_BOUNDARY}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{metadata}\r\n\
+             --{MULTIPART_BOUNDARY}\r\nContent-Type: {BACKUP_MIME}\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+    body
+}
+
+/// Builds a novel package for the project in a temp dir and returns its bytes.
+fn build_project_package(project_id: &str) -> Result<Vec<u8>, String> {
+    let temp_dir = create_temp_dir("drive-upload")?;
+    let package_path = temp_dir.join("upload.baa");
+    let result = export_novel_package(
+        project_id.to_string(),
+        Some(package_path.to_string_lossy().to_string()),
+    )
+    .and_then(|_| {
+        fs::read(&package_path).map_err(|e| format!("Failed to read backup package: {e}"))
+    });
+    let _ = fs::remove_dir_all(&temp_dir);
+    result
+}
+
+/// Uploads a backup package of the project to Google Drive, replacing the
+/// previous Drive backup of the same project if there is one.
+#[tauri::command]
+pub async fn upload_backup_to_drive(
+    client_id: String,
+    client_secret: Option<String>,
+    project_path: String,
+) -> Result<DriveBackupUploadResult, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let project = {
+        let conn = open_app_db()?;
+        get_project_by_path(&conn, &project_path)?
+    };
+    let data = build_project_package(&project.id)?;
+
+    let token = drive_access_token(&client_id, client_secret).await?;
+    let client = http_client()?;
+    let folder_id = ensure_backup_folder(&client, &token).await?;
+
+    let slug = slugify(&project.title);
+    let file_name = if slug.trim().is_empty() {
+        "project.baa".to_string()
+    } else {
+        format!("{slug}.baa")
+    };
+    let existing_query = format!(
+        "'{}' in parents and trashed = false and \
+         appProperties has {{ key='{PROJECT_ID_PROPERTY}' and value='{}' }}",
+        drive_query_literal(&folder_id),
+        drive_query_literal(&project.id)
+    );
+    let existing = find_drive_files(&client, &token, &existing_query)
+        .await?
+        .into_iter()
+        .next();
+
+    let app_properties = serde_json::json!({
+        BACKUP_PROPERTY: "1",
+        PROJECT_ID_PROPERTY: project.id,
+        PROJECT_TITLE_PROPERTY: app_property_value(PROJECT_TITLE_PROPERTY, &project.title),
+    });
+    let content_type = format!("multipart/related; boundary={MULTIPART_BOUNDARY}");
+    let request = match &existing {
+        Some(file) => {
+            let metadata = serde_json::json!({
+                "name": file_name,
+                "appProperties": app_properties,
+            });
+            client
+                .patch(format!("{DRIVE_UPLOAD_ENDPOINT}/{}", file.id))
+                .body(multipart_related_body(&metadata, &data))
+        }
+        None => {
+            let metadata = serde_json::json!({
+                "name": file_name,
+                "parents": [folder_id],
+                "mimeType": BACKUP_MIME,
+                "appProperties": app_properties,
+            });
+            client
+                .post(DRIVE_UPLOAD_ENDPOINT)
+                .body(multipart_related_body(&metadata, &data))
+        }
+    };
+
+    let response = request
+        .timeout(Duration::from_secs(DRIVE_TRANSFER_TIMEOUT_SECS))
+        .bearer_auth(&token)
+        .query(&[("uploadType", "multipart"), ("fields", "id,name")])
+        .header("Content-Type", content_type)
+        .send()
+        .await
+        .map_err(|e| format!("Drive upload request failed: {e}"))?;
+    let response = ensure_drive_success(response, "upload the backup to Google Drive").await?;
+    let uploaded: DriveFile = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse Drive upload response: {e}"))?;
+
+    Ok(DriveBackupUploadResult {
+        file_id: uploaded.id,
+        file_name: if uploaded.name.is_empty() {
+            file_name
+        } else {
+            uploaded.name
+        },
+        size_bytes: data.len() as u64,
+        updated_existing: existing.is_some(),
+    })
+}
+
+/// Lists backups this app uploaded to Drive, newest first.
+#[tauri::command]
+pub async fn list_drive_backups(
+    client_id: String,
+    client_secret: Option<String>,
+) -> Result<Vec<DriveBackupInfo>, String> {
+    let token = drive_access_token(&client_id, client_secret).await?;
+    let client = http_client()?;
+    let query = format!(
+        "trashed = false and appProperties has {{ key='{BACKUP_PROPERTY}' and value='1' }}"
+    );
+
+    let mut backups = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let mut params = vec![
+            ("q", query.clone()),
+            (
+                "fields",
+                "nextPageToken,files(id,name,modifiedTime,size,appProperties)".to_string(),
+            ),
+            ("orderBy", "modifiedTime desc".to_string()),
+            ("pageSize", "100".to_string()),
+        ];
+        if let Some(token) = &page_token {
+            params.push(("pageToken", token.clone()));
+        }
+
+        let response = client
+            .get(DRIVE_FILES_ENDPOINT)
+            .bearer_auth(&token)
+            .query(&params)
+            .send()
+            .await
+            .map_err(|e| format!("Drive list request failed: {e}"))?;
+        let response = ensure_drive_success(response, "list Google Drive backups").await?;
+        let page: DriveBackupFileList = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Drive list response: {e}"))?;
+
+        backups.extend(page.files.into_iter().map(|mut file| DriveBackupInfo {
+            size_bytes: file.size.as_deref().and_then(|size| size.parse().ok()),
+            project_id: file.app_properties.remove(PROJECT_ID_PROPERTY),
+            project_title: file.app_properties.remove(PROJECT_TITLE_PROPERTY),
+            file_id: file.id,
+            name: file.name,
+            modified_time: file.modified_time,
+        }));
+
+        match page.next_page_token {
+            Some(next) => page_token = Some(next),
+            None => break,
+        }
+    }
+
+    Ok(backups)
+}
+
+/// Streams a download into `path`, refusing bodies larger than a backup
+/// package may be so a bad or hostile file can't fill the disk.
+async fn download_to_file(mut response: Response, path: &Path) -> Result<(), String> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_READ_FILE_BYTES)
+    {
+        return Err("Drive backup exceeds the maximum backup size".to_string());
+    }
+    let mut file =
+        fs::File::create(path).map_err(|e| format!("Failed to stage Drive backup: {e}"))?;
+    let mut written = 0u64;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to read Drive backup download: {e}"))?
+    {
+        written += chunk.len() as u64;
+        if written > MAX_READ_FILE_BYTES {
+            return Err("Drive backup exceeds the maximum backup size".to_string());
+        }
+        file.write_all(&chunk)
+            .map_err(|e| format!("Failed to stage Drive backup: {e}"))?;
+    }
+    file.sync_all()
+        .map_err(|e| format!("Failed to stage Drive backup: {e}"))
+}
+
+/// Downloads a Drive backup and imports it as a new project in `series_id`.
+/// `series_index` overrides the book number stored in the package.
+#[tauri::command]
+pub async fn restore_from_drive(
+    client_id: String,
+    client_secret: Option<String>,
+    file_id: String,
+    series_id: String,
+    series_index: Option<String>,
+) -> Result<BackupImportResult, String> {
+    validate_no_null_bytes(&file_id, "Drive file id")?;
+    validate_no_null_bytes(&series_id, "Series id")?;
+    let token = drive_access_token(&client_id, client_secret).await?;
+    let client = http_client()?;
+
+    let response = client
+        .get(format!("{DRIVE_FILES_ENDPOINT}/{file_id}"))
+        .timeout(Duration::from_secs(DRIVE_TRANSFER_TIMEOUT_SECS))
+        .bearer_auth(&token)
+        .query(&[("alt", "media")])
+        .send()
+        .await
+        .map_err(|e| format!("Drive download request failed: {e}"))?;
+    let response = ensure_drive_success(response, "download the backup from Google Drive").await?;
+
+    let temp_dir = create_temp_dir("drive-restore")?;
+    let package_path = temp_dir.join("restore.baa");
+    let result = download_to_file(response, &package_path)
+        .await
+        .and_then(|_| {
+            import_backup_package(
+                package_path.to_string_lossy().to_string(),
+                Some(BackupImportOptions {
+                    target_series_id: Some(series_id),
+                    create_series_title: None,
+                    series_index,
+                }),
+            )
+        });
+    let _ = fs::remove_dir_all(&temp_dir);
+    result
+}
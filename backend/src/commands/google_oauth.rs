@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 use std::io::{Read, Write};
-use std::net::TcpListener;
+use std::net::{TcpListener, TcpStream};
 use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -25,6 +25,11 @@ use uuid::Uuid;
 const TOKENS_ACCOUNT: &str = "google-oauth-tokens";
 const USER_ACCOUNT: &str = "google-oauth-user";
 const CALLBACK_TIMEOUT_SECS: u64 = 180;
+const CALLBACK_READ_TIMEOUT_SECS: u64 = 5;
+const MAX_CALLBACK_REQUEST_BYTES: usize = 8192;
+const HTTP_CONNECT_TIMEOUT_SECS: u64 = 10;
+const HTTP_REQUEST_TIMEOUT_SECS: u64 = 30;
+const CALLBACK_PATH: &str = "/oauth2/callback";
 const OAUTH_NAMESPACE: &str = "oauth";
 const OAUTH_PROVIDER: &str = "google";
 
@@ -113,7 +118,66 @@ fn build_code_challenge(code_verifier: &str) -> String {
     URL_SAFE_NO_PAD.encode(digest)
 }
 
-fn wait_for_google_callback(listener: TcpListener) -> Result<HashMap<String, String>, String> {
+fn write_callback_response(stream: &mut TcpStream, status: &str, message: &str) {
+    let html = format!("<html><body>{message}</body></html>");
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        html.len(),
+        html
+    );
+    let _ = stream.write_all(response.as_bytes());
+    let _ = stream.flush();
+}
+
+/// Reads the request head (up to the blank line). Returns None for requests
+/// that are too large, time out, or close early.
+fn read_callback_request_head(stream: &mut TcpStream) -> Option<String> {
+    stream.set_nonblocking(false).ok()?;
+    stream
+        .set_read_timeout(Some(Duration::from_secs(CALLBACK_READ_TIMEOUT_SECS)))
+        .ok()?;
+
+    let mut head = Vec::with_capacity(1024);
+    let mut chunk = [0_u8; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() >= MAX_CALLBACK_REQUEST_BYTES {
+            return None;
+        }
+        let read = stream.read(&mut chunk).ok()?;
+        if read == 0 {
+            break;
+        }
+        let remaining = MAX_CALLBACK_REQUEST_BYTES - head.len();
+        head.extend_from_slice(&chunk[..read.min(remaining)]);
+    }
+    Some(String::from_utf8_lossy(&head).into_owned())
+}
+
+/// Parses `GET /oauth2/callback?...` into query params; anything else is None.
+fn parse_callback_request(request: &str) -> Option<HashMap<String, String>> {
+    let mut parts = request.lines().next()?.split_whitespace();
+    if parts.next()? != "GET" {
+        return None;
+    }
+    let callback_url = Url::parse(&format!("http://localhost{}", parts.next()?)).ok()?;
+    if callback_url.path() != CALLBACK_PATH {
+        return None;
+    }
+    Some(
+        callback_url
+            .query_pairs()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    )
+}
+
+/// Waits for Google's redirect. Stray connections (other paths, oversized
+/// or malformed requests, wrong state) are answered and ignored so a local
+/// process can't end or hijack the flow before the real callback arrives.
+fn wait_for_google_callback(
+    listener: TcpListener,
+    expected_state: &str,
+) -> Result<HashMap<String, String>, String> {
     listener
         .set_nonblocking(true)
         .map_err(|e| format!("Failed to configure callback listener: {e}"))?;
@@ -127,39 +191,32 @@ fn wait_for_google_callback(listener: TcpListener) -> Result<HashMap<String, Str
 
         match listener.accept() {
             Ok((mut stream, _)) => {
-                let mut buffer = [0_u8; 8192];
-                let bytes_read = stream
-                    .read(&mut buffer)
-                    .map_err(|e| format!("Failed to read callback request: {e}"))?;
-
-                let request = String::from_utf8_lossy(&buffer[..bytes_read]);
-                let request_line = request
-                    .lines()
-                    .next()
-                    .ok_or_else(|| "Invalid callback request".to_string())?;
-
-                let path = request_line
-                    .split_whitespace()
-                    .nth(1)
-                    .ok_or_else(|| "Failed to parse callback path".to_string())?;
-
-                let callback_url = Url::parse(&format!("http://localhost{path}"))
-                    .map_err(|e| format!("Failed to parse callback URL: {e}"))?;
-
-                let params = callback_url
-                    .query_pairs()
-                    .map(|(k, v)| (k.to_string(), v.to_string()))
-                    .collect::<HashMap<_, _>>();
-
-                let html = "<html><body><h3>Authentication complete.</h3><p>You can close this window and return to the app.</p></body></html>";
-                let response = format!(
-                    "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
-                    html.len(),
-                    html
+                let Some(request) = read_callback_request_head(&mut stream) else {
+                    write_callback_response(
+                        &mut stream,
+                        "400 Bad Request",
+                        "<h3>Invalid request.</h3>",
+                    );
+                    continue;
+                };
+                let Some(params) = parse_callback_request(&request) else {
+                    write_callback_response(&mut stream, "404 Not Found", "<h3>Not found.</h3>");
+                    continue;
+                };
+                if params.get("state").map(String::as_str) != Some(expected_state) {
+                    write_callback_response(
+                        &mut stream,
+                        "400 Bad Request",
+                        "<h3>Unexpected OAuth state.</h3>",
+                    );
+                    continue;
+                }
+
+                write_callback_response(
+                    &mut stream,
+                    "200 OK",
+                    "<h3>Authentication complete.</h3><p>You can close this window and return to the app.</p>",
                 );
-                let _ = stream.write_all(response.as_bytes());
-                let _ = stream.flush();
-
                 return Ok(params);
             }
             Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
@@ -296,6 +353,16 @@ async fn fetch_user_info(client: &Client, access_token: &str) -> Result<GoogleUs
     })
 }
 
+/// HTTP client for Google's OAuth and Drive endpoints, bounded so a stalled
+/// connection cannot hang sign-in, token refresh or a backup worker.
+pub(crate) fn http_client() -> Result<Client, String> {
+    Client::builder()
+        .connect_timeout(Duration::from_secs(HTTP_CONNECT_TIMEOUT_SECS))
+        .timeout(Duration::from_secs(HTTP_REQUEST_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))
+}
+
 #[command]
 pub async fn google_oauth_connect(
     client_id: String,
@@ -313,7 +380,7 @@ pub async fn google_oauth_connect(
         .map_err(|e| format!("Failed to read callback listener port: {e}"))?
         .port();
 
-    let redirect_uri = format!("http://127.0.0.1:{port}/oauth2/callback");
+    let redirect_uri = format!("http://127.0.0.1:{port}{CALLBACK_PATH}");
     let code_verifier = build_code_verifier();
     let code_challenge = build_code_challenge(&code_verifier);
     let state = Uuid::new_v4().simple().to_string();
@@ -348,8 +415,9 @@ pub async fn google_oauth_connect(
         .map_err(|e| format!("Failed to open browser for Google OAuth: {e}"))?;
 
     let (tx, rx) = mpsc::channel();
+    let expected_state = state.clone();
     thread::spawn(move || {
-        let result = wait_for_google_callback(listener);
+        let result = wait_for_google_callback(listener, &expected_state);
         let _ = tx.send(result);
     });
 
@@ -372,7 +440,7 @@ pub async fn google_oauth_connect(
         .get("code")
         .ok_or_else(|| "Missing authorization code".to_string())?;
 
-    let client = Client::new();
+    let client = http_client()?;
     let tokens = exchange_code_for_tokens(
         &client,
         &client_id,
@@ -408,7 +476,7 @@ pub async fn google_oauth_get_access_token(
         return Ok(Some(tokens.access_token));
     }
 
-    let client = Client::new();
+    let client = http_client()?;
     let refreshed =
         match refresh_access_token(&client, &client_id, client_secret.as_deref(), &tokens).await {
             Ok(t) => t,
@@ -430,8 +498,11 @@ pub fn google_oauth_get_user() -> Result<Option<GoogleUser>, String> {
 
 #[command]
 pub async fn google_oauth_sign_out() -> Result<(), String> {
-    if let Some(tokens) = load_json::<GoogleOAuthTokens>(TOKENS_ACCOUNT)? {
-        let client = Client::new();
+    // Revoking is best-effort; local sign-out must still happen.
+    if let (Some(tokens), Ok(client)) = (
+        load_json::<GoogleOAuthTokens>(TOKENS_ACCOUNT)?,
+        http_client(),
+    ) {
         let _ = client
             .post(GOOGLE_REVOKE_ENDPOINT)
             .header("Content-Type", "application/x-www-form-urlencoded")
@@ -0,0 +1,94 @@
+// Log file access for bug reports
+// `tauri_plugin_log` writes `<app log dir>/become-an-author.log`, rotating at
+// MAX_LOG_FILE_SIZE. The plugin is built at the most verbose level and the
+// effective level is controlled at runtime through `log::set_max_level`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use log::LevelFilter;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::storage::{app_pref_get, app_pref_set, open_app_db};
+use crate::utils::{now_millis, resolve_export_path};
+
+pub const LOG_FILE_NAME: &str = "become-an-author";
+pub const MAX_LOG_FILE_SIZE: u128 = 5 * 1024 * 1024;
+pub const KEEP_LOG_FILES: usize = 5;
+const LOG_LEVEL_PREF_KEY: &str = "log.level";
+const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::Info;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LogExportResult {
+    pub output_path: String,
+    pub bytes_written: u64,
+}
+
+fn log_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to resolve log directory: {e}"))?;
+    Ok(log_dir.join(format!("{LOG_FILE_NAME}.log")))
+}
+
+fn parse_log_level(level: &str) -> Result<LevelFilter, String> {
+    LevelFilter::from_str(level.trim()).map_err(|_| {
+        format!("Unknown log level '{level}' (expected off, error, warn, info, debug or trace)")
+    })
+}
+
+/// Applies the persisted log level. Called once from app setup.
+pub fn restore_log_level() {
+    let level = open_app_db()
+        .and_then(|conn| app_pref_get(&conn, LOG_LEVEL_PREF_KEY))
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<String>(&value).ok())
+        .and_then(|value| parse_log_level(&value).ok())
+        .unwrap_or(DEFAULT_LOG_LEVEL);
+    log::set_max_level(level);
+}
+
+#[tauri::command]
+pub fn get_log_path(app: AppHandle) -> Result<String, String> {
+    Ok(log_file_path(&app)?.to_string_lossy().to_string())
+}
+
+/// Copies the current log file to `output_path` so it can be attached to a
+/// bug report.
+#[tauri::command]
+pub fn export_logs(app: AppHandle, output_path: String) -> Result<LogExportResult, String> {
+    let source = log_file_path(&app)?;
+    if !source.is_file() {
+        return Err("No log file has been written yet".to_string());
+    }
+    let target = resolve_export_path(None, &output_path)?;
+    if target.is_dir() {
+        return Err("Target path is a directory".to_string());
+    }
+
+    let bytes_written =
+        fs::copy(&source, &target).map_err(|e| format!("Failed to export log file: {e}"))?;
+    Ok(LogExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        bytes_written,
+    })
+}
+
+/// Changes the log level immediately and remembers it across launches.
+/// Returns the level now in effect.
+#[tauri::command]
+pub fn set_log_level(level: String) -> Result<String, String> {
+    let filter = parse_log_level(&level)?;
+    let value = filter.to_string().to_lowercase();
+    let conn = open_app_db()?;
+    let value_json = serde_json::to_string(&value).map_err(|e| e.to_string())?;
+    app_pref_set(&conn, LOG_LEVEL_PREF_KEY, &value_json, now_millis())?;
+    log::set_max_level(filter);
+    log::info!("Log level set to {value}");
+    Ok(value)
+}
@@ -0,0 +1,318 @@
+// Storage maintenance commands (payload validation and canonicalization)
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::project::{get_project_by_path, renumber_structure_order};
+use crate::models::{
+    CodexEntry, CodexEntryTag, CodexRelation, CodexRelationType, CodexTag, CodexTemplate,
+    SceneCodexLink,
+};
+use crate::storage::{open_app_db, with_transaction};
+use crate::utils::{get_app_dir, read_text_file, validate_no_null_bytes};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidStoredPayload {
+    pub store: String,
+    pub id: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageNormalizationReport {
+    pub checked: usize,
+    pub rewritten: usize,
+    /// Binder nodes whose `order` was renumbered to be contiguous
+    pub structure_nodes_renumbered: usize,
+    /// Rows and files that failed to parse; these are left untouched
+    pub invalid: Vec<InvalidStoredPayload>,
+}
+
+/// Where a JSON column lives and which scope column selects this project's rows
+struct PayloadStore {
+    table: &'static str,
+    key_column: &'static str,
+    json_column: &'static str,
+    scope_column: &'static str,
+}
+
+fn normalize_store<T>(
+    conn: &Connection,
+    store: &PayloadStore,
+    scope_value: &str,
+    report: &mut StorageNormalizationReport,
+) -> Result<(), String>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let rows: Vec<(String, String)> = {
+        let sql = format!(
+            "SELECT {key}, {json} FROM {table} WHERE {scope} = ?1",
+            key = store.key_column,
+            json = store.json_column,
+            table = store.table,
+            scope = store.scope_column,
+        );
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| format!("Failed to prepare {} scan: {e}", store.table))?;
+        let mapped = stmt
+            .query_map(params![scope_value], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| format!("Failed to scan {}: {e}", store.table))?;
+        let mut rows = Vec::new();
+        for row in mapped {
+            rows.push(row.map_err(|e| format!("Failed to decode {} row: {e}", store.table))?);
+        }
+        rows
+    };
+
+    let update_sql = format!(
+        "UPDATE {table} SET {json} = ?1 WHERE {key} = ?2",
+        table = store.table,
+        json = store.json_column,
+        key = store.key_column,
+    );
+
+    for (id, raw) in rows {
+        report.checked += 1;
+        let parsed = match serde_json::from_str::<T>(&raw) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                report.invalid.push(InvalidStoredPayload {
+                    store: store.table.to_string(),
+                    id,
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let canonical = serde_json::to_string(&parsed).map_err(|e| e.to_string())?;
+        if canonical != raw {
+            conn.execute(&update_sql, params![canonical, id])
+                .map_err(|e| format!("Failed to rewrite {} row: {e}", store.table))?;
+            report.rewritten += 1;
+        }
+    }
+    Ok(())
+}
+
+fn series_store(table: &'static str) -> PayloadStore {
+    PayloadStore {
+        table,
+        key_column: "id",
+        json_column: "payload_json",
+        scope_column: "series_id",
+    }
+}
+
+/// Re-serializes every JSON payload owned by the project (and its series
+/// codex) in canonical form and renumbers the binder order. Payloads that
+/// don't deserialize are reported and skipped; scene files are only checked,
+/// never rewritten.
+#[tauri::command]
+pub fn normalize_project_storage(
+    project_path: String,
+) -> Result<StorageNormalizationReport, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let mut report = StorageNormalizationReport::default();
+
+    with_transaction(&conn, |conn| {
+        let series_id = project.series_id.as_str();
+        normalize_store::<CodexEntry>(
+            conn,
+            &series_store("codex_entries"),
+            series_id,
+            &mut report,
+        )?;
+        normalize_store::<CodexRelation>(
+            conn,
+            &series_store("codex_relations"),
+            series_id,
+            &mut report,
+        )?;
+        normalize_store::<CodexTag>(conn, &series_store("codex_tags"), series_id, &mut report)?;
+        normalize_store::<CodexEntryTag>(
+            conn,
+            &series_store("codex_entry_tags"),
+            series_id,
+            &mut report,
+        )?;
+        normalize_store::<CodexTemplate>(
+            conn,
+            &series_store("codex_templates"),
+            series_id,
+            &mut report,
+        )?;
+        normalize_store::<CodexRelationType>(
+            conn,
+            &series_store("codex_relation_types"),
+            series_id,
+            &mut report,
+        )?;
+        normalize_store::<SceneCodexLink>(
+            conn,
+            &series_store("scene_codex_links"),
+            series_id,
+            &mut report,
+        )?;
+
+        let project_id = project.id.as_str();
+        normalize_store::<serde_json::Value>(
+            conn,
+            &PayloadStore {
+                table: "scene_notes",
+                key_column: "id",
+                json_column: "content_json",
+                scope_column: "project_id",
+            },
+            project_id,
+            &mut report,
+        )?;
+        normalize_store::<serde_json::Value>(
+            conn,
+            &PayloadStore {
+                table: "snippets",
+                key_column: "id",
+                json_column: "content_json",
+                scope_column: "project_id",
+            },
+            project_id,
+            &mut report,
+        )?;
+        normalize_store::<Vec<String>>(
+            conn,
+            &PayloadStore {
+                table: "scene_metadata",
+                key_column: "scene_id",
+                json_column: "labels_json",
+                scope_column: "project_id",
+            },
+            project_id,
+            &mut report,
+        )?;
+        report.structure_nodes_renumbered = renumber_structure_order(conn, project_id)?;
+        Ok(())
+    })?;
+
+    let mut stmt = conn
+        .prepare("SELECT scene_id, scene_file FROM scene_metadata WHERE project_id = ?1")
+        .map_err(|e| format!("Failed to prepare scene file scan: {e}"))?;
+    let rows = stmt
+        .query_map(params![project.id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| format!("Failed to scan scene files: {e}"))?;
+
+    for row in rows {
+        let (scene_id, scene_file) =
+            row.map_err(|e| format!("Failed to decode scene file row: {e}"))?;
+        let path = PathBuf::from(&project.path)
+            .join("manuscript")
+            .join(&scene_file);
+        let Ok(content) = read_text_file(&path) else {
+            continue;
+        };
+        report.checked += 1;
+        if content.trim().is_empty() || !content.trim_start().starts_with('{') {
+            continue;
+        }
+        if let Err(e) = serde_json::from_str::<serde_json::Value>(&content) {
+            report.invalid.push(InvalidStoredPayload {
+                store: format!("manuscript/{scene_file}"),
+                id: scene_id,
+                error: e.to_string(),
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageInfo {
+    pub app_dir: String,
+    pub project_count: i64,
+    pub series_count: i64,
+    pub used_bytes: u64,
+    /// Free space on the volume holding the app dir, when the platform reports it
+    pub available_bytes: Option<u64>,
+}
+
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    // Blocks available to unprivileged users, in fragment-size units
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+#[cfg(windows)]
+fn available_disk_space(path: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    let mut available = 0u64;
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    (ok != 0).then_some(available)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn available_disk_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+#[tauri::command]
+pub fn get_storage_info() -> Result<StorageInfo, String> {
+    let app_dir = get_app_dir()?;
+    let conn = open_app_db()?;
+    let project_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM projects", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to count projects: {e}"))?;
+    let series_count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM series", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to count series: {e}"))?;
+
+    Ok(StorageInfo {
+        app_dir: app_dir.to_string_lossy().to_string(),
+        project_count,
+        series_count,
+        used_bytes: directory_size(&app_dir),
+        available_bytes: available_disk_space(&app_dir),
+    })
+}
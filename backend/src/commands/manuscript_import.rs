@@ -0,0 +1,255 @@
+// Manuscript import (.md / .docx drafts into a new project)
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use zip::ZipArchive;
+
+use crate::commands::project::{
+    create_project_from_import, new_structure_node, ImportedSceneContent,
+};
+use crate::models::{ProjectMeta, StructureNode};
+use crate::utils::{
+    parse_xml, validate_file_size, validate_no_null_bytes, XmlElement, MAX_MANUSCRIPT_IMPORT_SIZE,
+};
+
+const DEFAULT_SCENE_SEPARATOR: &str = "* * *";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ManuscriptImportResult {
+    pub project: ProjectMeta,
+    pub chapters: usize,
+    pub scenes: usize,
+}
+
+/// One block of the source document
+enum ManuscriptBlock {
+    Heading(String),
+    Paragraph(String),
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, blocks: &mut Vec<ManuscriptBlock>) {
+    if !paragraph.is_empty() {
+        blocks.push(ManuscriptBlock::Paragraph(paragraph.join(" ")));
+        paragraph.clear();
+    }
+}
+
+fn markdown_blocks(source: &str) -> Vec<ManuscriptBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("# ") {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            blocks.push(ManuscriptBlock::Heading(heading.trim().to_string()));
+        } else if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn docx_paragraph_text(node: &XmlElement, output: &mut String) {
+    match node.name.as_str() {
+        "w:t" => output.push_str(&node.text),
+        "w:tab" => output.push('\t'),
+        "w:br" | "w:cr" => output.push('\n'),
+        _ => {
+            for child in &node.children {
+                docx_paragraph_text(child, output);
+            }
+        }
+    }
+}
+
+fn is_docx_heading_one(paragraph: &XmlElement) -> bool {
+    let Some(props) = paragraph.child("w:pPr") else {
+        return false;
+    };
+    let style = props
+        .child("w:pStyle")
+        .and_then(|s| s.attrs.get("w:val"))
+        .map(|v| v.to_lowercase().replace(' ', ""))
+        .unwrap_or_default();
+    let outline_level = props
+        .child("w:outlineLvl")
+        .and_then(|o| o.attrs.get("w:val"))
+        .map(String::as_str);
+    style == "heading1" || outline_level == Some("0")
+}
+
+fn collect_docx_blocks(node: &XmlElement, blocks: &mut Vec<ManuscriptBlock>) {
+    for child in &node.children {
+        if child.name == "w:p" {
+            let mut text = String::new();
+            docx_paragraph_text(child, &mut text);
+            let text = text.trim().to_string();
+            if is_docx_heading_one(child) && !text.is_empty() {
+                blocks.push(ManuscriptBlock::Heading(text));
+            } else if !text.is_empty() {
+                blocks.push(ManuscriptBlock::Paragraph(text));
+            }
+        } else {
+            collect_docx_blocks(child, blocks);
+        }
+    }
+}
+
+fn docx_blocks(path: &Path) -> Result<Vec<ManuscriptBlock>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open document: {e}"))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed to read .docx archive: {e}"))?;
+    let mut document = archive
+        .by_name("word/document.xml")
+        .map_err(|e| format!("Document is missing word/document.xml: {e}"))?;
+    validate_file_size(
+        document.size(),
+        MAX_MANUSCRIPT_IMPORT_SIZE,
+        "word/document.xml",
+    )?;
+    let mut xml = String::new();
+    document
+        .by_ref()
+        .take(MAX_MANUSCRIPT_IMPORT_SIZE)
+        .read_to_string(&mut xml)
+        .map_err(|e| format!("Failed to read word/document.xml: {e}"))?;
+
+    let root = parse_xml(&xml)?;
+    let body = root
+        .find("w:body")
+        .ok_or("Document body not found in word/document.xml")?;
+    let mut blocks = Vec::new();
+    collect_docx_blocks(body, &mut blocks);
+    Ok(blocks)
+}
+
+fn normalize_separator(value: &str) -> String {
+    value.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn push_scene(
+    chapter: &mut StructureNode,
+    paragraphs: &mut Vec<String>,
+    scenes: &mut Vec<ImportedSceneContent>,
+) {
+    if paragraphs.is_empty() {
+        return;
+    }
+    let node = new_structure_node("scene", format!("Scene {}", chapter.children.len() + 1));
+    scenes.push(ImportedSceneContent {
+        file: node.file.clone().unwrap_or_default(),
+        title: node.title.clone(),
+        paragraphs: std::mem::take(paragraphs),
+    });
+    chapter.children.push(node);
+}
+
+fn build_structure(
+    blocks: Vec<ManuscriptBlock>,
+    separator: &str,
+) -> (Vec<StructureNode>, Vec<ImportedSceneContent>) {
+    let separator = normalize_separator(separator);
+    let mut act = new_structure_node("act", "Act 1".to_string());
+    let mut scenes = Vec::new();
+    let mut chapter: Option<StructureNode> = None;
+    let mut paragraphs = Vec::new();
+
+    for block in blocks {
+        match block {
+            ManuscriptBlock::Heading(title) => {
+                if let Some(mut current) = chapter.take() {
+                    push_scene(&mut current, &mut paragraphs, &mut scenes);
+                    act.children.push(current);
+                } else if !paragraphs.is_empty() {
+                    let mut front = new_structure_node("chapter", "Untitled Chapter".to_string());
+                    push_scene(&mut front, &mut paragraphs, &mut scenes);
+                    act.children.push(front);
+                }
+                chapter = Some(new_structure_node("chapter", title));
+            }
+            ManuscriptBlock::Paragraph(text) => {
+                if !separator.is_empty() && normalize_separator(&text) == separator {
+                    let current = chapter.get_or_insert_with(|| {
+                        new_structure_node("chapter", "Untitled Chapter".to_string())
+                    });
+                    push_scene(current, &mut paragraphs, &mut scenes);
+                } else {
+                    paragraphs.push(text);
+                }
+            }
+        }
+    }
+
+    if chapter.is_some() || !paragraphs.is_empty() {
+        let mut current = chapter
+            .unwrap_or_else(|| new_structure_node("chapter", "Untitled Chapter".to_string()));
+        push_scene(&mut current, &mut paragraphs, &mut scenes);
+        act.children.push(current);
+    }
+
+    (vec![act], scenes)
+}
+
+/// Imports a finished draft as a new project: level-1 headings start
+/// chapters and separator lines (default `* * *`) split scenes.
+#[tauri::command]
+pub fn import_manuscript_file(
+    file_path: String,
+    series_id: String,
+    series_index: String,
+    title: String,
+    scene_separator: Option<String>,
+) -> Result<ManuscriptImportResult, String> {
+    validate_no_null_bytes(&file_path, "File path")?;
+    let path = Path::new(&file_path);
+    let metadata = fs::metadata(path).map_err(|e| format!("Failed to read document: {e}"))?;
+    validate_file_size(
+        metadata.len(),
+        MAX_MANUSCRIPT_IMPORT_SIZE,
+        "Manuscript file",
+    )?;
+
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    let blocks = match extension.as_str() {
+        "md" | "markdown" | "txt" => {
+            let source =
+                fs::read_to_string(path).map_err(|e| format!("Failed to read document: {e}"))?;
+            markdown_blocks(source.trim_start_matches('\u{feff}'))
+        }
+        "docx" => docx_blocks(path)?,
+        _ => return Err("Only .md and .docx manuscripts can be imported".to_string()),
+    };
+
+    let separator = scene_separator.unwrap_or_else(|| DEFAULT_SCENE_SEPARATOR.to_string());
+    let (structure, scenes) = build_structure(blocks, &separator);
+    let chapters = structure.iter().map(|act| act.children.len()).sum();
+    let scene_count = scenes.len();
+
+    let title = if title.trim().is_empty() {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Imported Manuscript")
+            .to_string()
+    } else {
+        title
+    };
+    let project = create_project_from_import(title, series_id, series_index, structure, scenes)?;
+
+    Ok(ManuscriptImportResult {
+        project,
+        chapters,
+        scenes: scene_count,
+    })
+}
@@ -1,11 +1,21 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
+use crate::commands::codex::list_codex_entries;
+use crate::commands::scene::{
+    project_id_for_path, read_scene_content, resolve_scene_file, scene_file_path,
+};
 use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, content_hash, extract_plain_text, find_whole_word_matches_ignore_case,
+    read_text_file, validate_no_null_bytes,
+};
+
+const MENTION_CACHE_FILE: &str = "mention_cache.json";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,6 +30,101 @@ pub struct Mention {
     pub created_at: i64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionScanResult {
+    pub mentions: Vec<Mention>,
+    /// True when every scene was served from the cache
+    pub from_cache: bool,
+    pub scenes_rescanned: usize,
+}
+
+/// Where a codex name or alias appears in `SceneEntityText::text`. Offsets
+/// count UTF-16 code units, like JavaScript string indices; `end` is
+/// exclusive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntitySpan {
+    pub start: usize,
+    pub end: usize,
+    pub codex_entry_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneEntityText {
+    pub scene_id: String,
+    /// Plain text of the scene, paragraphs separated by blank lines
+    pub text: String,
+    pub spans: Vec<EntitySpan>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedSceneMentions {
+    content_hash: String,
+    /// (position, context) pairs; ids and timestamps are minted per call
+    hits: Vec<(usize, String)>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedEntryMentions {
+    terms: Vec<String>,
+    scenes: HashMap<String, CachedSceneMentions>,
+}
+
+/// Per-codex-entry scene scan results keyed by scene file, stored in
+/// `<project>/.meta/mention_cache.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MentionCache {
+    entries: HashMap<String, CachedEntryMentions>,
+}
+
+fn mention_cache_path(project_path: &str) -> PathBuf {
+    Path::new(project_path)
+        .join(".meta")
+        .join(MENTION_CACHE_FILE)
+}
+
+fn load_mention_cache(project_path: &str) -> MentionCache {
+    // A missing or corrupt cache just means a full rescan.
+    fs::read_to_string(mention_cache_path(project_path))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn store_mention_cache(project_path: &str, cache: &MentionCache) -> Result<(), String> {
+    let path = mention_cache_path(project_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .meta directory: {e}"))?;
+    }
+    let json = serde_json::to_string(cache)
+        .map_err(|e| format!("Failed to serialize mention cache: {e}"))?;
+    atomic_write(&path, &json)
+}
+
+/// Drops cached scan results for a scene so the next scan rereads it.
+pub(crate) fn invalidate_scene_mentions(
+    project_path: &str,
+    scene_file: &str,
+) -> Result<(), String> {
+    if !mention_cache_path(project_path).exists() {
+        return Ok(());
+    }
+    let mut cache = load_mention_cache(project_path);
+    let mut changed = false;
+    for entry in cache.entries.values_mut() {
+        changed |= entry.scenes.remove(scene_file).is_some();
+    }
+    if changed {
+        store_mention_cache(project_path, &cache)?;
+    }
+    Ok(())
+}
+
 fn normalize_terms(entry_name: String, aliases: Vec<String>) -> Vec<String> {
     let mut seen = HashSet::new();
     let mut terms = Vec::new();
@@ -92,8 +197,13 @@ fn find_all_mentions_in_text(
     }
 }
 
+/// Like `find_mentions`, but reports whether scene results came from the
+/// mention cache. Only scenes whose content hash changed are rescanned.
 #[tauri::command]
-pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec<Mention>, String> {
+pub fn scan_mentions(
+    project_path: String,
+    codex_entry_id: String,
+) -> Result<MentionScanResult, String> {
     let conn = open_app_db()?;
 
     let (project_id, series_id): (String, String) = conn
@@ -113,8 +223,13 @@ pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec
         .optional()
         .map_err(|e| format!("Failed to load codex entry for mention scan: {e}"))?;
 
+    let empty = MentionScanResult {
+        mentions: Vec::new(),
+        from_cache: false,
+        scenes_rescanned: 0,
+    };
     let Some(codex_payload) = codex_payload else {
-        return Ok(Vec::new());
+        return Ok(empty);
     };
 
     let codex_value: serde_json::Value = serde_json::from_str(&codex_payload)
@@ -136,10 +251,20 @@ pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec
 
     let terms = normalize_terms(entry_name, aliases);
     if terms.is_empty() {
-        return Ok(Vec::new());
+        return Ok(empty);
     }
 
     let mut mentions = Vec::new();
+    let mut cache = load_mention_cache(&project_path);
+    let mut entry_cache = cache.entries.remove(&codex_entry_id).unwrap_or_default();
+    if entry_cache.terms != terms {
+        entry_cache = CachedEntryMentions {
+            terms: terms.clone(),
+            scenes: HashMap::new(),
+        };
+    }
+    let mut live_scenes = HashMap::new();
+    let mut scenes_rescanned = 0usize;
 
     let mut scene_stmt = conn
         .prepare(
@@ -166,21 +291,59 @@ pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec
             row.map_err(|e| format!("Failed to decode scene metadata row: {e}"))?;
         let scene_path = PathBuf::from(&project_path)
             .join("manuscript")
-            .join(scene_file);
-        let content = fs::read_to_string(&scene_path)
+            .join(&scene_file);
+        let content = read_text_file(&scene_path)
             .map_err(|e| format!("Failed to read scene '{}': {e}", scene_path.display()))?;
         if content.is_empty() {
             continue;
         }
-        find_all_mentions_in_text(
-            &codex_entry_id,
-            "scene",
-            &scene_id,
-            &title,
-            &content,
-            &terms,
-            &mut mentions,
-        );
+
+        let hash = content_hash(&content);
+        let cached = entry_cache
+            .scenes
+            .remove(&scene_file)
+            .filter(|cached| cached.content_hash == hash);
+        let scene_cache = match cached {
+            Some(cached) => cached,
+            None => {
+                scenes_rescanned += 1;
+                let mut found = Vec::new();
+                find_all_mentions_in_text(
+                    &codex_entry_id,
+                    "scene",
+                    &scene_id,
+                    &title,
+                    &content,
+                    &terms,
+                    &mut found,
+                );
+                CachedSceneMentions {
+                    content_hash: hash,
+                    hits: found
+                        .into_iter()
+                        .map(|mention| (mention.position, mention.context))
+                        .collect(),
+                }
+            }
+        };
+        mentions.extend(scene_cache.hits.iter().map(|(position, context)| Mention {
+            id: uuid::Uuid::new_v4().to_string(),
+            codex_entry_id: codex_entry_id.clone(),
+            source_type: "scene".to_string(),
+            source_id: scene_id.clone(),
+            source_title: title.clone(),
+            position: *position,
+            context: context.clone(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        }));
+        live_scenes.insert(scene_file, scene_cache);
+    }
+
+    // Scenes no longer in the project fall out of the cache here.
+    entry_cache.scenes = live_scenes;
+    cache.entries.insert(codex_entry_id.clone(), entry_cache);
+    if let Err(e) = store_mention_cache(&project_path, &cache) {
+        log::warn!("Failed to write mention cache: {e}");
     }
 
     let mut snippet_stmt = conn
@@ -297,10 +460,75 @@ pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec
         }
     }
 
-    Ok(mentions)
+    Ok(MentionScanResult {
+        mentions,
+        from_cache: scenes_rescanned == 0,
+        scenes_rescanned,
+    })
+}
+
+#[tauri::command]
+pub fn find_mentions(project_path: String, codex_entry_id: String) -> Result<Vec<Mention>, String> {
+    Ok(scan_mentions(project_path, codex_entry_id)?.mentions)
 }
 
 #[tauri::command]
 pub fn count_mentions(project_path: String, codex_entry_id: String) -> Result<usize, String> {
     Ok(find_mentions(project_path, codex_entry_id)?.len())
 }
+
+/// Scene text with spans for every whole-word, case-insensitive occurrence of
+/// a codex entry name or alias. Where names overlap ("Anna" inside "Anna
+/// Kerr") the longer match wins.
+#[tauri::command]
+pub fn search_scene_with_entities(
+    project_path: String,
+    scene_id: String,
+) -> Result<SceneEntityText, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&scene_id, "Scene id")?;
+    let scene_file = {
+        let conn = open_app_db()?;
+        let project_id = project_id_for_path(&conn, &project_path)?;
+        resolve_scene_file(&conn, &project_id, &scene_id)?
+    };
+    let content = read_scene_content(&scene_file_path(&project_path, &scene_file))?;
+    let text = extract_plain_text(&content);
+
+    // (byte start, byte end, entry id) in the scene text
+    let mut candidates = Vec::new();
+    for entry in list_codex_entries(project_path)? {
+        for term in normalize_terms(entry.name, entry.aliases) {
+            for (start, end) in find_whole_word_matches_ignore_case(&text, &term) {
+                candidates.push((start, end, entry.id.clone()));
+            }
+        }
+    }
+    candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let mut spans = Vec::new();
+    let mut covered_until = 0usize;
+    let mut utf16_cursor = (0usize, 0usize); // (byte offset, UTF-16 offset)
+    let mut to_utf16 = |byte: usize| {
+        utf16_cursor.1 += text[utf16_cursor.0..byte].encode_utf16().count();
+        utf16_cursor.0 = byte;
+        utf16_cursor.1
+    };
+    for (start, end, codex_entry_id) in candidates {
+        if start < covered_until {
+            continue;
+        }
+        covered_until = end;
+        spans.push(EntitySpan {
+            start: to_utf16(start),
+            end: to_utf16(end),
+            codex_entry_id,
+        });
+    }
+
+    Ok(SceneEntityText {
+        scene_id,
+        text,
+        spans,
+    })
+}
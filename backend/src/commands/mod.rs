@@ -5,16 +5,37 @@ pub mod backup;
 pub mod backup_emergency;
 pub mod chat;
 pub mod codex;
+pub mod codex_category;
+pub mod codex_graph;
+pub mod codex_images;
 pub mod collaboration;
+pub mod data_root;
+pub mod drive_auto_backup;
+pub mod export;
+pub mod google_drive;
 pub mod google_oauth;
+pub mod logging;
+pub mod maintenance;
+pub mod manuscript_import;
 pub mod mention;
+pub mod outline;
+pub mod pov;
+pub mod progress;
 pub mod project;
+pub mod project_lock;
+pub mod prose;
 pub mod scene;
+pub mod scene_autosave;
 pub mod scene_note;
+pub mod scrivener;
 pub mod search;
 pub mod security;
 pub mod series;
+pub mod series_bible;
+pub mod shutdown;
 pub mod snippet;
+pub mod spellcheck;
+pub mod sync_conflicts;
 
 // Re-export all commands for easy access in lib.rs
 pub use app_state::*;
@@ -22,12 +43,33 @@ pub use backup::*;
 pub use backup_emergency::*;
 pub use chat::*;
 pub use codex::*;
+pub use codex_category::*;
+pub use codex_graph::*;
+pub use codex_images::*;
 pub use collaboration::*;
+pub use data_root::*;
+pub use drive_auto_backup::*;
+pub use export::*;
+pub use google_drive::*;
 pub use google_oauth::*;
+pub use logging::*;
+pub use maintenance::*;
+pub use manuscript_import::*;
 pub use mention::*;
+pub use outline::*;
+pub use pov::*;
+pub use progress::*;
 pub use project::*;
+pub use project_lock::*;
+pub use prose::*;
 pub use scene::*;
+pub use scene_autosave::*;
 pub use scene_note::*;
+pub use scrivener::*;
 pub use search::*;
 pub use series::*;
+pub use series_bible::*;
+pub use shutdown::*;
 pub use snippet::*;
+pub use spellcheck::*;
+pub use sync_conflicts::*;
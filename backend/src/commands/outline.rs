@@ -0,0 +1,288 @@
+// Scene-by-scene outline export (planning document for editors)
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::codex::{list_codex_entries, list_scene_codex_links};
+use crate::commands::project::{get_linear_scenes, get_project_by_path, get_structure};
+use crate::commands::scene_note::{list_scene_notes, SceneNoteOverview};
+use crate::models::StructureNode;
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, extract_paragraphs, render_markdown, resolve_export_path, validate_no_null_bytes,
+    write_docx, DocBlock,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OutlineExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub scene_count: usize,
+}
+
+/// One entry of a beat sheet. Consecutive scenes without a note collapse into
+/// a single gap so the missing stretch is visible without padding the list.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum BeatSheetItem {
+    Beat {
+        scene_id: String,
+        scene_title: String,
+        /// Position of the scene in reading order, starting at 0
+        scene_index: usize,
+        paragraphs: Vec<String>,
+    },
+    Gap {
+        scene_ids: Vec<String>,
+        scene_titles: Vec<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BeatSheetChapter {
+    pub act_title: Option<String>,
+    pub chapter_title: Option<String>,
+    pub items: Vec<BeatSheetItem>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BeatSheet {
+    pub chapters: Vec<BeatSheetChapter>,
+    pub beat_count: usize,
+    pub missing_count: usize,
+}
+
+struct OutlineSceneMeta {
+    status: String,
+    word_count: i32,
+    pov_character: Option<String>,
+}
+
+/// Everything the outline shows about scenes, keyed by scene id
+struct OutlineSource {
+    meta: HashMap<String, OutlineSceneMeta>,
+    codex_names: HashMap<String, Vec<String>>,
+    codex_by_id: HashMap<String, String>,
+    notes: BTreeMap<String, SceneNoteOverview>,
+}
+
+fn load_scene_meta(project_path: &str) -> Result<HashMap<String, OutlineSceneMeta>, String> {
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, project_path)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT scene_id, status, word_count, pov_character FROM scene_metadata WHERE project_id = ?1",
+        )
+        .map_err(|e| format!("Failed to prepare scene metadata query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project.id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                OutlineSceneMeta {
+                    status: row.get(1)?,
+                    word_count: row.get(2)?,
+                    pov_character: row.get(3)?,
+                },
+            ))
+        })
+        .map_err(|e| format!("Failed to query scene metadata: {e}"))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to decode scene metadata row: {e}"))
+}
+
+fn load_outline_source(project_path: &str) -> Result<OutlineSource, String> {
+    let codex_by_id: HashMap<String, String> = list_codex_entries(project_path.to_string())?
+        .into_iter()
+        .map(|entry| (entry.id, entry.name))
+        .collect();
+
+    let mut linked: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for link in list_scene_codex_links(project_path.to_string())? {
+        if let Some(name) = codex_by_id.get(&link.codex_id) {
+            linked
+                .entry(link.scene_id)
+                .or_default()
+                .insert(name.clone());
+        }
+    }
+
+    Ok(OutlineSource {
+        meta: load_scene_meta(project_path)?,
+        codex_names: linked
+            .into_iter()
+            .map(|(scene_id, names)| (scene_id, names.into_iter().collect()))
+            .collect(),
+        codex_by_id,
+        notes: list_scene_notes(project_path.to_string())?,
+    })
+}
+
+/// Paragraphs of a scene note (stored as an editor document)
+pub(crate) fn scene_note_paragraphs(note: &SceneNoteOverview) -> Vec<String> {
+    let content = match &note.note.content {
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    extract_paragraphs(&content)
+        .into_iter()
+        .map(|paragraph| paragraph.trim().to_string())
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect()
+}
+
+fn scene_summary_line(scene_id: &str, source: &OutlineSource) -> Option<String> {
+    let meta = source.meta.get(scene_id)?;
+    let mut parts = vec![
+        meta.status.clone(),
+        format!("{} words", meta.word_count.max(0)),
+    ];
+    if let Some(pov) = meta.pov_character.as_deref().filter(|pov| !pov.is_empty()) {
+        let pov = source.codex_by_id.get(pov).map_or(pov, String::as_str);
+        parts.push(format!("POV: {pov}"));
+    }
+    Some(parts.join(" \u{00B7} "))
+}
+
+fn outline_blocks(
+    nodes: &[StructureNode],
+    source: &OutlineSource,
+    blocks: &mut Vec<DocBlock>,
+    scene_count: &mut usize,
+) {
+    for node in nodes {
+        match node.node_type.as_str() {
+            "act" => blocks.push(DocBlock::Heading(2, node.title.clone())),
+            "chapter" => blocks.push(DocBlock::Heading(3, node.title.clone())),
+            "scene" => {
+                *scene_count += 1;
+                blocks.push(DocBlock::Paragraph(node.title.clone()));
+                if let Some(line) = scene_summary_line(&node.id, source) {
+                    blocks.push(DocBlock::Bullet(line));
+                }
+                if let Some(names) = source.codex_names.get(&node.id) {
+                    blocks.push(DocBlock::Bullet(format!("Codex: {}", names.join(", "))));
+                }
+                if let Some(note) = source.notes.get(&node.id) {
+                    blocks.extend(
+                        scene_note_paragraphs(note)
+                            .into_iter()
+                            .map(DocBlock::Paragraph),
+                    );
+                }
+            }
+            _ => {}
+        }
+        outline_blocks(&node.children, source, blocks, scene_count);
+    }
+}
+
+/// Writes the act/chapter/scene outline with each scene's status, word count,
+/// POV, linked codex entries and scene note as synopsis. `format` is
+/// "markdown" or "docx".
+#[tauri::command]
+pub fn export_outline(
+    project_path: String,
+    output_path: String,
+    format: String,
+) -> Result<OutlineExportResult, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let target = resolve_export_path(Some(&project_path), &output_path)?;
+    let format = match format.trim().to_lowercase().as_str() {
+        "markdown" | "md" => "markdown",
+        "docx" => "docx",
+        other => return Err(format!("Unsupported outline format: {other}")),
+    };
+
+    let project_title = {
+        let conn = open_app_db()?;
+        get_project_by_path(&conn, &project_path)?.title
+    };
+    let structure = get_structure(project_path.clone())?;
+    let source = load_outline_source(&project_path)?;
+
+    let mut blocks = vec![DocBlock::Heading(
+        1,
+        format!("{project_title} \u{2014} Outline"),
+    )];
+    let mut scene_count = 0;
+    outline_blocks(&structure, &source, &mut blocks, &mut scene_count);
+
+    match format {
+        "docx" => write_docx(&target, &blocks)?,
+        _ => atomic_write(&target, &render_markdown(&blocks))?,
+    }
+
+    Ok(OutlineExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        scene_count,
+    })
+}
+
+/// Collects scene notes in reading order into a beat list grouped by chapter.
+#[tauri::command]
+pub fn get_beat_sheet(project_path: String) -> Result<BeatSheet, String> {
+    let scenes = get_linear_scenes(project_path.clone())?;
+    let notes = list_scene_notes(project_path)?;
+
+    let mut sheet = BeatSheet {
+        chapters: Vec::new(),
+        beat_count: 0,
+        missing_count: 0,
+    };
+    for (scene_index, scene) in scenes.into_iter().enumerate() {
+        let same_chapter = sheet.chapters.last().is_some_and(|chapter| {
+            chapter.act_title == scene.act_title && chapter.chapter_title == scene.chapter_title
+        });
+        if !same_chapter {
+            sheet.chapters.push(BeatSheetChapter {
+                act_title: scene.act_title.clone(),
+                chapter_title: scene.chapter_title.clone(),
+                items: Vec::new(),
+            });
+        }
+        let Some(items) = sheet.chapters.last_mut().map(|chapter| &mut chapter.items) else {
+            continue;
+        };
+
+        let paragraphs = notes
+            .get(&scene.scene_id)
+            .map(scene_note_paragraphs)
+            .unwrap_or_default();
+        if !paragraphs.is_empty() {
+            sheet.beat_count += 1;
+            items.push(BeatSheetItem::Beat {
+                scene_id: scene.scene_id,
+                scene_title: scene.title,
+                scene_index,
+                paragraphs,
+            });
+            continue;
+        }
+
+        sheet.missing_count += 1;
+        match items.last_mut() {
+            Some(BeatSheetItem::Gap {
+                scene_ids,
+                scene_titles,
+            }) => {
+                scene_ids.push(scene.scene_id);
+                scene_titles.push(scene.title);
+            }
+            _ => items.push(BeatSheetItem::Gap {
+                scene_ids: vec![scene.scene_id],
+                scene_titles: vec![scene.title],
+            }),
+        }
+    }
+    Ok(sheet)
+}
@@ -0,0 +1,134 @@
+// Point-of-view listing for multi-POV manuscripts
+// Scenes store `pov_character` as free text, usually a codex entry id picked in
+// the editor but sometimes a typed name. Both forms are resolved to the codex
+// entry so one character's scenes group together.
+
+use std::collections::HashMap;
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::codex::list_codex_entries;
+use crate::commands::project::{get_linear_scenes, get_project_by_path, LinearScene};
+use crate::storage::open_app_db;
+use crate::utils::validate_no_null_bytes;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PovCharacter {
+    /// Codex entry id when the POV resolves to one, otherwise the stored text
+    pub pov: String,
+    pub name: String,
+    pub codex_entry_id: Option<String>,
+    pub scene_count: usize,
+    pub word_count: i64,
+}
+
+/// Maps stored POV values to (key, display name, codex entry id)
+struct PovResolver {
+    by_id: HashMap<String, String>,
+    by_name: HashMap<String, String>,
+}
+
+impl PovResolver {
+    fn load(project_path: &str) -> Result<Self, String> {
+        let entries = list_codex_entries(project_path.to_string())?;
+        Ok(Self {
+            by_name: entries
+                .iter()
+                .map(|entry| (entry.name.trim().to_lowercase(), entry.id.clone()))
+                .collect(),
+            by_id: entries
+                .into_iter()
+                .map(|entry| (entry.id, entry.name))
+                .collect(),
+        })
+    }
+
+    fn resolve(&self, pov: &str) -> (String, String, Option<String>) {
+        let pov = pov.trim();
+        let entry_id = if self.by_id.contains_key(pov) {
+            Some(pov.to_string())
+        } else {
+            self.by_name.get(&pov.to_lowercase()).cloned()
+        };
+        match entry_id {
+            Some(id) => (id.clone(), self.by_id[&id].clone(), Some(id)),
+            None => (pov.to_string(), pov.to_string(), None),
+        }
+    }
+}
+
+fn load_scene_povs(project_path: &str) -> Result<HashMap<String, String>, String> {
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, project_path)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT scene_id, pov_character FROM scene_metadata
+             WHERE project_id = ?1 AND pov_character IS NOT NULL AND TRIM(pov_character) != ''",
+        )
+        .map_err(|e| format!("Failed to prepare POV query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project.id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| format!("Failed to query scene POVs: {e}"))?;
+    rows.collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to decode scene POV row: {e}"))
+}
+
+/// Distinct POV characters across the manuscript with their scene and word
+/// counts, most scenes first. Scenes without a POV are not counted.
+#[tauri::command]
+pub fn list_pov_characters(project_path: String) -> Result<Vec<PovCharacter>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let resolver = PovResolver::load(&project_path)?;
+    let povs = load_scene_povs(&project_path)?;
+
+    let mut characters: Vec<PovCharacter> = Vec::new();
+    for scene in get_linear_scenes(project_path)? {
+        let Some(pov) = povs.get(&scene.scene_id) else {
+            continue;
+        };
+        let (key, name, codex_entry_id) = resolver.resolve(pov);
+        match characters.iter_mut().find(|character| character.pov == key) {
+            Some(character) => {
+                character.scene_count += 1;
+                character.word_count += i64::from(scene.word_count.max(0));
+            }
+            None => characters.push(PovCharacter {
+                pov: key,
+                name,
+                codex_entry_id,
+                scene_count: 1,
+                word_count: i64::from(scene.word_count.max(0)),
+            }),
+        }
+    }
+
+    characters.sort_by(|a, b| {
+        b.scene_count
+            .cmp(&a.scene_count)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    Ok(characters)
+}
+
+/// Scenes told from `pov` in reading order. `pov` may be a codex entry id or a
+/// name; either matches scenes that stored the other form.
+#[tauri::command]
+pub fn get_scenes_by_pov(project_path: String, pov: String) -> Result<Vec<LinearScene>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&pov, "POV")?;
+    let resolver = PovResolver::load(&project_path)?;
+    let povs = load_scene_povs(&project_path)?;
+    let (wanted, _, _) = resolver.resolve(&pov);
+
+    Ok(get_linear_scenes(project_path)?
+        .into_iter()
+        .filter(|scene| {
+            povs.get(&scene.scene_id)
+                .is_some_and(|scene_pov| resolver.resolve(scene_pov).0 == wanted)
+        })
+        .collect())
+}
@@ -0,0 +1,336 @@
+// Writing progress (daily project word totals recorded on scene saves)
+
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::project::get_project_by_path;
+use crate::storage::{
+    open_app_db, project_setting_delete, project_setting_get, project_setting_set,
+    PROJECT_TARGET_WORDS,
+};
+use crate::utils::{timestamp, validate_no_null_bytes};
+
+const DEFAULT_MILESTONE_INTERVAL: i64 = 10_000;
+/// Calendar days the completion estimate averages over
+const ESTIMATE_WINDOW_DAYS: i64 = 14;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressPoint {
+    pub date: String,
+    pub cumulative_words: i64,
+    pub daily_words: i64,
+    /// Highest word-count milestone first reached on this day
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionEstimate {
+    pub target_words: Option<i64>,
+    pub current_words: i64,
+    pub remaining_words: i64,
+    pub target_reached: bool,
+    /// Words per calendar day over the last `window_days` days
+    pub recent_daily_average: f64,
+    pub window_days: i64,
+    /// None without a target, once it is reached, or with no recent progress
+    pub estimated_days_remaining: Option<i64>,
+    pub estimated_completion_date: Option<String>,
+}
+
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Stores today's total word count for the project. Called after every
+/// scene save so the history has one row per day the author wrote.
+pub(crate) fn record_project_progress(conn: &Connection, project_id: &str) -> Result<(), String> {
+    let total_words: i64 = conn
+        .query_row(
+            "SELECT COALESCE(SUM(word_count), 0) FROM scene_metadata WHERE project_id = ?1",
+            params![project_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to total project word count: {e}"))?;
+
+    conn.execute(
+        r#"
+        INSERT INTO writing_progress(project_id, day, total_words, updated_at)
+        VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT(project_id, day) DO UPDATE SET
+            total_words = excluded.total_words,
+            updated_at = excluded.updated_at
+        "#,
+        params![project_id, today(), total_words, timestamp::now_millis()],
+    )
+    .map_err(|e| format!("Failed to record writing progress: {e}"))?;
+    Ok(())
+}
+
+/// The manuscript word target, a project setting
+fn project_word_target(conn: &Connection, project_id: &str) -> Option<i64> {
+    project_setting_get(conn, project_id, PROJECT_TARGET_WORDS)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<i64>(&value).ok())
+        .filter(|target| *target > 0)
+}
+
+/// Words added on days from `since` onwards. The first recorded day has no
+/// earlier total to compare against, so it counts as zero rather than as the
+/// whole manuscript.
+fn words_written_since(conn: &Connection, project_id: &str, since: &str) -> Result<i64, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT day, total_words FROM writing_progress WHERE project_id = ?1 ORDER BY day ASC",
+        )
+        .map_err(|e| format!("Failed to prepare progress query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|e| format!("Failed to query writing progress: {e}"))?;
+
+    let mut written = 0i64;
+    let mut previous_total: Option<i64> = None;
+    for row in rows {
+        let (day, total) = row.map_err(|e| format!("Failed to decode progress row: {e}"))?;
+        if let Some(previous) = previous_total {
+            if day.as_str() >= since {
+                written += total - previous;
+            }
+        }
+        previous_total = Some(total);
+    }
+    Ok(written)
+}
+
+fn validate_day(value: &str, label: &str) -> Result<(), String> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|_| ())
+        .map_err(|_| format!("{label} must be a YYYY-MM-DD date"))
+}
+
+/// Chart points for `rows` (day, total words), oldest first, keeping the days
+/// between `from` and `to`. Days before the window still feed the running
+/// totals so the first visible day's delta is correct. The first recorded day
+/// has no earlier total, so it shows no daily words and no milestone: an
+/// existing manuscript's words weren't written that day.
+fn progress_points(
+    rows: Vec<(String, i64)>,
+    from: Option<&str>,
+    to: Option<&str>,
+    interval: i64,
+) -> Vec<ProgressPoint> {
+    let mut points = Vec::new();
+    let mut previous_total: Option<i64> = None;
+    let mut best_total: Option<i64> = None;
+    for (day, total) in rows {
+        let in_range = from.map_or(true, |from| day.as_str() >= from)
+            && to.map_or(true, |to| day.as_str() <= to);
+
+        let milestone = match best_total {
+            Some(best) if total / interval > best / interval => Some((total / interval) * interval),
+            _ => None,
+        };
+        best_total = Some(best_total.map_or(total, |best| best.max(total)));
+
+        if in_range {
+            points.push(ProgressPoint {
+                date: day,
+                cumulative_words: total,
+                daily_words: previous_total.map_or(0, |previous| total - previous),
+                milestone,
+            });
+        }
+        previous_total = Some(total);
+    }
+    points
+}
+
+/// Returns one point per recorded day between `from` and `to` (inclusive,
+/// both optional). Progress is only recorded per project, not per chapter,
+/// so milestones mark word-count multiples instead of chapter goals: each
+/// multiple of `milestone_interval` words (default 10,000) on the day it was
+/// first crossed.
+#[tauri::command]
+pub fn get_progress_chart_data(
+    project_path: String,
+    from: Option<String>,
+    to: Option<String>,
+    milestone_interval: Option<i64>,
+) -> Result<Vec<ProgressPoint>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    if let Some(from) = &from {
+        validate_day(from, "From")?;
+    }
+    if let Some(to) = &to {
+        validate_day(to, "To")?;
+    }
+    let interval = milestone_interval
+        .filter(|value| *value > 0)
+        .unwrap_or(DEFAULT_MILESTONE_INTERVAL);
+
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT day, total_words
+            FROM writing_progress
+            WHERE project_id = ?1
+            ORDER BY day ASC
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare progress query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project.id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|e| format!("Failed to query writing progress: {e}"))?;
+
+    let rows = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to decode progress row: {e}"))?;
+
+    Ok(progress_points(
+        rows,
+        from.as_deref(),
+        to.as_deref(),
+        interval,
+    ))
+}
+
+/// Sets or clears (`None`) the project's manuscript word target.
+#[tauri::command]
+pub fn set_project_word_target(
+    project_path: String,
+    target_words: Option<i64>,
+) -> Result<(), String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    match target_words {
+        Some(target) if target <= 0 => Err("Word target must be positive".to_string()),
+        Some(target) => project_setting_set(
+            &conn,
+            &project.id,
+            PROJECT_TARGET_WORDS,
+            &target.to_string(),
+            timestamp::now_millis(),
+        ),
+        None => project_setting_delete(&conn, &project.id, PROJECT_TARGET_WORDS),
+    }
+}
+
+/// Projects a finish date from the project's word target and the average
+/// daily words over the last ESTIMATE_WINDOW_DAYS days.
+#[tauri::command]
+pub fn get_completion_estimate(project_path: String) -> Result<CompletionEstimate, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+
+    let current_words: i64 = conn
+        .query_row(
+            "SELECT COALESCE(SUM(word_count), 0) FROM scene_metadata WHERE project_id = ?1",
+            params![project.id],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to total project word count: {e}"))?;
+    let target_words = project_word_target(&conn, &project.id);
+
+    let today = chrono::Local::now().date_naive();
+    let window_start = today - chrono::Duration::days(ESTIMATE_WINDOW_DAYS - 1);
+    let written = words_written_since(
+        &conn,
+        &project.id,
+        &window_start.format("%Y-%m-%d").to_string(),
+    )?;
+    let recent_daily_average = written.max(0) as f64 / ESTIMATE_WINDOW_DAYS as f64;
+
+    let remaining_words = target_words.map_or(0, |target| (target - current_words).max(0));
+    let target_reached = target_words.is_some() && remaining_words == 0;
+    let estimated_days_remaining = match target_words {
+        Some(_) if !target_reached && recent_daily_average > 0.0 => {
+            Some((remaining_words as f64 / recent_daily_average).ceil() as i64)
+        }
+        _ => None,
+    };
+    let estimated_completion_date = estimated_days_remaining
+        .and_then(|days| today.checked_add_days(chrono::Days::new(days as u64)))
+        .map(|date| date.format("%Y-%m-%d").to_string());
+
+    Ok(CompletionEstimate {
+        target_words,
+        current_words,
+        remaining_words,
+        target_reached,
+        recent_daily_average,
+        window_days: ESTIMATE_WINDOW_DAYS,
+        estimated_days_remaining,
+        estimated_completion_date,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(totals: &[(&str, i64)]) -> Vec<(String, i64)> {
+        totals
+            .iter()
+            .map(|(day, total)| (day.to_string(), *total))
+            .collect()
+    }
+
+    #[test]
+    fn first_day_of_an_existing_project_counts_nothing() {
+        let points = progress_points(
+            rows(&[("2026-03-01", 45_200), ("2026-03-02", 46_100)]),
+            None,
+            None,
+            10_000,
+        );
+        assert_eq!(points[0].daily_words, 0);
+        assert_eq!(points[0].milestone, None);
+        assert_eq!(points[1].daily_words, 900);
+        assert_eq!(points[1].milestone, None);
+    }
+
+    #[test]
+    fn milestones_mark_the_day_a_multiple_is_crossed() {
+        let points = progress_points(
+            rows(&[
+                ("2026-03-01", 9_500),
+                ("2026-03-02", 10_400),
+                ("2026-03-03", 9_800),
+                ("2026-03-04", 10_200),
+                ("2026-03-05", 21_000),
+            ]),
+            None,
+            None,
+            10_000,
+        );
+        let milestones: Vec<_> = points.iter().map(|point| point.milestone).collect();
+        assert_eq!(
+            milestones,
+            vec![None, Some(10_000), None, None, Some(20_000)]
+        );
+    }
+
+    #[test]
+    fn days_before_the_window_feed_the_first_delta() {
+        let points = progress_points(
+            rows(&[("2026-03-01", 1_000), ("2026-03-02", 1_600)]),
+            Some("2026-03-02"),
+            None,
+            10_000,
+        );
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].daily_words, 600);
+    }
+}
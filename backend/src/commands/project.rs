@@ -2,17 +2,30 @@
 
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
-
-use crate::models::{ProjectMeta, StructureNode};
-use crate::storage::{open_app_db, with_transaction};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::UNIX_EPOCH;
+use tauri::Window;
+
+use crate::commands::codex::{get_codex_entry, upsert_codex_entry};
+use crate::commands::codex_images::resolve_entry_images;
+use crate::commands::project_lock::{acquire_project_lock, window_session_id, ProjectLockWarning};
+use crate::commands::series::count_series_projects;
+use crate::models::{ProjectMeta, SceneCodexLink, StructureNode};
+use crate::storage::{
+    open_app_db, project_setting_delete, project_setting_get, project_setting_set,
+    with_transaction, PROJECT_SCENE_FILE_NAMING,
+};
 use crate::utils::{
-    atomic_write, get_app_dir, get_projects_dir, slugify, timestamp, validate_no_null_bytes,
-    validate_project_creation, validate_project_title,
+    atomic_write, count_words, get_app_dir, get_projects_dir, paragraphs_to_doc, read_text_file,
+    slugify, timestamp, validate_no_null_bytes, validate_project_creation, validate_project_title,
 };
 
+const SCENE_FILE_NAMING_UUID: &str = "uuid";
+const SCENE_FILE_NAMING_TITLE: &str = "title";
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RecentProject {
@@ -42,6 +55,15 @@ struct StructureNodeRow {
     scene_file: Option<String>,
 }
 
+/// Returned by `delete_project` so the UI can offer to delete a series that
+/// just lost its last book
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectDeletion {
+    pub series_id: String,
+    pub series_empty: bool,
+}
+
 fn get_projects_trash_dir() -> Result<PathBuf, String> {
     let app_dir = get_app_dir()?;
     Ok(app_dir.join("Trash"))
@@ -72,7 +94,10 @@ fn row_to_project(row: &rusqlite::Row<'_>) -> Result<ProjectMeta, rusqlite::Erro
     })
 }
 
-fn get_project_by_path(conn: &Connection, project_path: &str) -> Result<ProjectMeta, String> {
+pub(crate) fn get_project_by_path(
+    conn: &Connection,
+    project_path: &str,
+) -> Result<ProjectMeta, String> {
     conn.query_row(
         r#"
         SELECT id, title, author, description, path, archived, language, cover_image,
@@ -124,38 +149,181 @@ fn upsert_project(conn: &Connection, project: &ProjectMeta) -> Result<(), String
         ],
     )
     .map_err(|e| format!("Failed to upsert project: {e}"))?;
+    write_project_manifest(project);
     Ok(())
 }
 
-fn ensure_unique_series_index(
+/// Copy of the project's registry row inside its folder, so the folder can be
+/// registered again when the app database loses it
+const PROJECT_MANIFEST_FILE: &str = "project.json";
+
+fn project_manifest_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".meta").join(PROJECT_MANIFEST_FILE)
+}
+
+fn write_project_manifest(project: &ProjectMeta) {
+    let project_dir = Path::new(&project.path);
+    if !project_dir.join(".meta").is_dir() {
+        return;
+    }
+    let result = serde_json::to_string_pretty(project)
+        .map_err(|e| e.to_string())
+        .and_then(|json| atomic_write(&project_manifest_path(project_dir), &json));
+    if let Err(e) = result {
+        log::warn!("Failed to write project manifest for {}: {e}", project.path);
+    }
+}
+
+fn read_project_manifest(project_dir: &Path) -> Result<ProjectMeta, String> {
+    let path = project_manifest_path(project_dir);
+    if !path.is_file() {
+        return Err("no .meta/project.json".to_string());
+    }
+    let content = read_text_file(&path)?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid project manifest '{}': {e}", path.display()))
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesIndexConflict {
+    pub project_id: String,
+    pub title: String,
+    pub path: String,
+    pub series_index: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesIndexValidation {
+    /// Trimmed index as it would be stored
+    pub normalized: String,
+    pub valid: bool,
+    pub error: Option<String>,
+    pub conflict: Option<SeriesIndexConflict>,
+}
+
+/// Comparison key for series indexes, so "Book 1", "book 01" and "1" collide.
+fn series_index_key(series_index: &str) -> String {
+    let lowered = series_index.trim().to_lowercase();
+    let mut rest = lowered.as_str();
+    for prefix in ["book", "volume", "vol.", "vol", "no.", "#"] {
+        if let Some(stripped) = rest.strip_prefix(prefix) {
+            let stripped = stripped.trim_start();
+            if stripped.starts_with(|c: char| c.is_ascii_digit()) {
+                rest = stripped;
+                break;
+            }
+        }
+    }
+    match rest.parse::<u64>() {
+        Ok(number) => number.to_string(),
+        Err(_) => rest.split_whitespace().collect::<Vec<_>>().join(" "),
+    }
+}
+
+fn check_series_index(
     conn: &Connection,
     series_id: &str,
     series_index: &str,
     exclude_project_id: Option<&str>,
-) -> Result<(), String> {
-    let count: i64 = if let Some(exclude) = exclude_project_id {
-        conn.query_row(
-            "SELECT COUNT(1) FROM projects WHERE series_id = ?1 AND series_index = ?2 AND id != ?3",
-            params![series_id, series_index, exclude],
-            |row| row.get(0),
-        )
-        .map_err(|e| format!("Failed to validate series index uniqueness: {e}"))?
-    } else {
-        conn.query_row(
-            "SELECT COUNT(1) FROM projects WHERE series_id = ?1 AND series_index = ?2",
-            params![series_id, series_index],
-            |row| row.get(0),
-        )
-        .map_err(|e| format!("Failed to validate series index uniqueness: {e}"))?
+) -> Result<SeriesIndexValidation, String> {
+    let normalized = match normalize_series_index(series_index) {
+        Ok(normalized) => normalized,
+        Err(error) => {
+            return Ok(SeriesIndexValidation {
+                normalized: series_index.trim().to_string(),
+                valid: false,
+                error: Some(error),
+                conflict: None,
+            })
+        }
     };
 
-    if count > 0 {
-        return Err(format!(
-            "Series index '{}' already exists in this series",
-            series_index
-        ));
+    let mut stmt = conn
+        .prepare("SELECT id, title, path, series_index FROM projects WHERE series_id = ?1")
+        .map_err(|e| format!("Failed to prepare series index query: {e}"))?;
+    let rows = stmt
+        .query_map(params![series_id], |row| {
+            Ok(SeriesIndexConflict {
+                project_id: row.get(0)?,
+                title: row.get(1)?,
+                path: row.get(2)?,
+                series_index: row.get(3)?,
+            })
+        })
+        .map_err(|e| format!("Failed to validate series index uniqueness: {e}"))?;
+
+    let key = series_index_key(&normalized);
+    let mut conflict = None;
+    for row in rows {
+        let existing = row.map_err(|e| format!("Failed to decode series index row: {e}"))?;
+        if Some(existing.project_id.as_str()) != exclude_project_id
+            && series_index_key(&existing.series_index) == key
+        {
+            conflict = Some(existing);
+            break;
+        }
+    }
+
+    Ok(SeriesIndexValidation {
+        error: conflict.as_ref().map(|_| {
+            format!(
+                "Series index '{}' already exists in this series",
+                normalized
+            )
+        }),
+        valid: conflict.is_none(),
+        normalized,
+        conflict,
+    })
+}
+
+pub(crate) fn ensure_unique_series_index(
+    conn: &Connection,
+    series_id: &str,
+    series_index: &str,
+    exclude_project_id: Option<&str>,
+) -> Result<(), String> {
+    let validation = check_series_index(conn, series_id, series_index, exclude_project_id)?;
+    match validation.error {
+        Some(error) => Err(error),
+        None => Ok(()),
     }
-    Ok(())
+}
+
+/// "Book N" for the first number after the highest numeric index in the series
+pub(crate) fn next_series_index(conn: &Connection, series_id: &str) -> Result<String, String> {
+    let mut stmt = conn
+        .prepare("SELECT series_index FROM projects WHERE series_id = ?1")
+        .map_err(|e| format!("Failed to prepare series index query: {e}"))?;
+    let rows = stmt
+        .query_map(params![series_id], |row| row.get::<_, String>(0))
+        .map_err(|e| format!("Failed to query series indexes: {e}"))?;
+
+    let mut highest = 0u64;
+    for row in rows {
+        let series_index = row.map_err(|e| format!("Failed to decode series index row: {e}"))?;
+        if let Ok(number) = series_index_key(&series_index).parse::<u64>() {
+            highest = highest.max(number);
+        }
+    }
+    Ok(format!("Book {}", highest + 1))
+}
+
+/// Checks a series index against every project in the series (not just the
+/// recent list). Used by the new/edit project dialogs before submitting.
+#[tauri::command]
+pub fn validate_series_index(
+    series_id: String,
+    index: String,
+    exclude_project_id: Option<String>,
+) -> Result<SeriesIndexValidation, String> {
+    validate_no_null_bytes(&series_id, "Series id")?;
+    validate_no_null_bytes(&index, "Series index")?;
+    let conn = open_app_db()?;
+    ensure_series_exists(&conn, &series_id)?;
+    check_series_index(&conn, &series_id, &index, exclude_project_id.as_deref())
 }
 
 fn ensure_series_exists(conn: &Connection, series_id: &str) -> Result<(), String> {
@@ -213,6 +381,15 @@ fn resolve_series_for_restored_project(
 }
 
 fn add_recent_entry(conn: &Connection, project_path: &str, title: &str) -> Result<(), String> {
+    upsert_recent_entry(conn, project_path, title, timestamp::now_millis())
+}
+
+fn upsert_recent_entry(
+    conn: &Connection,
+    project_path: &str,
+    title: &str,
+    last_opened: i64,
+) -> Result<(), String> {
     conn.execute(
         r#"
         INSERT INTO recent_projects(project_path, title, last_opened)
@@ -221,7 +398,7 @@ fn add_recent_entry(conn: &Connection, project_path: &str, title: &str) -> Resul
             title = excluded.title,
             last_opened = excluded.last_opened
         "#,
-        params![project_path, title, timestamp::now_millis()],
+        params![project_path, title, last_opened],
     )
     .map_err(|e| format!("Failed to upsert recent project: {e}"))?;
     Ok(())
@@ -262,6 +439,154 @@ fn fetch_structure_rows(
     Ok(result)
 }
 
+/// Renumbers `order_index` to 0, 1, 2… within each parent, keeping the
+/// current order (ties broken by creation), and updates the scene metadata
+/// order and the structure cache to match. Returns how many nodes changed.
+pub(crate) fn renumber_structure_order(
+    conn: &Connection,
+    project_id: &str,
+) -> Result<usize, String> {
+    let rows: Vec<(String, Option<String>, i32)> = {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, parent_id, order_index FROM structure_nodes
+                 WHERE project_id = ?1 ORDER BY order_index ASC, created_at ASC, rowid ASC",
+            )
+            .map_err(|e| format!("Failed to prepare structure order query: {e}"))?;
+        let rows = stmt
+            .query_map(params![project_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .map_err(|e| format!("Failed to query structure order: {e}"))?;
+        rows.collect::<Result<_, _>>()
+            .map_err(|e| format!("Failed to decode structure order row: {e}"))?
+    };
+
+    let mut next_index: HashMap<Option<String>, i32> = HashMap::new();
+    let now = timestamp::now_millis();
+    let mut renumbered = 0;
+    for (id, parent_id, order_index) in rows {
+        let slot = next_index.entry(parent_id).or_insert(0);
+        let expected = *slot;
+        *slot += 1;
+        if order_index == expected {
+            continue;
+        }
+        conn.execute(
+            "UPDATE structure_nodes SET order_index = ?1, updated_at = ?2 WHERE id = ?3 AND project_id = ?4",
+            params![expected, now, id, project_id],
+        )
+        .map_err(|e| format!("Failed to renumber structure node: {e}"))?;
+        renumbered += 1;
+    }
+    if renumbered == 0 {
+        return Ok(0);
+    }
+
+    // Keep the scene metadata's copy of the order in step, as replace_structure does
+    conn.execute(
+        "UPDATE scene_metadata SET order_index = (
+             SELECT order_index FROM structure_nodes
+             WHERE structure_nodes.id = scene_metadata.scene_id
+         )
+         WHERE project_id = ?1 AND scene_id IN (
+             SELECT id FROM structure_nodes WHERE project_id = ?1
+         )",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to sync scene metadata order: {e}"))?;
+    structure_cache().invalidate_project(project_id);
+    Ok(renumbered)
+}
+
+/// Repairs duplicated or gapped `order` values in the binder. Returns the
+/// number of nodes renumbered.
+#[tauri::command]
+pub fn normalize_structure_order(project_path: String) -> Result<usize, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    with_transaction(&conn, |conn| renumber_structure_order(conn, &project.id))
+}
+
+/// Row count and newest `updated_at` for a project's structure. Cheap to read
+/// and changes whenever the structure rows are rewritten.
+type StructureStamp = (i64, Option<i64>);
+
+fn structure_stamp(conn: &Connection, project_id: &str) -> Result<StructureStamp, String> {
+    conn.query_row(
+        "SELECT COUNT(*), MAX(updated_at) FROM structure_nodes WHERE project_id = ?1",
+        params![project_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )
+    .map_err(|e| format!("Failed to read structure stamp: {e}"))
+}
+
+struct CachedStructure {
+    project_id: String,
+    stamp: StructureStamp,
+    nodes: Vec<StructureNode>,
+}
+
+/// Parsed structure trees keyed by project path. `replace_structure`
+/// invalidates entries explicitly; the stamp check catches anything that
+/// rewrites the rows behind our back (snapshot restore, another DB handle).
+#[derive(Default)]
+pub struct StructureCache {
+    entries: Mutex<HashMap<String, CachedStructure>>,
+}
+
+impl StructureCache {
+    fn get(
+        &self,
+        project_path: &str,
+        project_id: &str,
+        stamp: StructureStamp,
+    ) -> Option<Vec<StructureNode>> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(project_path)
+            .filter(|cached| cached.project_id == project_id && cached.stamp == stamp)
+            .map(|cached| cached.nodes.clone())
+    }
+
+    fn insert(
+        &self,
+        project_path: &str,
+        project_id: &str,
+        stamp: StructureStamp,
+        nodes: &[StructureNode],
+    ) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                project_path.to_string(),
+                CachedStructure {
+                    project_id: project_id.to_string(),
+                    stamp,
+                    nodes: nodes.to_vec(),
+                },
+            );
+        }
+    }
+
+    pub fn invalidate_project(&self, project_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, cached| cached.project_id != project_id);
+        }
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+pub fn structure_cache() -> &'static StructureCache {
+    static CACHE: OnceLock<StructureCache> = OnceLock::new();
+    CACHE.get_or_init(StructureCache::default)
+}
+
 fn build_structure_tree(rows: Vec<StructureNodeRow>) -> Vec<StructureNode> {
     let mut grouped: HashMap<Option<String>, Vec<StructureNodeRow>> = HashMap::new();
     for row in rows {
@@ -334,6 +659,8 @@ fn sync_scene_metadata_from_structure(
     collect_scene_nodes(structure, &mut scene_nodes);
     let now = timestamp::now_millis();
 
+    // `updated_at` versions the scene body (save_scene's conflict check), so
+    // structure edits leave it alone on existing rows.
     for (scene_id, scene_file, order_index) in &scene_nodes {
         let existing_created_at: Option<i64> = conn
             .query_row(
@@ -364,8 +691,7 @@ fn sync_scene_metadata_from_structure(
                 project_id = excluded.project_id,
                 scene_file = excluded.scene_file,
                 title = excluded.title,
-                order_index = excluded.order_index,
-                updated_at = excluded.updated_at
+                order_index = excluded.order_index
             "#,
             params![
                 scene_id,
@@ -451,7 +777,10 @@ fn replace_structure(
         }
 
         sync_scene_metadata_from_structure(conn, project_id, structure)
-    })
+    })?;
+
+    structure_cache().invalidate_project(project_id);
+    Ok(())
 }
 
 fn normalize_series_index(series_index: &str) -> Result<String, String> {
@@ -462,6 +791,78 @@ fn normalize_series_index(series_index: &str) -> Result<String, String> {
     Ok(normalized)
 }
 
+/// Builds an empty structure node with a fresh id (and scene file for scenes)
+pub(crate) fn new_structure_node(node_type: &str, title: String) -> StructureNode {
+    let id = uuid::Uuid::new_v4().to_string();
+    StructureNode {
+        file: (node_type == "scene").then(|| format!("{id}.md")),
+        id,
+        node_type: node_type.to_string(),
+        title,
+        order: 0,
+        children: Vec::new(),
+    }
+}
+
+/// Plain-text scene body produced by one of the importers
+pub(crate) struct ImportedSceneContent {
+    pub file: String,
+    pub title: String,
+    pub paragraphs: Vec<String>,
+}
+
+/// Creates a new project from an importer's structure and scene bodies.
+pub(crate) fn create_project_from_import(
+    title: String,
+    series_id: String,
+    series_index: String,
+    structure: Vec<StructureNode>,
+    scenes: Vec<ImportedSceneContent>,
+) -> Result<ProjectMeta, String> {
+    let project = create_project(title, String::new(), String::new(), series_id, series_index)?;
+    let populated = save_structure(project.path.clone(), structure).and_then(|_| {
+        scenes.into_iter().try_for_each(|scene| {
+            let word_count = scene.paragraphs.iter().map(|p| count_words(p)).sum::<i32>();
+            crate::commands::scene::save_scene(
+                project.path.clone(),
+                scene.file,
+                paragraphs_to_doc(&scene.paragraphs),
+                Some(scene.title),
+                word_count,
+                None,
+            )
+            .map(|_| ())
+        })
+    });
+
+    if let Err(e) = populated {
+        discard_imported_project(&project);
+        return Err(e);
+    }
+    Ok(project)
+}
+
+/// Removes a half-built import so a failed import leaves no project behind
+fn discard_imported_project(project: &ProjectMeta) {
+    if let Err(e) = fs::remove_dir_all(&project.path) {
+        log::warn!(
+            "Failed to remove folder of failed import {}: {e}",
+            project.path
+        );
+    }
+    let removed = open_app_db().and_then(|conn| {
+        with_transaction(&conn, |conn| {
+            delete_project_rows(conn, &project.id, &project.path)
+        })
+    });
+    if let Err(e) = removed {
+        log::warn!(
+            "Failed to remove rows of failed import {}: {e}",
+            project.path
+        );
+    }
+}
+
 #[tauri::command]
 pub fn list_recent_projects() -> Result<Vec<RecentProject>, String> {
     let conn = open_app_db()?;
@@ -510,12 +911,155 @@ pub fn remove_from_recent(project_path: String) -> Result<(), String> {
     Ok(())
 }
 
+const PROJECT_SCAN_DEPTH: usize = 3;
+
+fn is_project_folder(dir: &Path) -> bool {
+    dir.join(".meta").is_dir() && dir.join("manuscript").is_dir()
+}
+
+/// Project folders under `dir`, looking a few levels down for projects created
+/// in custom sub-folders. Hidden folders and project folders' own contents are
+/// skipped.
+fn find_project_folders(dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden || !path.is_dir() {
+            continue;
+        }
+        if is_project_folder(&path) {
+            found.push(path);
+        } else if depth > 1 {
+            find_project_folders(&path, depth - 1, found);
+        }
+    }
+}
+
+/// Newest modification time of the project's `.meta` and `manuscript` folders
+fn project_folder_modified_at(dir: &Path) -> Option<i64> {
+    [".meta", "manuscript"]
+        .iter()
+        .filter_map(|name| dir.join(name).metadata().ok()?.modified().ok())
+        .filter_map(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|since_epoch| since_epoch.as_millis() as i64)
+        .max()
+}
+
+/// Registers a project folder the database has no row for, from the folder's
+/// `.meta/project.json`. A project whose registered folder no longer exists is
+/// pointed at this one (the folder was moved); a missing series is replaced by
+/// the recovery series, and a taken book number by the next free one.
+fn register_orphan_project(conn: &Connection, folder: &Path) -> Result<ProjectMeta, String> {
+    let mut project = read_project_manifest(folder)?;
+    project.path = folder.to_string_lossy().to_string();
+
+    let registered_path: Option<String> = conn
+        .query_row(
+            "SELECT path FROM projects WHERE id = ?1",
+            params![project.id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to look up project by id: {e}"))?;
+    if let Some(registered_path) = registered_path {
+        if Path::new(&registered_path).exists() {
+            return Err(format!(
+                "project {} is already registered at {registered_path}",
+                project.id
+            ));
+        }
+    }
+
+    project.series_id = resolve_series_for_restored_project(conn, &project.series_id)?;
+    if ensure_unique_series_index(
+        conn,
+        &project.series_id,
+        &project.series_index,
+        Some(&project.id),
+    )
+    .is_err()
+    {
+        project.series_index = next_series_index(conn, &project.series_id)?;
+    }
+
+    upsert_project(conn, &project)?;
+    Ok(project)
+}
+
+/// Recovery for a lost or corrupted recent list: replaces it with every
+/// project folder found under the projects directory, dated by the folder's
+/// last modification. Folders the database doesn't know are registered again
+/// from their `.meta/project.json`. Returns the number of projects found.
+#[tauri::command]
+pub fn rebuild_recent_projects() -> Result<usize, String> {
+    let mut folders = Vec::new();
+    find_project_folders(&get_projects_dir()?, PROJECT_SCAN_DEPTH, &mut folders);
+
+    let conn = open_app_db()?;
+    let mut recent = Vec::new();
+    for folder in folders {
+        let path = folder.to_string_lossy().to_string();
+        let project = match get_project_by_path(&conn, &path) {
+            Ok(project) => {
+                // Backfills the manifest for projects created before it existed
+                write_project_manifest(&project);
+                project
+            }
+            Err(_) => match register_orphan_project(&conn, &folder) {
+                Ok(project) => {
+                    log::info!("Registered project folder {path} again");
+                    project
+                }
+                Err(e) => {
+                    log::warn!("Skipping project folder {path}: {e}");
+                    continue;
+                }
+            },
+        };
+        let last_opened = project_folder_modified_at(&folder).unwrap_or(project.updated_at);
+        recent.push((project.path, project.title, last_opened));
+    }
+
+    with_transaction(&conn, |conn| {
+        conn.execute("DELETE FROM recent_projects", [])
+            .map_err(|e| format!("Failed to clear recent projects: {e}"))?;
+        for (path, title, last_opened) in &recent {
+            upsert_recent_entry(conn, path, title, *last_opened)?;
+        }
+        Ok(())
+    })?;
+    Ok(recent.len())
+}
+
+/// `ProjectMeta` plus a warning when another instance already has the
+/// project open. Serialises as the plain project with an extra field.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenedProject {
+    #[serde(flatten)]
+    pub project: ProjectMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_warning: Option<ProjectLockWarning>,
+}
+
 #[tauri::command]
-pub fn open_project(project_path: String) -> Result<ProjectMeta, String> {
+pub fn open_project(window: Window, project_path: String) -> Result<OpenedProject, String> {
     let conn = open_app_db()?;
     let project = get_project_by_path(&conn, &project_path)?;
     add_recent_entry(&conn, &project.path, &project.title)?;
-    Ok(project)
+    // A lock that can't be written (read-only media) shouldn't block opening.
+    let session_id = window_session_id(&window);
+    let lock_warning = acquire_project_lock(&project.path, &session_id).unwrap_or_else(|e| {
+        log::warn!("Failed to write project lock for {}: {e}", project.path);
+        None
+    });
+    Ok(OpenedProject {
+        project,
+        lock_warning,
+    })
 }
 
 #[tauri::command]
@@ -628,7 +1172,7 @@ pub fn create_project(
 }
 
 #[tauri::command]
-pub fn delete_project(project_path: String) -> Result<(), String> {
+pub fn delete_project(project_path: String) -> Result<ProjectDeletion, String> {
     let conn = open_app_db()?;
     let project = get_project_by_path(&conn, &project_path)?;
 
@@ -677,6 +1221,12 @@ pub fn delete_project(project_path: String) -> Result<(), String> {
         .map_err(|e| format!("Failed to remove project from recent list: {e}"))?;
 
         Ok(())
+    })?;
+
+    let series_empty = count_series_projects(&conn, &project.series_id)? == 0;
+    Ok(ProjectDeletion {
+        series_id: project.series_id,
+        series_empty,
     })
 }
 
@@ -815,69 +1365,90 @@ pub fn permanently_delete_trashed_project(trash_path: String) -> Result<(), Stri
             params![trash_path],
         )
         .map_err(|e| format!("Failed to delete deleted_projects row: {e}"))?;
-        conn.execute("DELETE FROM projects WHERE id = ?1", params![project_id])
-            .map_err(|e| format!("Failed to delete project row: {e}"))?;
-        conn.execute(
-            "DELETE FROM recent_projects WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete recent project row: {e}"))?;
-
-        conn.execute(
-            "DELETE FROM structure_nodes WHERE project_id = ?1",
-            params![project_id],
-        )
-        .map_err(|e| format!("Failed to delete structure rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM scene_metadata WHERE project_id = ?1",
-            params![project_id],
-        )
-        .map_err(|e| format!("Failed to delete scene metadata rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM snippets WHERE project_id = ?1",
-            params![project_id],
-        )
-        .map_err(|e| format!("Failed to delete snippet rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM scene_notes WHERE project_id = ?1",
-            params![project_id],
-        )
-        .map_err(|e| format!("Failed to delete scene note rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM chat_messages WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete chat message rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM chat_threads WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete chat thread rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM yjs_snapshots WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete yjs snapshot rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM yjs_update_log WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete yjs update rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM search_index WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete search index rows: {e}"))?;
-        conn.execute(
-            "DELETE FROM search_sync_state WHERE project_path = ?1",
-            params![original_path],
-        )
-        .map_err(|e| format!("Failed to delete search sync rows: {e}"))?;
+        delete_project_rows(conn, &project_id, &original_path)?;
 
         Ok(())
     })
 }
 
+/// Removes a project's row and every app-db row scoped to it
+fn delete_project_rows(
+    conn: &Connection,
+    project_id: &str,
+    project_path: &str,
+) -> Result<(), String> {
+    conn.execute("DELETE FROM projects WHERE id = ?1", params![project_id])
+        .map_err(|e| format!("Failed to delete project row: {e}"))?;
+    conn.execute(
+        "DELETE FROM recent_projects WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete recent project row: {e}"))?;
+
+    conn.execute(
+        "DELETE FROM structure_nodes WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete structure rows: {e}"))?;
+    structure_cache().invalidate_project(project_id);
+    conn.execute(
+        "DELETE FROM scene_metadata WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete scene metadata rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM snippets WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete snippet rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM scene_notes WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete scene note rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM writing_progress WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete writing progress rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM project_settings WHERE project_id = ?1",
+        params![project_id],
+    )
+    .map_err(|e| format!("Failed to delete project setting rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM chat_messages WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete chat message rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM chat_threads WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete chat thread rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM yjs_snapshots WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete yjs snapshot rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM yjs_update_log WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete yjs update rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM search_index WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete search index rows: {e}"))?;
+    conn.execute(
+        "DELETE FROM search_sync_state WHERE project_path = ?1",
+        params![project_path],
+    )
+    .map_err(|e| format!("Failed to delete search sync rows: {e}"))?;
+    Ok(())
+}
+
 #[tauri::command]
 pub fn update_project(
     project_path: String,
@@ -952,6 +1523,7 @@ pub fn update_project(
         .and_then(|v| v.as_str())
         .map(str::to_string);
 
+    let series_changed = next_series_id.is_some() || next_series_index.is_some();
     if let Some(series_id) = next_series_id {
         ensure_series_exists(&conn, &series_id)?;
         project.series_id = series_id;
@@ -961,12 +1533,16 @@ pub fn update_project(
         project.series_index = normalize_series_index(&series_index)?;
     }
 
-    ensure_unique_series_index(
-        &conn,
-        &project.series_id,
-        &project.series_index,
-        Some(&project.id),
-    )?;
+    // Only re-check when the placement changes, so legacy near-duplicates
+    // (e.g. "1" and "01") don't block unrelated edits like archiving.
+    if series_changed {
+        ensure_unique_series_index(
+            &conn,
+            &project.series_id,
+            &project.series_index,
+            Some(&project.id),
+        )?;
+    }
 
     project.updated_at = timestamp::now_millis();
     upsert_project(&conn, &project)?;
@@ -974,6 +1550,162 @@ pub fn update_project(
     Ok(project)
 }
 
+fn codex_name_key(category: &str, name: &str) -> (String, String) {
+    (category.to_string(), name.trim().to_lowercase())
+}
+
+/// Maps codex ids used by the project's scene links in the old series onto
+/// the new series: entries with the same category and name are reused, and
+/// with `copy_codex` the rest are copied over under fresh ids.
+fn map_linked_codex_entries(
+    conn: &Connection,
+    old_series_id: &str,
+    new_series_id: &str,
+    codex_ids: &[String],
+    copy_codex: bool,
+) -> Result<HashMap<String, String>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, category, name FROM codex_entries WHERE series_id = ?1")
+        .map_err(|e| format!("Failed to prepare codex name query: {e}"))?;
+    let rows = stmt
+        .query_map(params![new_series_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to query codex names: {e}"))?;
+    let mut by_name = HashMap::new();
+    for row in rows {
+        let (id, category, name) = row.map_err(|e| format!("Failed to decode codex row: {e}"))?;
+        by_name.insert(codex_name_key(&category, &name), id);
+    }
+
+    let mut mapping = HashMap::new();
+    for codex_id in codex_ids {
+        let Some(mut entry) = get_codex_entry(conn, old_series_id, codex_id)? else {
+            continue;
+        };
+        let key = codex_name_key(&entry.category, &entry.name);
+        if let Some(existing_id) = by_name.get(&key) {
+            mapping.insert(codex_id.clone(), existing_id.clone());
+        } else if copy_codex {
+            let now = timestamp::now_millis();
+            entry.id = uuid::Uuid::new_v4().to_string();
+            entry.created_at = now;
+            entry.updated_at = now;
+            // Absolute paths let the copy pull the images into the new series
+            resolve_entry_images(old_series_id, &mut entry);
+            upsert_codex_entry(conn, new_series_id, &entry)?;
+            by_name.insert(key, entry.id.clone());
+            mapping.insert(codex_id.clone(), entry.id);
+        }
+    }
+    Ok(mapping)
+}
+
+/// Moves a project into another series at `new_series_index`. Scene codex
+/// links follow the project: they are re-pointed at matching (or, with
+/// `copy_codex`, copied) entries of the new series, and dropped otherwise.
+#[tauri::command]
+pub fn move_project_to_series(
+    project_path: String,
+    new_series_id: String,
+    new_series_index: String,
+    copy_codex: Option<bool>,
+) -> Result<ProjectMeta, String> {
+    validate_no_null_bytes(&new_series_id, "Series id")?;
+    validate_no_null_bytes(&new_series_index, "Series index")?;
+    let conn = open_app_db()?;
+    let mut project = get_project_by_path(&conn, &project_path)?;
+    ensure_series_exists(&conn, &new_series_id)?;
+    let series_index = normalize_series_index(&new_series_index)?;
+    ensure_unique_series_index(&conn, &new_series_id, &series_index, Some(&project.id))?;
+    let old_series_id = project.series_id.clone();
+
+    with_transaction(&conn, |conn| {
+        if old_series_id != new_series_id {
+            let mut stmt = conn
+                .prepare(
+                    r#"
+                    SELECT payload_json FROM scene_codex_links
+                    WHERE series_id = ?1 AND scene_id IN (
+                        SELECT id FROM structure_nodes WHERE project_id = ?2
+                        UNION SELECT scene_id FROM scene_metadata WHERE project_id = ?2
+                    )
+                    "#,
+                )
+                .map_err(|e| format!("Failed to prepare scene codex link query: {e}"))?;
+            let rows = stmt
+                .query_map(params![old_series_id, project.id], |row| {
+                    row.get::<_, String>(0)
+                })
+                .map_err(|e| format!("Failed to query scene codex links: {e}"))?;
+            let mut links = Vec::new();
+            for row in rows {
+                let payload =
+                    row.map_err(|e| format!("Failed to decode scene codex link row: {e}"))?;
+                if let Ok(link) = serde_json::from_str::<SceneCodexLink>(&payload) {
+                    links.push(link);
+                }
+            }
+
+            let mut codex_ids: Vec<String> =
+                links.iter().map(|link| link.codex_id.clone()).collect();
+            codex_ids.sort();
+            codex_ids.dedup();
+            let mapping = map_linked_codex_entries(
+                conn,
+                &old_series_id,
+                &new_series_id,
+                &codex_ids,
+                copy_codex.unwrap_or(false),
+            )?;
+
+            for mut link in links {
+                match mapping.get(&link.codex_id) {
+                    Some(new_codex_id) => {
+                        link.codex_id = new_codex_id.clone();
+                        link.updated_at = timestamp::now_millis();
+                        let payload_json =
+                            serde_json::to_string(&link).map_err(|e| e.to_string())?;
+                        conn.execute(
+                            r#"
+                            UPDATE scene_codex_links
+                            SET series_id = ?1, codex_id = ?2, payload_json = ?3, updated_at = ?4
+                            WHERE id = ?5
+                            "#,
+                            params![
+                                new_series_id,
+                                link.codex_id,
+                                payload_json,
+                                link.updated_at,
+                                link.id
+                            ],
+                        )
+                        .map_err(|e| format!("Failed to move scene codex link: {e}"))?;
+                    }
+                    None => {
+                        conn.execute(
+                            "DELETE FROM scene_codex_links WHERE id = ?1",
+                            params![link.id],
+                        )
+                        .map_err(|e| format!("Failed to drop scene codex link: {e}"))?;
+                    }
+                }
+            }
+        }
+
+        project.series_id = new_series_id.clone();
+        project.series_index = series_index.clone();
+        project.updated_at = timestamp::now_millis();
+        upsert_project(conn, &project)
+    })?;
+
+    Ok(project)
+}
+
 #[tauri::command]
 pub fn archive_project(project_path: String) -> Result<ProjectMeta, String> {
     let updates = serde_json::json!({ "archived": true });
@@ -984,8 +1716,99 @@ pub fn archive_project(project_path: String) -> Result<ProjectMeta, String> {
 pub fn get_structure(project_path: String) -> Result<Vec<StructureNode>, String> {
     let conn = open_app_db()?;
     let project = get_project_by_path(&conn, &project_path)?;
-    let rows = fetch_structure_rows(&conn, &project.id)?;
-    Ok(build_structure_tree(rows))
+    let stamp = structure_stamp(&conn, &project.id)?;
+    let cache = structure_cache();
+    if let Some(nodes) = cache.get(&project_path, &project.id, stamp) {
+        return Ok(nodes);
+    }
+
+    let nodes = build_structure_tree(fetch_structure_rows(&conn, &project.id)?);
+    cache.insert(&project_path, &project.id, stamp, &nodes);
+    Ok(nodes)
+}
+
+/// Structure node annotated with totals over its descendant scenes. A scene
+/// carries its own word count and a scene count of 1.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StructureNodeWithCounts {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub node_type: String,
+    pub title: String,
+    pub order: i32,
+    #[serde(default)]
+    pub children: Vec<StructureNodeWithCounts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub word_count: i64,
+    pub scene_count: usize,
+}
+
+fn annotate_counts(
+    node: StructureNode,
+    scene_words: &HashMap<String, i64>,
+) -> StructureNodeWithCounts {
+    let children: Vec<StructureNodeWithCounts> = node
+        .children
+        .into_iter()
+        .map(|child| annotate_counts(child, scene_words))
+        .collect();
+    let (word_count, scene_count) = if node.node_type == "scene" {
+        let words = node
+            .file
+            .as_ref()
+            .and_then(|file| scene_words.get(file))
+            .copied()
+            .unwrap_or(0);
+        (words, 1)
+    } else {
+        children.iter().fold((0, 0), |(words, scenes), child| {
+            (words + child.word_count, scenes + child.scene_count)
+        })
+    };
+
+    StructureNodeWithCounts {
+        id: node.id,
+        node_type: node.node_type,
+        title: node.title,
+        order: node.order,
+        children,
+        file: node.file,
+        word_count,
+        scene_count,
+    }
+}
+
+/// `get_structure` with word and scene totals rolled up onto every node,
+/// using the stored scene word counts (one query, no scene file reads).
+#[tauri::command]
+pub fn get_structure_with_counts(
+    project_path: String,
+) -> Result<Vec<StructureNodeWithCounts>, String> {
+    let structure = get_structure(project_path.clone())?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+
+    let mut stmt = conn
+        .prepare("SELECT scene_file, word_count FROM scene_metadata WHERE project_id = ?1")
+        .map_err(|e| format!("Failed to prepare scene word count query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project.id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })
+        .map_err(|e| format!("Failed to query scene word counts: {e}"))?;
+    let mut scene_words = HashMap::new();
+    for row in rows {
+        let (scene_file, word_count) =
+            row.map_err(|e| format!("Failed to decode scene word count row: {e}"))?;
+        scene_words.insert(scene_file, word_count.max(0));
+    }
+
+    Ok(structure
+        .into_iter()
+        .map(|node| annotate_counts(node, &scene_words))
+        .collect())
 }
 
 #[tauri::command]
@@ -1024,20 +1847,167 @@ fn insert_node_in_tree(
     Ok(())
 }
 
+/// Whether new scene files are named `<order>-<slug>.md` instead of by UUID
+fn titled_scene_files_enabled(conn: &Connection, project_id: &str) -> bool {
+    project_setting_get(conn, project_id, PROJECT_SCENE_FILE_NAMING)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<String>(&value).ok())
+        .is_some_and(|mode| mode == SCENE_FILE_NAMING_TITLE)
+}
+
+/// `<reading position>-<slug of title>.md`, suffixed until it clashes with
+/// neither another scene in the structure nor a file in `manuscript/`
+fn titled_scene_file_name(
+    structure: &[StructureNode],
+    project_path: &str,
+    scene_id: &str,
+    title: &str,
+) -> String {
+    let mut scenes = Vec::new();
+    collect_scene_nodes(structure, &mut scenes);
+    let position = scenes
+        .iter()
+        .position(|(id, _, _)| id == scene_id)
+        .unwrap_or(scenes.len())
+        + 1;
+    let own_file = scenes
+        .iter()
+        .find(|(id, _, _)| id == scene_id)
+        .map(|(_, file, _)| file.as_str());
+    let taken: HashSet<&str> = scenes
+        .iter()
+        .filter(|(id, _, _)| id != scene_id)
+        .map(|(_, file, _)| file.as_str())
+        .collect();
+
+    let slug: String = slugify(title).chars().take(60).collect();
+    let slug = slug.trim_end_matches('-');
+    let base = if slug.is_empty() {
+        format!("{position:03}-scene")
+    } else {
+        format!("{position:03}-{slug}")
+    };
+    let manuscript_dir = PathBuf::from(project_path).join("manuscript");
+    let mut candidate = format!("{base}.md");
+    let mut suffix = 2;
+    while taken.contains(candidate.as_str())
+        || (own_file != Some(candidate.as_str()) && manuscript_dir.join(&candidate).exists())
+    {
+        candidate = format!("{base}-{suffix}.md");
+        suffix += 1;
+    }
+    candidate
+}
+
+fn set_scene_file_in_tree(nodes: &mut [StructureNode], node_id: &str, file: &str) -> bool {
+    for node in nodes.iter_mut() {
+        if node.id == node_id {
+            node.file = Some(file.to_string());
+            return true;
+        }
+        if set_scene_file_in_tree(&mut node.children, node_id, file) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Chooses how new scene files are named: "uuid" (default) or "title" for
+/// `<order>-<slug>.md`. Existing files are left alone; see `rename_scene_file`.
+#[tauri::command]
+pub fn set_scene_file_naming(project_path: String, mode: String) -> Result<(), String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    match mode.trim() {
+        SCENE_FILE_NAMING_UUID => {
+            project_setting_delete(&conn, &project.id, PROJECT_SCENE_FILE_NAMING)
+        }
+        SCENE_FILE_NAMING_TITLE => {
+            let value_json =
+                serde_json::to_string(SCENE_FILE_NAMING_TITLE).map_err(|e| e.to_string())?;
+            project_setting_set(
+                &conn,
+                &project.id,
+                PROJECT_SCENE_FILE_NAMING,
+                &value_json,
+                timestamp::now_millis(),
+            )
+        }
+        other => Err(format!("Unknown scene file naming mode: {other}")),
+    }
+}
+
+/// Renames a scene's file to `<order>-<slug of title>.md` from its current
+/// title and position, updating the structure and scene metadata to match.
+#[tauri::command]
+pub fn rename_scene_file(project_path: String, node_id: String) -> Result<StructureNode, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&node_id, "Node id")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let mut structure = get_structure(project_path.clone())?;
+    let node = find_node(&structure, &node_id)
+        .cloned()
+        .ok_or_else(|| "Node not found".to_string())?;
+    let Some(old_file) = node.file.clone().filter(|_| node.node_type == "scene") else {
+        return Err("Only scenes have files to rename".to_string());
+    };
+
+    let new_file = titled_scene_file_name(&structure, &project.path, &node_id, &node.title);
+    if new_file == old_file {
+        return Ok(node);
+    }
+    let manuscript_dir = PathBuf::from(&project.path).join("manuscript");
+    let old_path = manuscript_dir.join(&old_file);
+    let new_path = manuscript_dir.join(&new_file);
+    if old_path.exists() {
+        fs::rename(&old_path, &new_path)
+            .map_err(|e| format!("Failed to rename scene file: {e}"))?;
+    }
+
+    set_scene_file_in_tree(&mut structure, &node_id, &new_file);
+    if let Err(e) = replace_structure(&conn, &project.id, &structure) {
+        if new_path.exists() {
+            let _ = fs::rename(&new_path, &old_path);
+        }
+        return Err(e);
+    }
+    if let Err(e) = crate::commands::mention::invalidate_scene_mentions(&project.path, &old_file) {
+        log::warn!("Failed to invalidate mention cache for {old_file}: {e}");
+    }
+
+    Ok(StructureNode {
+        file: Some(new_file),
+        ..node
+    })
+}
+
 #[tauri::command]
 pub fn create_node(
     project_path: String,
     parent_id: Option<String>,
     node_type: String,
     title: String,
+    template_id: Option<String>,
 ) -> Result<StructureNode, String> {
     validate_node_type(&node_type)?;
     let conn = open_app_db()?;
     let project = get_project_by_path(&conn, &project_path)?;
+    let initial_content = match template_id.as_deref().filter(|id| !id.trim().is_empty()) {
+        Some(template_id) if node_type == "scene" => {
+            crate::commands::scene::get_scene_template(&conn, template_id)?
+                .ok_or_else(|| format!("Scene template not found: {template_id}"))?
+                .content
+        }
+        Some(_) => return Err("Templates can only be applied to scenes".to_string()),
+        None => String::new(),
+    };
 
     let mut structure = get_structure(project_path.clone())?;
     let id = uuid::Uuid::new_v4().to_string();
-    let new_node = StructureNode {
+    let mut new_node = StructureNode {
         id: id.clone(),
         node_type: node_type.clone(),
         title,
@@ -1051,6 +2021,11 @@ pub fn create_node(
     };
 
     insert_node_in_tree(&mut structure, parent_id.as_deref(), new_node.clone())?;
+    if new_node.file.is_some() && titled_scene_files_enabled(&conn, &project.id) {
+        let file = titled_scene_file_name(&structure, &project.path, &id, &new_node.title);
+        set_scene_file_in_tree(&mut structure, &id, &file);
+        new_node.file = Some(file);
+    }
     replace_structure(&conn, &project.id, &structure)?;
 
     if let Some(file) = &new_node.file {
@@ -1059,7 +2034,8 @@ pub fn create_node(
             fs::create_dir_all(parent).map_err(|e| e.to_string())?;
         }
         if !file_path.exists() {
-            atomic_write(&file_path, "").map_err(|e| format!("Failed to create scene file: {e}"))?;
+            atomic_write(&file_path, &initial_content)
+                .map_err(|e| format!("Failed to create scene file: {e}"))?;
         }
     }
 
@@ -1092,6 +2068,203 @@ pub fn rename_node(project_path: String, node_id: String, new_title: String) ->
     replace_structure(&conn, &project.id, &structure)
 }
 
+/// One step of a batched structure edit (e.g. a multi-step drag and drop)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StructureOperation {
+    /// Moves a node under `parent_id` (root when `None`) at `index`,
+    /// clamped to the end of the sibling list
+    Move {
+        node_id: String,
+        parent_id: Option<String>,
+        index: usize,
+    },
+    Rename {
+        node_id: String,
+        title: String,
+    },
+}
+
+pub(crate) fn find_node<'a>(
+    nodes: &'a [StructureNode],
+    node_id: &str,
+) -> Option<&'a StructureNode> {
+    nodes.iter().find_map(|node| {
+        if node.id == node_id {
+            Some(node)
+        } else {
+            find_node(&node.children, node_id)
+        }
+    })
+}
+
+/// Detaches a node (with its subtree) without touching scene files
+fn take_node_from_tree(nodes: &mut Vec<StructureNode>, node_id: &str) -> Option<StructureNode> {
+    if let Some(index) = nodes.iter().position(|node| node.id == node_id) {
+        return Some(nodes.remove(index));
+    }
+    nodes
+        .iter_mut()
+        .find_map(|node| take_node_from_tree(&mut node.children, node_id))
+}
+
+fn children_of<'a>(
+    nodes: &'a mut Vec<StructureNode>,
+    parent_id: &str,
+) -> Option<&'a mut Vec<StructureNode>> {
+    for node in nodes.iter_mut() {
+        if node.id == parent_id {
+            return Some(&mut node.children);
+        }
+        if let Some(children) = children_of(&mut node.children, parent_id) {
+            return Some(children);
+        }
+    }
+    None
+}
+
+fn renumber_tree(nodes: &mut [StructureNode]) {
+    for (index, node) in nodes.iter_mut().enumerate() {
+        node.order = index as i32;
+        renumber_tree(&mut node.children);
+    }
+}
+
+fn apply_structure_operation(
+    structure: &mut Vec<StructureNode>,
+    operation: &StructureOperation,
+) -> Result<(), String> {
+    match operation {
+        StructureOperation::Move {
+            node_id,
+            parent_id,
+            index,
+        } => {
+            let node = take_node_from_tree(structure, node_id)
+                .ok_or_else(|| format!("Node not found: {node_id}"))?;
+            if let Some(parent_id) = parent_id {
+                if parent_id == node_id || find_node(&node.children, parent_id).is_some() {
+                    return Err("Cannot move a node into itself".to_string());
+                }
+            }
+            let siblings = match parent_id {
+                Some(parent_id) => children_of(structure, parent_id)
+                    .ok_or_else(|| format!("Parent node not found: {parent_id}"))?,
+                None => structure,
+            };
+            let index = (*index).min(siblings.len());
+            siblings.insert(index, node);
+            Ok(())
+        }
+        StructureOperation::Rename { node_id, title } => {
+            if rename_node_in_tree(structure, node_id, title) {
+                Ok(())
+            } else {
+                Err(format!("Node not found: {node_id}"))
+            }
+        }
+    }
+}
+
+/// Applies a list of moves and renames as one change: either every operation
+/// succeeds and the structure is written once, or nothing is written.
+/// Sibling order is renumbered from position afterwards.
+#[tauri::command]
+pub fn apply_structure_operations(
+    project_path: String,
+    operations: Vec<StructureOperation>,
+) -> Result<Vec<StructureNode>, String> {
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    apply_structure_operations_for_project(&conn, &project, &operations)
+}
+
+pub(crate) fn apply_structure_operations_for_project(
+    conn: &Connection,
+    project: &ProjectMeta,
+    operations: &[StructureOperation],
+) -> Result<Vec<StructureNode>, String> {
+    let mut structure = get_structure(project.path.clone())?;
+    for operation in operations {
+        apply_structure_operation(&mut structure, operation)?;
+    }
+    renumber_tree(&mut structure);
+    replace_structure(conn, &project.id, &structure)?;
+    Ok(structure)
+}
+
+/// Parent of a node (`Some(None)` for a root node), `None` when not found
+pub(crate) fn find_parent_id(nodes: &[StructureNode], node_id: &str) -> Option<Option<String>> {
+    if nodes.iter().any(|node| node.id == node_id) {
+        return Some(None);
+    }
+    nodes.iter().find_map(|node| {
+        if node.children.iter().any(|child| child.id == node_id) {
+            Some(Some(node.id.clone()))
+        } else {
+            find_parent_id(&node.children, node_id)
+        }
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RenamedNode {
+    pub id: String,
+    pub old_title: String,
+    pub new_title: String,
+}
+
+fn renumber_chapters_in_tree(
+    nodes: &mut [StructureNode],
+    pattern: &str,
+    next_number: &mut i32,
+    renamed: &mut Vec<RenamedNode>,
+) {
+    for node in nodes.iter_mut() {
+        if node.node_type == "chapter" {
+            let new_title = pattern.replace("{n}", &next_number.to_string());
+            *next_number += 1;
+            if node.title != new_title {
+                renamed.push(RenamedNode {
+                    id: node.id.clone(),
+                    old_title: std::mem::replace(&mut node.title, new_title.clone()),
+                    new_title,
+                });
+            }
+        }
+        renumber_chapters_in_tree(&mut node.children, pattern, next_number, renamed);
+    }
+}
+
+/// Renames every chapter in reading order using `pattern`, where `{n}` is
+/// replaced by a running number starting at `start` (default 1).
+#[tauri::command]
+pub fn renumber_chapters(
+    project_path: String,
+    pattern: String,
+    start: Option<i32>,
+) -> Result<Vec<RenamedNode>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&pattern, "Pattern")?;
+    if !pattern.contains("{n}") {
+        return Err("Pattern must contain a {n} placeholder".to_string());
+    }
+
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let mut structure = get_structure(project_path)?;
+
+    let mut next_number = start.unwrap_or(1);
+    let mut renamed = Vec::new();
+    renumber_chapters_in_tree(&mut structure, &pattern, &mut next_number, &mut renamed);
+
+    if !renamed.is_empty() {
+        replace_structure(&conn, &project.id, &structure)?;
+    }
+    Ok(renamed)
+}
+
 fn remove_node_from_tree(
     nodes: &mut Vec<StructureNode>,
     node_id: &str,
@@ -1170,7 +2343,120 @@ pub fn delete_node(project_path: String, node_id: String) -> Result<(), String>
             params![scene_id],
         )
         .map_err(|e| format!("Failed to delete scene metadata row: {e}"))?;
+        conn.execute(
+            "DELETE FROM scene_notes WHERE project_id = ?1 AND scene_id = ?2",
+            params![project.id, scene_id],
+        )
+        .map_err(|e| format!("Failed to delete scene note row: {e}"))?;
     }
 
     Ok(())
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LinearScene {
+    pub scene_id: String,
+    pub file: String,
+    pub title: String,
+    pub act_title: Option<String>,
+    pub chapter_title: Option<String>,
+    pub order_index: i32,
+    pub word_count: i32,
+    /// Total words of all scenes that precede this one in reading order.
+    pub word_offset: i64,
+}
+
+fn load_scene_word_counts(
+    conn: &Connection,
+    project_id: &str,
+) -> Result<HashMap<String, i32>, String> {
+    let mut stmt = conn
+        .prepare("SELECT scene_id, word_count FROM scene_metadata WHERE project_id = ?1")
+        .map_err(|e| format!("Failed to prepare scene word count query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![project_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i32>(1)?))
+        })
+        .map_err(|e| format!("Failed to query scene word counts: {e}"))?;
+
+    let mut counts = HashMap::new();
+    for row in rows {
+        let (scene_id, word_count) =
+            row.map_err(|e| format!("Failed to decode scene word count row: {e}"))?;
+        counts.insert(scene_id, word_count);
+    }
+    Ok(counts)
+}
+
+fn collect_linear_scenes(
+    nodes: &[StructureNode],
+    act_title: Option<&str>,
+    chapter_title: Option<&str>,
+    word_counts: &HashMap<String, i32>,
+    output: &mut Vec<LinearScene>,
+) {
+    for node in nodes {
+        match node.node_type.as_str() {
+            "act" => {
+                collect_linear_scenes(&node.children, Some(&node.title), None, word_counts, output)
+            }
+            "chapter" => collect_linear_scenes(
+                &node.children,
+                act_title,
+                Some(&node.title),
+                word_counts,
+                output,
+            ),
+            "scene" => {
+                if let Some(file) = &node.file {
+                    let word_offset = output
+                        .last()
+                        .map(|prev| prev.word_offset + i64::from(prev.word_count.max(0)))
+                        .unwrap_or(0);
+                    let order_index = output.len() as i32;
+                    output.push(LinearScene {
+                        scene_id: node.id.clone(),
+                        file: file.clone(),
+                        title: node.title.clone(),
+                        act_title: act_title.map(ToString::to_string),
+                        chapter_title: chapter_title.map(ToString::to_string),
+                        order_index,
+                        word_count: word_counts.get(&node.id).copied().unwrap_or(0),
+                        word_offset,
+                    });
+                }
+                collect_linear_scenes(
+                    &node.children,
+                    act_title,
+                    chapter_title,
+                    word_counts,
+                    output,
+                );
+            }
+            _ => collect_linear_scenes(
+                &node.children,
+                act_title,
+                chapter_title,
+                word_counts,
+                output,
+            ),
+        }
+    }
+}
+
+/// Flattens the manuscript tree into reading order so callers can step
+/// between scenes without re-walking acts and chapters themselves.
+#[tauri::command]
+pub fn get_linear_scenes(project_path: String) -> Result<Vec<LinearScene>, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let structure = build_structure_tree(fetch_structure_rows(&conn, &project.id)?);
+    let word_counts = load_scene_word_counts(&conn, &project.id)?;
+
+    let mut scenes = Vec::new();
+    collect_linear_scenes(&structure, None, None, &word_counts, &mut scenes);
+    Ok(scenes)
+}
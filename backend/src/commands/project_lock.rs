@@ -0,0 +1,189 @@
+// Project lock file (`.meta/.lock`)
+// Opening a project records which session is editing it so a second window,
+// or a second machine sharing the folder through a sync service, can warn
+// before saves race. Each window gets its own session UUID; host and pid are
+// only kept for the warning text. Locks older than LOCK_STALE_AFTER_MS are
+// ignored. Paths locked by this instance are remembered so shutdown can
+// release them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{State, Window};
+
+use crate::commands::scene_autosave::SceneSaveQueue;
+use crate::utils::{atomic_write, now_millis, validate_no_null_bytes};
+
+const LOCK_FILE_NAME: &str = ".lock";
+const LOCK_STALE_AFTER_MS: i64 = 12 * 60 * 60 * 1000;
+
+/// Project paths this instance has written a lock for, with the session
+/// that holds each
+static HELD_LOCKS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+/// Lock session of each window label, created on first use
+static WINDOW_SESSIONS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLock {
+    /// Locks written before sessions existed have none and never match
+    #[serde(default)]
+    pub session_id: String,
+    pub host: String,
+    pub pid: u32,
+    pub acquired_at: i64,
+}
+
+/// Returned instead of taking the lock when another instance holds a fresh one
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectLockWarning {
+    pub lock: ProjectLock,
+    pub message: String,
+}
+
+fn lock_file_path(project_path: &str) -> PathBuf {
+    Path::new(project_path).join(".meta").join(LOCK_FILE_NAME)
+}
+
+fn host_name() -> String {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .chain(fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// The lock session of a window, stable for as long as the process runs
+pub(crate) fn window_session_id(window: &Window) -> String {
+    match WINDOW_SESSIONS.lock() {
+        Ok(mut sessions) => sessions
+            .entry(window.label().to_string())
+            .or_insert_with(|| uuid::Uuid::new_v4().to_string())
+            .clone(),
+        Err(_) => uuid::Uuid::new_v4().to_string(),
+    }
+}
+
+fn current_lock(session_id: &str) -> ProjectLock {
+    ProjectLock {
+        session_id: session_id.to_string(),
+        host: host_name(),
+        pid: std::process::id(),
+        acquired_at: now_millis(),
+    }
+}
+
+fn is_own_lock(lock: &ProjectLock, session_id: &str) -> bool {
+    !lock.session_id.is_empty() && lock.session_id == session_id
+}
+
+/// The lock on disk, if any. Unreadable lock files are treated as absent.
+fn read_lock(project_path: &str) -> Option<ProjectLock> {
+    let content = fs::read_to_string(lock_file_path(project_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_lock(project_path: &str, session_id: &str) -> Result<(), String> {
+    let payload = serde_json::to_string_pretty(&current_lock(session_id))
+        .map_err(|e| format!("Failed to serialize project lock: {e}"))?;
+    atomic_write(&lock_file_path(project_path), &payload)?;
+    if let Ok(mut held) = HELD_LOCKS.lock() {
+        held.insert(project_path.to_string(), session_id.to_string());
+    }
+    Ok(())
+}
+
+fn release_lock(project_path: &str, session_id: &str) -> Result<bool, String> {
+    if let Ok(mut held) = HELD_LOCKS.lock() {
+        if held
+            .get(project_path)
+            .is_some_and(|held_by| held_by == session_id)
+        {
+            held.remove(project_path);
+        }
+    }
+    match read_lock(project_path) {
+        Some(lock) if is_own_lock(&lock, session_id) => {
+            fs::remove_file(lock_file_path(project_path))
+                .map_err(|e| format!("Failed to release project lock: {e}"))?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Releases every lock this instance took. Returns how many were removed.
+pub(crate) fn release_held_project_locks() -> usize {
+    let held: Vec<(String, String)> = match HELD_LOCKS.lock() {
+        Ok(held) => held.clone().into_iter().collect(),
+        Err(_) => return 0,
+    };
+    held.iter()
+        .filter(
+            |(project_path, session_id)| match release_lock(project_path, session_id) {
+                Ok(released) => released,
+                Err(e) => {
+                    log::warn!("Failed to release lock for {project_path}: {e}");
+                    false
+                }
+            },
+        )
+        .count()
+}
+
+/// Takes the project lock unless another session holds a fresh one, in which
+/// case the existing lock is left alone and described in the warning.
+pub(crate) fn acquire_project_lock(
+    project_path: &str,
+    session_id: &str,
+) -> Result<Option<ProjectLockWarning>, String> {
+    if let Some(lock) = read_lock(project_path) {
+        let fresh = now_millis() - lock.acquired_at < LOCK_STALE_AFTER_MS;
+        if fresh && !is_own_lock(&lock, session_id) {
+            let message = if lock.pid == std::process::id() && lock.host == host_name() {
+                "This project is already open in another window. Editing it in two places can lose changes."
+                    .to_string()
+            } else {
+                format!(
+                    "This project is already open on {} (process {}). Editing it in two places can lose changes.",
+                    lock.host, lock.pid
+                )
+            };
+            return Ok(Some(ProjectLockWarning { lock, message }));
+        }
+    }
+    write_lock(project_path, session_id)?;
+    Ok(None)
+}
+
+/// Removes the lock if this window holds it, after writing any autosaves
+/// still queued for the project. Returns whether the lock was removed.
+#[tauri::command]
+pub fn release_project_lock(
+    window: Window,
+    queue: State<'_, SceneSaveQueue>,
+    project_path: String,
+) -> Result<bool, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    queue.flush(Some(&project_path));
+    release_lock(&project_path, &window_session_id(&window))
+}
+
+/// Takes the lock regardless of who holds it, for when the user confirms the
+/// other instance is gone (e.g. it crashed on another machine).
+#[tauri::command]
+pub fn override_project_lock(window: Window, project_path: String) -> Result<ProjectLock, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    if !Path::new(&project_path).is_dir() {
+        return Err(format!("Project folder not found: {project_path}"));
+    }
+    write_lock(&project_path, &window_session_id(&window))?;
+    read_lock(&project_path).ok_or_else(|| "Failed to read back project lock".to_string())
+}
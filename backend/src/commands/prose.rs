@@ -0,0 +1,548 @@
+// Prose analysis over scene text
+// Word frequencies skip stopwords: a built-in English list plus the project's
+// `.meta/stopwords.txt` (one word per line, `#` comments allowed), so authors
+// can also exclude their own common terms. Slower analyses are saved as the
+// latest `Analysis` of their type in `.meta/analyses/<type>.json`.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::project::{find_node, get_linear_scenes, get_structure, LinearScene};
+use crate::commands::scene::{read_scene_content, scene_file_path};
+use crate::commands::spellcheck::{normalize_apostrophes, tokenize_words};
+use crate::models::StructureNode;
+use crate::utils::{
+    atomic_write, count_words, extract_paragraphs, extract_plain_text, split_sentences, timestamp,
+    validate_file_size, validate_no_null_bytes, validate_project_path, MAX_DICTIONARY_SIZE,
+};
+
+const STOPWORDS_FILE: &str = "stopwords.txt";
+const DEFAULT_TOP_WORDS: usize = 100;
+const ANALYSES_DIR: &str = "analyses";
+const ANALYSIS_TYPES: [&str; 2] = ["echoes", "sentence-rhythm"];
+const DEFAULT_ECHO_MIN_LENGTH: usize = 3;
+const DEFAULT_ECHO_WINDOW: usize = 200;
+/// Scenes need this many sentences before they can be called flat
+const FLAT_MIN_SENTENCES: usize = 5;
+/// Standard deviation below this fraction of the mean sentence length is flat
+const FLAT_MAX_VARIATION: f64 = 0.3;
+
+/// Whitespace-separated, lowercase
+const BUILTIN_STOPWORDS: &str = "\
+    a about above after again against all am an and any are as at be because been before \
+    being below between both but by can could did do does doing don't down during each few \
+    for from further had has have having he he'd he's her here hers herself him himself his \
+    how i i'd i'm i've if in into is it it's its itself just me more most my myself no nor \
+    not now of off on once only or other our ours ourselves out over own same she she'd \
+    she's should so some such than that that's the their theirs them themselves then there \
+    these they this those through to too under until up very was we were what when where \
+    which while who whom why will with would you your yours yourself yourselves";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StopwordList {
+    pub builtin: Vec<String>,
+    /// Contents of the project's `.meta/stopwords.txt`
+    pub custom: Vec<String>,
+    /// Sorted union of both lists
+    pub effective: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WordFrequency {
+    pub word: String,
+    pub count: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ProseAnalysis {
+    pub total_words: usize,
+    /// Distinct words once stopwords are excluded
+    pub unique_words: usize,
+    pub excluded_stopwords: usize,
+    pub top_words: Vec<WordFrequency>,
+}
+
+/// A saved analysis run; `result` holds the command's own result type
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Analysis {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub analysis_type: String,
+    pub scope: Option<String>,
+    pub created_at: i64,
+    pub result: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EchoLocation {
+    pub scene_id: String,
+    pub scene_title: String,
+    /// Word offset of the phrase within the scene
+    pub word_index: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EchoOccurrence {
+    pub first: EchoLocation,
+    pub second: EchoLocation,
+    /// Words from the start of the first occurrence to the start of the second
+    pub distance: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Echo {
+    pub phrase: String,
+    pub occurrences: Vec<EchoOccurrence>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EchoReport {
+    pub analysis_id: String,
+    pub words_scanned: usize,
+    pub echoes: Vec<Echo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SentenceSample {
+    pub text: String,
+    pub words: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneRhythm {
+    pub scene_id: String,
+    pub scene_title: String,
+    pub sentence_count: usize,
+    /// Mean sentence length in words
+    pub average_length: f64,
+    pub standard_deviation: f64,
+    pub longest: Option<SentenceSample>,
+    pub shortest: Option<SentenceSample>,
+    /// Enough sentences, all close to the average length
+    pub flat: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SentenceRhythmReport {
+    pub analysis_id: String,
+    pub scenes: Vec<SceneRhythm>,
+    pub flat_scene_count: usize,
+}
+
+fn stopwords_path(project_path: &str) -> PathBuf {
+    Path::new(project_path).join(".meta").join(STOPWORDS_FILE)
+}
+
+fn load_custom_stopwords(project_path: &str) -> Result<Vec<String>, String> {
+    let path = stopwords_path(project_path);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let metadata = fs::metadata(&path).map_err(|e| format!("Failed to read stopword list: {e}"))?;
+    validate_file_size(metadata.len(), MAX_DICTIONARY_SIZE, "Stopword list")?;
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read stopword list: {e}"))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(normalize_apostrophes)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect())
+}
+
+fn stopword_list(project_path: &str) -> Result<StopwordList, String> {
+    let builtin: Vec<String> = BUILTIN_STOPWORDS
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    let custom = load_custom_stopwords(project_path)?;
+    let effective = builtin
+        .iter()
+        .chain(custom.iter())
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    Ok(StopwordList {
+        builtin,
+        custom,
+        effective,
+    })
+}
+
+/// Lowercased words prose analysis leaves out for this project
+pub(crate) fn effective_stopwords(project_path: &str) -> Result<HashSet<String>, String> {
+    Ok(stopword_list(project_path)?.effective.into_iter().collect())
+}
+
+/// Plain text of every scene in reading order
+pub(crate) fn scene_texts(project_path: &str) -> Result<Vec<(LinearScene, String)>, String> {
+    get_linear_scenes(project_path.to_string())?
+        .into_iter()
+        .map(|scene| {
+            let content = read_scene_content(&scene_file_path(project_path, &scene.file))?;
+            Ok((scene, extract_plain_text(&content)))
+        })
+        .collect()
+}
+
+fn collect_scene_ids(node: &StructureNode, ids: &mut HashSet<String>) {
+    if node.node_type == "scene" {
+        ids.insert(node.id.clone());
+    }
+    for child in &node.children {
+        collect_scene_ids(child, ids);
+    }
+}
+
+/// `scene_texts` limited to `scope`: None or "project" for the whole
+/// manuscript, otherwise the id of an act, chapter or scene.
+pub(crate) fn scene_texts_in_scope(
+    project_path: &str,
+    scope: Option<&str>,
+) -> Result<Vec<(LinearScene, String)>, String> {
+    let node_id = match scope.map(str::trim) {
+        None | Some("") | Some("project") => return scene_texts(project_path),
+        Some(node_id) => node_id,
+    };
+    let structure = get_structure(project_path.to_string())?;
+    let node = find_node(&structure, node_id)
+        .ok_or_else(|| format!("Scope not found in the project structure: {node_id}"))?;
+    let mut scene_ids = HashSet::new();
+    collect_scene_ids(node, &mut scene_ids);
+
+    let mut texts = scene_texts(project_path)?;
+    texts.retain(|(scene, _)| scene_ids.contains(&scene.scene_id));
+    Ok(texts)
+}
+
+fn validate_analysis_type(analysis_type: &str) -> Result<(), String> {
+    if ANALYSIS_TYPES.contains(&analysis_type) {
+        Ok(())
+    } else {
+        Err(format!("Unknown analysis type: {analysis_type}"))
+    }
+}
+
+fn analysis_path(project_path: &str, analysis_type: &str) -> PathBuf {
+    Path::new(project_path)
+        .join(".meta")
+        .join(ANALYSES_DIR)
+        .join(format!("{analysis_type}.json"))
+}
+
+/// Stores `result` as the latest analysis of its type, replacing the last run
+pub(crate) fn save_analysis<T: Serialize>(
+    project_path: &str,
+    analysis_type: &str,
+    scope: Option<String>,
+    id: String,
+    result: &T,
+) -> Result<Analysis, String> {
+    validate_analysis_type(analysis_type)?;
+    let analysis = Analysis {
+        id,
+        analysis_type: analysis_type.to_string(),
+        scope,
+        created_at: timestamp::now_millis(),
+        result: serde_json::to_value(result)
+            .map_err(|e| format!("Failed to serialize analysis: {e}"))?,
+    };
+    let path = analysis_path(project_path, analysis_type);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create analyses directory: {e}"))?;
+    }
+    let json = serde_json::to_string_pretty(&analysis)
+        .map_err(|e| format!("Failed to serialize analysis: {e}"))?;
+    atomic_write(&path, &json)?;
+    Ok(analysis)
+}
+
+/// The last saved analysis of a type, if one has been run
+#[tauri::command]
+pub fn get_saved_analysis(
+    project_path: String,
+    analysis_type: String,
+) -> Result<Option<Analysis>, String> {
+    validate_project_path(&project_path)?;
+    validate_analysis_type(&analysis_type)?;
+    let path = analysis_path(&project_path, &analysis_type);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read saved analysis: {e}"))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse saved analysis: {e}"))
+}
+
+#[tauri::command]
+pub fn get_stopwords(project_path: String) -> Result<StopwordList, String> {
+    validate_project_path(&project_path)?;
+    stopword_list(&project_path)
+}
+
+/// Replaces the project's custom stopwords and returns the effective list.
+#[tauri::command]
+pub fn set_custom_stopwords(
+    project_path: String,
+    words: Vec<String>,
+) -> Result<StopwordList, String> {
+    validate_project_path(&project_path)?;
+    let mut unique = BTreeSet::new();
+    for word in &words {
+        validate_no_null_bytes(word, "Stopword")?;
+        let word = word.trim();
+        if word.chars().any(char::is_whitespace) {
+            return Err(format!("Stopword cannot contain whitespace: {word}"));
+        }
+        if !word.is_empty() {
+            unique.insert(normalize_apostrophes(word));
+        }
+    }
+
+    let path = stopwords_path(&project_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .meta directory: {e}"))?;
+    }
+    let mut content = unique.into_iter().collect::<Vec<_>>().join("\n");
+    content.push('\n');
+    atomic_write(&path, &content)?;
+    stopword_list(&project_path)
+}
+
+/// Most frequent words across the manuscript, excluding stopwords.
+/// `limit` defaults to 100.
+#[tauri::command]
+pub fn analyze_prose(project_path: String, limit: Option<usize>) -> Result<ProseAnalysis, String> {
+    validate_project_path(&project_path)?;
+    let stopwords = effective_stopwords(&project_path)?;
+
+    let mut total_words = 0;
+    let mut excluded_stopwords = 0;
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (_, text) in scene_texts(&project_path)? {
+        for word in tokenize_words(&text) {
+            total_words += 1;
+            let word = normalize_apostrophes(word);
+            if stopwords.contains(&word) {
+                excluded_stopwords += 1;
+            } else {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let unique_words = counts.len();
+    let mut top_words: Vec<WordFrequency> = counts
+        .into_iter()
+        .map(|(word, count)| WordFrequency { word, count })
+        .collect();
+    top_words.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    top_words.truncate(limit.unwrap_or(DEFAULT_TOP_WORDS));
+
+    Ok(ProseAnalysis {
+        total_words,
+        unique_words,
+        excluded_stopwords,
+        top_words,
+    })
+}
+
+/// Repeated `min_length`-word phrases whose occurrences start within
+/// `window` words of each other. The text is read as one stream, so echoes
+/// can cross scene boundaries; phrases made only of stopwords are ignored,
+/// and a longer repeated run is reported once as the full phrase.
+#[tauri::command]
+pub fn find_echoes(
+    project_path: String,
+    scope: Option<String>,
+    min_length: Option<usize>,
+    window: Option<usize>,
+) -> Result<EchoReport, String> {
+    validate_project_path(&project_path)?;
+    let min_length = min_length.unwrap_or(DEFAULT_ECHO_MIN_LENGTH).max(2);
+    let window = window.unwrap_or(DEFAULT_ECHO_WINDOW).max(min_length);
+    let stopwords = effective_stopwords(&project_path)?;
+    let scenes = scene_texts_in_scope(&project_path, scope.as_deref())?;
+
+    // (normalized word, scene index, word index within scene)
+    let mut tokens: Vec<(String, usize, usize)> = Vec::new();
+    for (scene_index, (_, text)) in scenes.iter().enumerate() {
+        for (word_index, word) in tokenize_words(text).into_iter().enumerate() {
+            tokens.push((normalize_apostrophes(word), scene_index, word_index));
+        }
+    }
+
+    // Each run is (first start, second start, length in words)
+    let mut runs: Vec<(usize, usize, usize)> = Vec::new();
+    let mut last_seen: HashMap<String, usize> = HashMap::new();
+    for start in 0..tokens.len().saturating_sub(min_length - 1) {
+        let words = &tokens[start..start + min_length];
+        let key = words
+            .iter()
+            .map(|(word, _, _)| word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let Some(previous) = last_seen.insert(key, start) else {
+            continue;
+        };
+        let distance = start - previous;
+        if distance < min_length || distance > window {
+            continue;
+        }
+        match runs.last_mut() {
+            Some((first, second, length))
+                if *second + *length - min_length + 1 == start && *second - *first == distance =>
+            {
+                *length += 1;
+            }
+            _ if words.iter().all(|(word, _, _)| stopwords.contains(word)) => {}
+            _ => runs.push((previous, start, min_length)),
+        }
+    }
+
+    let location = |token: usize| {
+        let (_, scene_index, word_index) = tokens[token];
+        let scene = &scenes[scene_index].0;
+        EchoLocation {
+            scene_id: scene.scene_id.clone(),
+            scene_title: scene.title.clone(),
+            word_index,
+        }
+    };
+    let mut by_phrase: HashMap<String, Vec<EchoOccurrence>> = HashMap::new();
+    for (first, second, length) in runs {
+        let phrase = tokens[second..second + length]
+            .iter()
+            .map(|(word, _, _)| word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        by_phrase.entry(phrase).or_default().push(EchoOccurrence {
+            first: location(first),
+            second: location(second),
+            distance: second - first,
+        });
+    }
+    let mut echoes: Vec<Echo> = by_phrase
+        .into_iter()
+        .map(|(phrase, occurrences)| Echo {
+            phrase,
+            occurrences,
+        })
+        .collect();
+    echoes.sort_by(|a, b| {
+        b.occurrences
+            .len()
+            .cmp(&a.occurrences.len())
+            .then_with(|| a.phrase.cmp(&b.phrase))
+    });
+
+    let report = EchoReport {
+        analysis_id: uuid::Uuid::new_v4().to_string(),
+        words_scanned: tokens.len(),
+        echoes,
+    };
+    save_analysis(
+        &project_path,
+        "echoes",
+        scope,
+        report.analysis_id.clone(),
+        &report,
+    )?;
+    Ok(report)
+}
+
+fn scene_rhythm(scene: &LinearScene, text: &str) -> SceneRhythm {
+    let paragraphs = extract_paragraphs(text);
+    let sentences: Vec<(&str, usize)> = paragraphs
+        .iter()
+        .flat_map(|paragraph| {
+            split_sentences(paragraph)
+                .into_iter()
+                .map(|sentence| (sentence, count_words(sentence).max(0) as usize))
+                .collect::<Vec<_>>()
+        })
+        .filter(|(_, words)| *words > 0)
+        .collect();
+    let sample = |(text, words): &(&str, usize)| SentenceSample {
+        text: text.to_string(),
+        words: *words,
+    };
+
+    let count = sentences.len();
+    let (average_length, standard_deviation) = if count == 0 {
+        (0.0, 0.0)
+    } else {
+        let mean = sentences
+            .iter()
+            .map(|(_, words)| *words as f64)
+            .sum::<f64>()
+            / count as f64;
+        let variance = sentences
+            .iter()
+            .map(|(_, words)| (*words as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        (mean, variance.sqrt())
+    };
+
+    SceneRhythm {
+        scene_id: scene.scene_id.clone(),
+        scene_title: scene.title.clone(),
+        sentence_count: count,
+        average_length,
+        standard_deviation,
+        longest: sentences.iter().max_by_key(|(_, words)| *words).map(sample),
+        shortest: sentences.iter().min_by_key(|(_, words)| *words).map(sample),
+        flat: count >= FLAT_MIN_SENTENCES
+            && standard_deviation < average_length * FLAT_MAX_VARIATION,
+    }
+}
+
+/// Per-scene sentence length statistics. Scenes whose sentences barely vary
+/// in length are flagged as flat. Saved as the latest "sentence-rhythm"
+/// analysis.
+#[tauri::command]
+pub fn analyze_sentence_rhythm(
+    project_path: String,
+    scope: Option<String>,
+) -> Result<SentenceRhythmReport, String> {
+    validate_project_path(&project_path)?;
+    let scenes: Vec<SceneRhythm> = scene_texts_in_scope(&project_path, scope.as_deref())?
+        .iter()
+        .map(|(scene, text)| scene_rhythm(scene, text))
+        .collect();
+
+    let report = SentenceRhythmReport {
+        analysis_id: uuid::Uuid::new_v4().to_string(),
+        flat_scene_count: scenes.iter().filter(|scene| scene.flat).count(),
+        scenes,
+    };
+    save_analysis(
+        &project_path,
+        "sentence-rhythm",
+        scope,
+        report.analysis_id.clone(),
+        &report,
+    )?;
+    Ok(report)
+}
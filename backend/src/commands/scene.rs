@@ -1,51 +1,39 @@
 // Scene commands (manuscript text on filesystem, metadata in SQLite)
 
+use std::collections::HashSet;
 use std::fs;
-use std::path::{Component, Path, PathBuf};
+use std::path::{Path, PathBuf};
 
 use rusqlite::{params, OptionalExtension};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::models::{Scene, SceneMeta};
-use crate::storage::open_app_db;
+use crate::commands::project::{
+    apply_structure_operations_for_project, find_parent_id, get_project_by_path, get_structure,
+    StructureOperation,
+};
+use crate::models::{Scene, SceneMeta, SceneTemplate};
+use crate::storage::{app_pref_get, open_app_db, with_transaction};
 use crate::utils::{
-    atomic_write, count_words, timestamp, validate_file_size, validate_no_null_bytes,
-    MAX_SCENE_SIZE,
+    atomic_write, count_sentences, count_words, decode_text, extract_paragraphs,
+    extract_plain_text, normalize_typography, read_text_file, sanitize_path_component, timestamp,
+    validate_file_size, validate_no_null_bytes, validate_project_path, MAX_SCENE_SIZE,
 };
 
 fn validate_scene_file_name(scene_file: &str) -> Result<(), String> {
-    let trimmed = scene_file.trim();
-    if trimmed.is_empty() {
-        return Err("Scene file cannot be empty".to_string());
-    }
-
-    validate_no_null_bytes(trimmed, "Scene file")?;
-
-    let path = Path::new(trimmed);
-    if path.is_absolute() {
-        return Err("Scene file must be a relative filename".to_string());
-    }
-
-    let mut components = path.components();
-    let Some(first) = components.next() else {
-        return Err("Scene file cannot be empty".to_string());
-    };
-    if components.next().is_some() {
-        return Err("Scene file cannot contain path separators".to_string());
-    }
-
-    if !matches!(first, Component::Normal(_)) {
-        return Err("Scene file contains invalid path components".to_string());
-    }
+    let file_name =
+        sanitize_path_component(scene_file).map_err(|e| format!("Invalid scene file: {e}"))?;
 
-    if path.extension().and_then(|e| e.to_str()) != Some("md") {
+    if Path::new(&file_name).extension().and_then(|e| e.to_str()) != Some("md") {
         return Err("Scene file must end with .md".to_string());
     }
 
     Ok(())
 }
 
-fn project_id_for_path(conn: &rusqlite::Connection, project_path: &str) -> Result<String, String> {
+pub(crate) fn project_id_for_path(
+    conn: &rusqlite::Connection,
+    project_path: &str,
+) -> Result<String, String> {
     conn.query_row(
         "SELECT id FROM projects WHERE path = ?1",
         params![project_path],
@@ -54,6 +42,19 @@ fn project_id_for_path(conn: &rusqlite::Connection, project_path: &str) -> Resul
     .map_err(|e| format!("Failed to resolve project id for scene operation: {e}"))
 }
 
+/// App preference holding the per-project "normalize typography on save" flag
+fn typography_pref_key(project_id: &str) -> String {
+    format!("project.{project_id}.normalize_typography")
+}
+
+fn typography_normalization_enabled(conn: &rusqlite::Connection, project_id: &str) -> bool {
+    app_pref_get(conn, &typography_pref_key(project_id))
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str::<bool>(&value).ok())
+        .unwrap_or(false)
+}
+
 fn scene_meta_from_row(row: &rusqlite::Row<'_>) -> Result<SceneMeta, rusqlite::Error> {
     let labels_json: String = row.get(7)?;
     let labels = serde_json::from_str::<Vec<String>>(&labels_json).map_err(|e| {
@@ -223,21 +224,47 @@ fn resolve_word_count(content: &str, provided_word_count: i32) -> i32 {
     if provided_word_count >= 0 {
         provided_word_count
     } else {
-        count_words(content)
+        count_words(&extract_plain_text(content))
     }
 }
 
-fn scene_file_path(project_path: &str, scene_file: &str) -> PathBuf {
+/// "the-long-night.md" becomes "The long night"
+fn title_from_scene_file(scene_file: &str) -> Option<String> {
+    let stem = scene_file.trim_end_matches(".md").replace(['-', '_'], " ");
+    let stem = stem.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut chars = stem.chars();
+    let first = chars.next()?;
+    Some(first.to_uppercase().chain(chars).collect())
+}
+
+/// Metadata for a scene file that has no row yet, e.g. a plain `.md` file
+/// dropped into `manuscript`: the title comes from the file name, the
+/// timestamps from the file's mtime and the word count from its text.
+fn synthesize_scene_meta(scene_file: &str, path: &Path, content: &str, now: i64) -> SceneMeta {
+    let modified_at = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+        .map_or(now, |elapsed| elapsed.as_millis() as i64);
+    let mut meta = default_scene_meta(scene_file, modified_at);
+    if let Some(title) = title_from_scene_file(scene_file) {
+        meta.title = title;
+    }
+    meta.word_count = count_words(&extract_plain_text(content));
+    meta
+}
+
+pub(crate) fn scene_file_path(project_path: &str, scene_file: &str) -> PathBuf {
     PathBuf::from(project_path)
         .join("manuscript")
         .join(scene_file)
 }
 
-fn read_scene_content(path: &PathBuf) -> Result<String, String> {
+pub(crate) fn read_scene_content(path: &PathBuf) -> Result<String, String> {
     if path.exists() {
         let metadata = fs::metadata(path).map_err(|e| e.to_string())?;
         validate_file_size(metadata.len(), MAX_SCENE_SIZE, "Scene file")?;
-        fs::read_to_string(path).map_err(|e| e.to_string())
+        read_text_file(path)
     } else {
         Ok(String::new())
     }
@@ -245,7 +272,7 @@ fn read_scene_content(path: &PathBuf) -> Result<String, String> {
 
 #[tauri::command]
 pub fn load_scene(project_path: String, scene_file: String) -> Result<Scene, String> {
-    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_project_path(&project_path)?;
     validate_scene_file_name(&scene_file)?;
     let conn = open_app_db()?;
     let project_id = project_id_for_path(&conn, &project_path)?;
@@ -255,11 +282,33 @@ pub fn load_scene(project_path: String, scene_file: String) -> Result<Scene, Str
 
     let now = timestamp::now_millis();
     let meta = get_scene_meta_by_file(&conn, &project_id, &scene_file)?
-        .unwrap_or_else(|| default_scene_meta(&scene_file, now));
+        .unwrap_or_else(|| synthesize_scene_meta(&scene_file, &path, &content, now));
 
     Ok(Scene { meta, content })
 }
 
+/// Returned (as JSON after the `[E_CONFLICT]` tag) when a save was based on
+/// an older version than the one currently stored.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneConflict {
+    pub scene_file: String,
+    pub expected_updated_at: i64,
+    pub current_updated_at: i64,
+    pub current_content: String,
+    pub incoming_content: String,
+}
+
+/// `save_scene` result: the updated metadata plus how many words the save
+/// added (negative when words were removed) relative to the stored count.
+/// Snake case like the flattened `SceneMeta` fields.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SavedSceneMeta {
+    #[serde(flatten)]
+    pub meta: SceneMeta,
+    pub word_delta: i32,
+}
+
 #[tauri::command]
 pub fn save_scene(
     project_path: String,
@@ -267,8 +316,9 @@ pub fn save_scene(
     content: String,
     title: Option<String>,
     word_count: i32,
-) -> Result<SceneMeta, String> {
-    validate_no_null_bytes(&project_path, "Project path")?;
+    expected_updated_at: Option<i64>,
+) -> Result<SavedSceneMeta, String> {
+    validate_project_path(&project_path)?;
     validate_scene_file_name(&scene_file)?;
     if content.len() as u64 > MAX_SCENE_SIZE {
         return Err("[E_CONTENT_TOO_LARGE] Scene content exceeds maximum size of 10 MB".to_string());
@@ -277,8 +327,28 @@ pub fn save_scene(
     let project_id = project_id_for_path(&conn, &project_path)?;
 
     let now = timestamp::now_millis();
-    let mut meta = get_scene_meta_by_file(&conn, &project_id, &scene_file)?
-        .unwrap_or_else(|| default_scene_meta(&scene_file, now));
+    let existing = get_scene_meta_by_file(&conn, &project_id, &scene_file)?;
+    if let (Some(expected), Some(current)) = (expected_updated_at, existing.as_ref()) {
+        if current.updated_at > expected {
+            let path = scene_file_path(&project_path, &scene_file);
+            let conflict = SceneConflict {
+                scene_file: scene_file.clone(),
+                expected_updated_at: expected,
+                current_updated_at: current.updated_at,
+                current_content: read_scene_content(&path)?,
+                incoming_content: content,
+            };
+            let payload = serde_json::to_string(&conflict).map_err(|e| e.to_string())?;
+            return Err(format!("[E_CONFLICT] {payload}"));
+        }
+    }
+    let previous_word_count = existing.as_ref().map_or(0, |meta| meta.word_count.max(0));
+    let mut meta = existing.unwrap_or_else(|| default_scene_meta(&scene_file, now));
+    let content = if typography_normalization_enabled(&conn, &project_id) {
+        normalize_typography(&content)
+    } else {
+        content
+    };
 
     if let Some(title) = title {
         let normalized = title.trim().to_string();
@@ -297,7 +367,13 @@ pub fn save_scene(
     atomic_write(&path, &content)?;
 
     upsert_scene_meta(&conn, &project_id, &scene_file, &meta)?;
-    Ok(meta)
+    crate::commands::progress::record_project_progress(&conn, &project_id)?;
+    if let Err(e) = crate::commands::mention::invalidate_scene_mentions(&project_path, &scene_file)
+    {
+        log::warn!("Failed to invalidate mention cache for {scene_file}: {e}");
+    }
+    let word_delta = meta.word_count - previous_word_count;
+    Ok(SavedSceneMeta { meta, word_delta })
 }
 
 #[derive(Deserialize, Default)]
@@ -319,7 +395,7 @@ pub fn update_scene_metadata(
     scene_file: String,
     updates: SceneMetadataUpdates,
 ) -> Result<SceneMeta, String> {
-    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_project_path(&project_path)?;
     validate_scene_file_name(&scene_file)?;
     let conn = open_app_db()?;
     let project_id = project_id_for_path(&conn, &project_path)?;
@@ -376,16 +452,107 @@ pub fn update_scene_metadata(
         meta.archived = archived;
     }
 
-    meta.word_count = count_words(&content);
+    meta.word_count = count_words(&extract_plain_text(&content));
     meta.updated_at = now;
 
     upsert_scene_meta(&conn, &project_id, &scene_file, &meta)?;
     Ok(meta)
 }
 
+/// Sidebar edits that never need the scene body
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneMetaUpdates {
+    pub title: Option<String>,
+    pub status: Option<String>,
+    /// Blank clears the POV
+    pub pov_character: Option<String>,
+    /// Position among the scene's siblings in the binder
+    pub order: Option<i32>,
+}
+
+/// Changes title, status, POV or order of a scene by id without reading the
+/// scene body (unless the scene has no metadata yet and its word count has to
+/// be established). Title and order are binder properties, so they go through
+/// the structure operations like a rename or drag in the binder would. The
+/// scene's `updated_at` is left alone: it versions the body, and bumping it
+/// would make the open editor's next save fail with `E_CONFLICT`.
+#[tauri::command]
+pub fn update_scene_meta(
+    project_path: String,
+    scene_id: String,
+    updates: SceneMetaUpdates,
+) -> Result<SceneMeta, String> {
+    validate_project_path(&project_path)?;
+    validate_no_null_bytes(&scene_id, "Scene id")?;
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let project_id = project.id.clone();
+
+    let (mut meta, scene_file) = match get_scene_meta_by_id(&conn, &project_id, &scene_id)? {
+        Some(found) => found,
+        None => {
+            let scene_file = resolve_scene_file(&conn, &project_id, &scene_id)?;
+            validate_scene_file_name(&scene_file)?;
+            let content = read_scene_content(&scene_file_path(&project_path, &scene_file))?;
+            let mut meta = default_scene_meta(&scene_file, timestamp::now_millis());
+            meta.id = scene_id.clone();
+            meta.word_count = count_words(&extract_plain_text(&content));
+            (meta, scene_file)
+        }
+    };
+
+    if let Some(status) = updates.status {
+        let normalized = status.trim().to_string();
+        if !normalized.is_empty() {
+            meta.status = normalized;
+        }
+    }
+    if let Some(pov) = updates.pov_character {
+        let normalized = pov.trim().to_string();
+        meta.pov_character = if normalized.is_empty() {
+            None
+        } else {
+            Some(normalized)
+        };
+    }
+    upsert_scene_meta(&conn, &project_id, &scene_file, &meta)?;
+
+    let mut operations = Vec::new();
+    if let Some(title) = updates.title {
+        let normalized = title.trim().to_string();
+        if !normalized.is_empty() && normalized != meta.title {
+            operations.push(StructureOperation::Rename {
+                node_id: scene_id.clone(),
+                title: normalized,
+            });
+        }
+    }
+    if let Some(order) = updates.order {
+        if order != meta.order {
+            let structure = get_structure(project_path.clone())?;
+            let parent_id = find_parent_id(&structure, &scene_id)
+                .ok_or_else(|| format!("Scene not found in structure: {scene_id}"))?;
+            operations.push(StructureOperation::Move {
+                node_id: scene_id.clone(),
+                parent_id,
+                index: order.max(0) as usize,
+            });
+        }
+    }
+    if operations.is_empty() {
+        return Ok(meta);
+    }
+    apply_structure_operations_for_project(&conn, &project, &operations)?;
+
+    get_scene_meta_by_id(&conn, &project_id, &scene_id)?
+        .map(|(meta, _)| meta)
+        .ok_or_else(|| format!("Scene not found: {scene_id}"))
+}
+
 #[tauri::command]
 pub fn delete_scene(project_path: String, scene_file: String) -> Result<(), String> {
-    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_project_path(&project_path)?;
     validate_scene_file_name(&scene_file)?;
     let conn = open_app_db()?;
     let project_id = project_id_for_path(&conn, &project_path)?;
@@ -404,37 +571,443 @@ pub fn delete_scene(project_path: String, scene_file: String) -> Result<(), Stri
     Ok(())
 }
 
+pub(crate) fn resolve_scene_file(
+    conn: &rusqlite::Connection,
+    project_id: &str,
+    scene_id: &str,
+) -> Result<String, String> {
+    if let Some((_, scene_file)) = get_scene_meta_by_id(conn, project_id, scene_id)? {
+        return Ok(scene_file);
+    }
+    conn.query_row(
+        "SELECT scene_file FROM structure_nodes WHERE id = ?1 AND project_id = ?2",
+        params![scene_id, project_id],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .map_err(|e| format!("Failed to resolve scene file from structure nodes: {e}"))?
+    .ok_or_else(|| format!("Scene not found: {}", scene_id))
+}
+
 #[tauri::command]
 pub fn save_scene_by_id(
     project_path: String,
     scene_id: String,
     content: String,
     word_count: i32,
-) -> Result<SceneMeta, String> {
+) -> Result<SavedSceneMeta, String> {
     validate_no_null_bytes(&project_path, "Project path")?;
     let conn = open_app_db()?;
     let project_id = project_id_for_path(&conn, &project_path)?;
+    let resolved_file = resolve_scene_file(&conn, &project_id, &scene_id)?;
 
-    let resolved_file =
-        if let Some((_, scene_file)) = get_scene_meta_by_id(&conn, &project_id, &scene_id)? {
-            scene_file
-        } else {
-            conn.query_row(
-                "SELECT scene_file FROM structure_nodes WHERE id = ?1 AND project_id = ?2",
-                params![scene_id, project_id],
-                |row| row.get::<_, String>(0),
-            )
-            .optional()
-            .map_err(|e| format!("Failed to resolve scene file from structure nodes: {e}"))?
-            .ok_or_else(|| format!("Scene not found: {}", scene_id))?
+    save_scene(project_path, resolved_file, content, None, word_count, None)
+}
+
+/// One-off typography pass over a scene regardless of the project setting.
+/// Returns whether the scene changed.
+#[tauri::command]
+pub fn normalize_scene_typography(project_path: String, scene_id: String) -> Result<bool, String> {
+    validate_project_path(&project_path)?;
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+    let scene_file = resolve_scene_file(&conn, &project_id, &scene_id)?;
+    validate_scene_file_name(&scene_file)?;
+
+    let content = read_scene_content(&scene_file_path(&project_path, &scene_file))?;
+    let normalized = normalize_typography(&content);
+    if normalized == content {
+        return Ok(false);
+    }
+    // Typography changes never alter the word count.
+    let word_count =
+        get_scene_meta_by_file(&conn, &project_id, &scene_file)?.map_or(-1, |meta| meta.word_count);
+    save_scene(project_path, scene_file, normalized, None, word_count, None)?;
+    Ok(true)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneEncodingFailure {
+    pub scene_file: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneEncodingReport {
+    pub checked: usize,
+    /// Scene files rewritten as BOM-free UTF-8 with LF line endings
+    pub fixed: Vec<String>,
+    /// Files that couldn't be read or decoded, or are over the scene size
+    /// limit; these are left untouched
+    pub failed: Vec<SceneEncodingFailure>,
+}
+
+/// Rewrites scene files carrying a byte-order mark, UTF-16 encoding or CRLF
+/// line endings (typically imported from other tools) as plain UTF-8 with LF
+/// endings. Scenes are decoded this way on every read already; this makes
+/// the files themselves consistent.
+#[tauri::command]
+pub fn normalize_scene_encoding(project_path: String) -> Result<SceneEncodingReport, String> {
+    validate_project_path(&project_path)?;
+    let manuscript_dir = PathBuf::from(&project_path).join("manuscript");
+    let mut report = SceneEncodingReport::default();
+    if !manuscript_dir.is_dir() {
+        return Ok(report);
+    }
+
+    let entries = fs::read_dir(&manuscript_dir)
+        .map_err(|e| format!("Failed to read manuscript directory: {e}"))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read manuscript entry: {e}"))?;
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+        let scene_file = entry.file_name().to_string_lossy().to_string();
+        report.checked += 1;
+
+        let raw = match fs::metadata(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|metadata| validate_file_size(metadata.len(), MAX_SCENE_SIZE, "Scene file"))
+            .and_then(|_| fs::read(&path).map_err(|e| e.to_string()))
+        {
+            Ok(raw) => raw,
+            Err(e) => {
+                report.failed.push(SceneEncodingFailure {
+                    scene_file,
+                    error: e,
+                });
+                continue;
+            }
+        };
+        match decode_text(&raw) {
+            Ok(decoded) if decoded.as_bytes() != raw.as_slice() => {
+                atomic_write(&path, &decoded)?;
+                report.fixed.push(scene_file);
+            }
+            Ok(_) => {}
+            Err(error) => report
+                .failed
+                .push(SceneEncodingFailure { scene_file, error }),
+        }
+    }
+    report.fixed.sort();
+    Ok(report)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneWordCount {
+    pub scene_id: String,
+    pub word_count: i32,
+    /// False when the scene had no metadata row and its text was recounted
+    pub from_metadata: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneSelectionWordCount {
+    pub scenes: Vec<SceneWordCount>,
+    pub total_words: i64,
+}
+
+/// Word totals for an arbitrary selection of scenes (e.g. a binder
+/// multi-select). Stored counts are used where present; other scenes are
+/// recounted from their text. Duplicate ids are counted once.
+#[tauri::command]
+pub fn count_words_for_scenes(
+    project_path: String,
+    scene_ids: Vec<String>,
+) -> Result<SceneSelectionWordCount, String> {
+    validate_project_path(&project_path)?;
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+
+    let mut seen = HashSet::new();
+    let mut scenes = Vec::new();
+    for scene_id in scene_ids {
+        validate_no_null_bytes(&scene_id, "Scene id")?;
+        if !seen.insert(scene_id.clone()) {
+            continue;
+        }
+        let stored = get_scene_meta_by_id(&conn, &project_id, &scene_id)?
+            .map(|(meta, _)| meta.word_count)
+            .filter(|word_count| *word_count >= 0);
+        let (word_count, from_metadata) = match stored {
+            Some(word_count) => (word_count, true),
+            None => {
+                let scene_file = resolve_scene_file(&conn, &project_id, &scene_id)?;
+                validate_scene_file_name(&scene_file)?;
+                let content = read_scene_content(&scene_file_path(&project_path, &scene_file))?;
+                (count_words(&extract_plain_text(&content)), false)
+            }
         };
+        scenes.push(SceneWordCount {
+            scene_id,
+            word_count,
+            from_metadata,
+        });
+    }
 
-    save_scene(project_path, resolved_file, content, None, word_count)
+    let total_words = scenes.iter().map(|scene| i64::from(scene.word_count)).sum();
+    Ok(SceneSelectionWordCount {
+        scenes,
+        total_words,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WordCountDrift {
+    pub scene_id: String,
+    pub scene_file: String,
+    pub title: String,
+    pub stored_word_count: i32,
+    /// Equal to the stored count when the file couldn't be read
+    pub actual_word_count: i32,
+    /// Why the file couldn't be recounted; such scenes are never corrected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Scenes whose stored word count differs from a fresh count of the file,
+/// e.g. after the file was edited outside the app, plus scenes whose file
+/// couldn't be read. Missing files are skipped.
+fn find_word_count_drift(
+    conn: &rusqlite::Connection,
+    project_id: &str,
+    project_path: &str,
+) -> Result<Vec<WordCountDrift>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT scene_id, scene_file, title, word_count FROM scene_metadata \
+             WHERE project_id = ?1 ORDER BY order_index, scene_id",
+        )
+        .map_err(|e| format!("Failed to prepare word count audit: {e}"))?;
+    let rows = stmt
+        .query_map(params![project_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, i32>(3)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to scan scene word counts: {e}"))?;
+
+    let mut drift = Vec::new();
+    for row in rows {
+        let (scene_id, scene_file, title, stored_word_count) =
+            row.map_err(|e| format!("Failed to decode scene word count row: {e}"))?;
+        let path = scene_file_path(project_path, &scene_file);
+        if !path.is_file() {
+            continue;
+        }
+        let (actual_word_count, error) = match read_scene_content(&path) {
+            Ok(content) => (count_words(&extract_plain_text(&content)), None),
+            Err(e) => (stored_word_count, Some(e)),
+        };
+        if actual_word_count != stored_word_count || error.is_some() {
+            drift.push(WordCountDrift {
+                scene_id,
+                scene_file,
+                title,
+                stored_word_count,
+                actual_word_count,
+                error,
+            });
+        }
+    }
+    Ok(drift)
+}
+
+/// Lists scenes whose stored word count no longer matches their text, with
+/// both numbers, so stale stats can be spotted before they are trusted.
+#[tauri::command]
+pub fn audit_word_counts(project_path: String) -> Result<Vec<WordCountDrift>, String> {
+    validate_project_path(&project_path)?;
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+    find_word_count_drift(&conn, &project_id, &project_path)
+}
+
+/// Stores fresh word counts for drifted scenes (all of them, or only
+/// `scene_ids`) in one transaction and returns the scenes that were
+/// corrected. `updated_at` is left alone since the text itself didn't change.
+#[tauri::command]
+pub fn recount_word_counts(
+    project_path: String,
+    scene_ids: Option<Vec<String>>,
+) -> Result<Vec<WordCountDrift>, String> {
+    validate_project_path(&project_path)?;
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+    let only: Option<HashSet<String>> = scene_ids.map(|ids| ids.into_iter().collect());
+
+    let drift: Vec<WordCountDrift> = find_word_count_drift(&conn, &project_id, &project_path)?
+        .into_iter()
+        .filter(|scene| {
+            scene.error.is_none()
+                && only
+                    .as_ref()
+                    .map_or(true, |ids| ids.contains(&scene.scene_id))
+        })
+        .collect();
+    with_transaction(&conn, |conn| {
+        for scene in &drift {
+            conn.execute(
+                "UPDATE scene_metadata SET word_count = ?1 WHERE project_id = ?2 AND scene_id = ?3",
+                params![scene.actual_word_count, project_id, scene.scene_id],
+            )
+            .map_err(|e| format!("Failed to update word count for {}: {e}", scene.title))?;
+        }
+        Ok(())
+    })?;
+    Ok(drift)
+}
+
+/// Reading speed behind `TextStats::reading_minutes`
+const READING_WORDS_PER_MINUTE: usize = 250;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStats {
+    pub words: i32,
+    pub characters: usize,
+    pub characters_no_spaces: usize,
+    pub sentences: usize,
+    pub paragraphs: usize,
+    pub reading_minutes: usize,
+}
+
+/// Word count of editor text (plain or the editor's JSON document), using
+/// the same counting as a scene save.
+#[tauri::command]
+pub fn count_text_words(text: String) -> Result<i32, String> {
+    validate_file_size(text.len() as u64, MAX_SCENE_SIZE, "Text")?;
+    Ok(count_words(&extract_plain_text(&text)))
+}
+
+/// Live statistics for editor text. Characters are counted within
+/// paragraphs, so paragraph breaks are not included.
+#[tauri::command]
+pub fn count_text_stats(text: String) -> Result<TextStats, String> {
+    validate_file_size(text.len() as u64, MAX_SCENE_SIZE, "Text")?;
+    let paragraphs = extract_paragraphs(&text);
+    let plain = paragraphs.join("\n\n");
+    let words = count_words(&plain);
+
+    Ok(TextStats {
+        words,
+        characters: paragraphs.iter().map(|p| p.chars().count()).sum(),
+        characters_no_spaces: plain.chars().filter(|c| !c.is_whitespace()).count(),
+        sentences: paragraphs.iter().map(|p| count_sentences(p)).sum(),
+        paragraphs: paragraphs.iter().filter(|p| !p.trim().is_empty()).count(),
+        reading_minutes: (words.max(0) as usize).div_ceil(READING_WORDS_PER_MINUTE),
+    })
+}
+
+fn scene_template_from_row(row: &rusqlite::Row<'_>) -> Result<SceneTemplate, rusqlite::Error> {
+    Ok(SceneTemplate {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        content: row.get(2)?,
+        created_at: row.get(3)?,
+        updated_at: row.get(4)?,
+    })
+}
+
+pub(crate) fn get_scene_template(
+    conn: &rusqlite::Connection,
+    template_id: &str,
+) -> Result<Option<SceneTemplate>, String> {
+    conn.query_row(
+        "SELECT id, name, content, created_at, updated_at FROM scene_templates WHERE id = ?1",
+        params![template_id],
+        scene_template_from_row,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to read scene template: {e}"))
+}
+
+#[tauri::command]
+pub fn list_scene_templates() -> Result<Vec<SceneTemplate>, String> {
+    let conn = open_app_db()?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, name, content, created_at, updated_at FROM scene_templates ORDER BY name COLLATE NOCASE ASC",
+        )
+        .map_err(|e| format!("Failed to prepare scene template query: {e}"))?;
+    let rows = stmt
+        .query_map([], scene_template_from_row)
+        .map_err(|e| format!("Failed to execute scene template query: {e}"))?;
+
+    let mut templates = Vec::new();
+    for row in rows {
+        templates.push(row.map_err(|e| format!("Failed to decode scene template row: {e}"))?);
+    }
+    Ok(templates)
+}
+
+#[tauri::command]
+pub fn save_scene_template(mut template: SceneTemplate) -> Result<SceneTemplate, String> {
+    validate_no_null_bytes(&template.id, "Template id")?;
+    template.name = template.name.trim().to_string();
+    if template.id.trim().is_empty() {
+        return Err("Template id cannot be empty".to_string());
+    }
+    if template.name.is_empty() {
+        return Err("Template name cannot be empty".to_string());
+    }
+    if template.content.len() as u64 > MAX_SCENE_SIZE {
+        return Err(
+            "[E_CONTENT_TOO_LARGE] Template content exceeds maximum size of 10 MB".to_string(),
+        );
+    }
+
+    let conn = open_app_db()?;
+    let now = timestamp::now_millis();
+    template.created_at =
+        get_scene_template(&conn, &template.id)?.map_or(now, |existing| existing.created_at);
+    template.updated_at = now;
+    conn.execute(
+        r#"
+        INSERT INTO scene_templates(id, name, content, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT(id) DO UPDATE SET
+            name = excluded.name,
+            content = excluded.content,
+            updated_at = excluded.updated_at
+        "#,
+        params![
+            template.id,
+            template.name,
+            template.content,
+            template.created_at,
+            template.updated_at,
+        ],
+    )
+    .map_err(|e| format!("Failed to save scene template: {e}"))?;
+
+    Ok(template)
+}
+
+#[tauri::command]
+pub fn delete_scene_template(template_id: String) -> Result<(), String> {
+    let conn = open_app_db()?;
+    conn.execute(
+        "DELETE FROM scene_templates WHERE id = ?1",
+        params![template_id],
+    )
+    .map_err(|e| format!("Failed to delete scene template: {e}"))?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::resolve_word_count;
+    use super::{resolve_word_count, title_from_scene_file};
 
     #[test]
     fn preserves_zero_word_count_when_provided() {
@@ -447,4 +1020,13 @@ mod tests {
         let content = "one two three";
         assert_eq!(resolve_word_count(content, -1), 3);
     }
+
+    #[test]
+    fn titles_external_scene_files_from_their_name() {
+        assert_eq!(
+            title_from_scene_file("the-long_night.md").as_deref(),
+            Some("The long night")
+        );
+        assert_eq!(title_from_scene_file("--.md"), None);
+    }
 }
@@ -0,0 +1,222 @@
+// Coalesced autosave
+// The editor autosaves every few keystrokes. `save_scene_debounced` keeps only
+// the newest content per scene and writes it once the scene has been quiet for
+// the debounce window; an emergency backup is written on every call so a crash
+// inside the window loses nothing. Pending saves are flushed when the project
+// lock is released and when the app exits.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::backup_emergency::save_emergency_backup_with_ttl;
+use crate::commands::scene::save_scene_by_id;
+use crate::models::EmergencyBackup;
+use crate::utils::{now_millis, validate_no_null_bytes};
+
+const MAX_DEBOUNCE_MS: u64 = 10_000;
+
+/// (project path, scene id)
+type SaveKey = (String, String);
+
+struct PendingSave {
+    content: String,
+    word_count: i32,
+    due_at: Instant,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DebouncedSave {
+    pub scene_id: String,
+    /// When the coalesced write is due (epoch millis)
+    pub write_due_at: i64,
+}
+
+/// Newest unsaved content per scene. `writing` is held for every disk write so
+/// a flush also waits for writes a worker has already started.
+#[derive(Default)]
+pub struct SceneSaveQueue {
+    pending: Arc<Mutex<HashMap<SaveKey, PendingSave>>>,
+    writing: Arc<Mutex<()>>,
+}
+
+fn write_pending(key: SaveKey, save: PendingSave) -> bool {
+    let (project_path, scene_id) = key;
+    match save_scene_by_id(
+        project_path,
+        scene_id.clone(),
+        save.content,
+        save.word_count,
+    ) {
+        Ok(_) => true,
+        Err(e) => {
+            log::error!("Coalesced save of scene {scene_id} failed: {e}");
+            false
+        }
+    }
+}
+
+/// Sleeps until the scene's save is due (pushed back by newer saves), then
+/// writes it. Exits once the entry is gone, e.g. after a flush.
+fn run_save_worker(
+    pending: Arc<Mutex<HashMap<SaveKey, PendingSave>>>,
+    writing: Arc<Mutex<()>>,
+    key: SaveKey,
+) {
+    loop {
+        let wait = {
+            let Ok(pending) = pending.lock() else {
+                return;
+            };
+            match pending.get(&key) {
+                Some(save) => save.due_at.saturating_duration_since(Instant::now()),
+                None => return,
+            }
+        };
+        if !wait.is_zero() {
+            thread::sleep(wait);
+            continue;
+        }
+
+        let Ok(_gate) = writing.lock() else {
+            return;
+        };
+        let save = {
+            let Ok(mut pending) = pending.lock() else {
+                return;
+            };
+            match pending.get(&key) {
+                Some(save) if save.due_at <= Instant::now() => pending.remove(&key),
+                Some(_) => continue,
+                None => return,
+            }
+        };
+        if let Some(save) = save {
+            write_pending(key, save);
+        }
+        return;
+    }
+}
+
+impl SceneSaveQueue {
+    fn enqueue(&self, key: SaveKey, content: String, word_count: i32, debounce: Duration) {
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+        let save = PendingSave {
+            content,
+            word_count,
+            due_at: Instant::now() + debounce,
+        };
+        if pending.insert(key.clone(), save).is_none() {
+            let pending = self.pending.clone();
+            let writing = self.writing.clone();
+            thread::spawn(move || run_save_worker(pending, writing, key));
+        }
+    }
+
+    /// Writes pending saves now, for one project or all of them. Returns the
+    /// number of scenes written.
+    pub fn flush(&self, project_path: Option<&str>) -> usize {
+        let Ok(_gate) = self.writing.lock() else {
+            return 0;
+        };
+        let saves: Vec<(SaveKey, PendingSave)> = {
+            let Ok(mut pending) = self.pending.lock() else {
+                return 0;
+            };
+            let keys: Vec<SaveKey> = pending
+                .keys()
+                .filter(|(path, _)| project_path.map_or(true, |project| project == path))
+                .cloned()
+                .collect();
+            keys.into_iter()
+                .filter_map(|key| pending.remove(&key).map(|save| (key, save)))
+                .collect()
+        };
+        saves
+            .into_iter()
+            .map(|(key, save)| write_pending(key, save))
+            .filter(|written| *written)
+            .count()
+    }
+
+    /// Writes the content now, replacing anything queued for the scene
+    fn write_now(&self, key: SaveKey, content: String, word_count: i32) -> Result<(), String> {
+        let _gate = self
+            .writing
+            .lock()
+            .map_err(|_| "Scene save queue is unavailable".to_string())?;
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.remove(&key);
+        }
+        let (project_path, scene_id) = key;
+        save_scene_by_id(project_path, scene_id, content, word_count).map(|_| ())
+    }
+}
+
+/// Queues the scene content for a single write after `debounce_ms` without
+/// further saves to the same scene (capped at 10s; 0 writes immediately). An
+/// emergency backup of the content is written right away.
+#[tauri::command]
+pub fn save_scene_debounced(
+    queue: State<'_, SceneSaveQueue>,
+    project_path: String,
+    scene_id: String,
+    content: String,
+    word_count: i32,
+    debounce_ms: u64,
+) -> Result<DebouncedSave, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+    validate_no_null_bytes(&scene_id, "Scene id")?;
+    if scene_id.trim().is_empty() {
+        return Err("Scene id is required".to_string());
+    }
+
+    let now = now_millis();
+    save_emergency_backup_with_ttl(
+        EmergencyBackup {
+            id: uuid::Uuid::new_v4().to_string(),
+            scene_id: scene_id.clone(),
+            content: content.clone(),
+            timestamp: now,
+            expires_at: now,
+            content_hash: None,
+        },
+        None,
+    )?;
+
+    let debounce_ms = debounce_ms.min(MAX_DEBOUNCE_MS);
+    if debounce_ms == 0 {
+        queue.write_now((project_path, scene_id.clone()), content, word_count)?;
+    } else {
+        queue.enqueue(
+            (project_path, scene_id.clone()),
+            content,
+            word_count,
+            Duration::from_millis(debounce_ms),
+        );
+    }
+
+    Ok(DebouncedSave {
+        scene_id,
+        write_due_at: now + debounce_ms as i64,
+    })
+}
+
+/// Writes any queued saves immediately. Returns the number of scenes written.
+#[tauri::command]
+pub fn flush_scene_saves(
+    queue: State<'_, SceneSaveQueue>,
+    project_path: Option<String>,
+) -> Result<usize, String> {
+    if let Some(project_path) = &project_path {
+        validate_no_null_bytes(project_path, "Project path")?;
+    }
+    Ok(queue.flush(project_path.as_deref()))
+}
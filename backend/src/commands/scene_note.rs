@@ -1,6 +1,9 @@
 // Scene Note commands (SQLite-backed)
 
+use std::collections::BTreeMap;
+
 use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
 
 use crate::models::SceneNote;
 use crate::storage::open_app_db;
@@ -14,6 +17,35 @@ fn project_id_for_path(conn: &rusqlite::Connection, project_path: &str) -> Resul
     .map_err(|e| format!("Failed to resolve project id for scene note operation: {e}"))
 }
 
+fn row_to_scene_note(row: &rusqlite::Row<'_>) -> Result<SceneNote, rusqlite::Error> {
+    let content_json: String = row.get(3)?;
+    let content = serde_json::from_str(&content_json).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(
+            content_json.len(),
+            rusqlite::types::Type::Text,
+            Box::new(e),
+        )
+    })?;
+
+    Ok(SceneNote {
+        id: row.get(0)?,
+        scene_id: row.get(1)?,
+        project_id: row.get(2)?,
+        content,
+        created_at: row.get(4)?,
+        updated_at: row.get(5)?,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SceneNoteOverview {
+    #[serde(flatten)]
+    pub note: SceneNote,
+    /// None when the note's scene is no longer in the structure
+    pub scene_title: Option<String>,
+}
+
 #[tauri::command]
 pub fn get_scene_note(project_path: String, scene_id: String) -> Result<Option<SceneNote>, String> {
     let conn = open_app_db()?;
@@ -26,25 +58,7 @@ pub fn get_scene_note(project_path: String, scene_id: String) -> Result<Option<S
         WHERE project_id = ?1 AND scene_id = ?2
         "#,
         params![project_id, scene_id],
-        |row| {
-            let content_json: String = row.get(3)?;
-            let content = serde_json::from_str(&content_json).map_err(|e| {
-                rusqlite::Error::FromSqlConversionFailure(
-                    content_json.len(),
-                    rusqlite::types::Type::Text,
-                    Box::new(e),
-                )
-            })?;
-
-            Ok(SceneNote {
-                id: row.get(0)?,
-                scene_id: row.get(1)?,
-                project_id: row.get(2)?,
-                content,
-                created_at: row.get(4)?,
-                updated_at: row.get(5)?,
-            })
-        },
+        row_to_scene_note,
     )
     .optional()
     .map_err(|e| format!("Failed to fetch scene note: {e}"))
@@ -103,3 +117,40 @@ pub fn delete_scene_note(project_path: String, scene_id: String) -> Result<(), S
 
     Ok(())
 }
+
+/// All notes in the project keyed by scene id, for the notes overview panel.
+#[tauri::command]
+pub fn list_scene_notes(
+    project_path: String,
+) -> Result<BTreeMap<String, SceneNoteOverview>, String> {
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT n.id, n.scene_id, n.project_id, n.content_json, n.created_at, n.updated_at,
+                   s.title
+            FROM scene_notes n
+            LEFT JOIN structure_nodes s
+                ON s.id = n.scene_id AND s.project_id = n.project_id
+            WHERE n.project_id = ?1
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare scene note list query: {e}"))?;
+    let rows = stmt
+        .query_map(params![project_id], |row| {
+            Ok(SceneNoteOverview {
+                note: row_to_scene_note(row)?,
+                scene_title: row.get(6)?,
+            })
+        })
+        .map_err(|e| format!("Failed to list scene notes: {e}"))?;
+
+    let mut notes = BTreeMap::new();
+    for row in rows {
+        let overview = row.map_err(|e| format!("Failed to decode scene note row: {e}"))?;
+        notes.insert(overview.note.scene_id.clone(), overview);
+    }
+    Ok(notes)
+}
@@ -0,0 +1,693 @@
+// Scrivener interop (.scriv packages: .scrivx binder XML + RTF documents)
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::commands::project::{
+    create_project_from_import, get_project_by_path, new_structure_node, ImportedSceneContent,
+};
+use crate::commands::scene::read_scene_content;
+use crate::models::{ProjectMeta, StructureNode};
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, escape_xml, extract_paragraphs, parse_xml, resolve_export_path,
+    validate_file_size, validate_no_null_bytes, XmlElement, MAX_SCRIVENER_DOCUMENT_SIZE,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrivenerExportResult {
+    pub package_path: String,
+    pub folders_written: usize,
+    pub documents_written: usize,
+}
+
+fn escape_rtf(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '{' => escaped.push_str("\\{"),
+            '}' => escaped.push_str("\\}"),
+            '\n' => escaped.push_str("\\line "),
+            '\t' => escaped.push_str("\\tab "),
+            c if c.is_ascii() => escaped.push(c),
+            c => {
+                // RTF \u takes a signed 16-bit value; astral chars need surrogate pairs.
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("\\u{}?", *unit as i16));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+fn paragraphs_to_rtf(paragraphs: &[String]) -> String {
+    let mut rtf = String::from(
+        "{\\rtf1\\ansi\\ansicpg1252\\uc1\\deff0\n{\\fonttbl{\\f0\\froman Times New Roman;}}\n\\f0\\fs24\n",
+    );
+    for paragraph in paragraphs {
+        rtf.push_str("\\pard ");
+        rtf.push_str(&escape_rtf(paragraph));
+        rtf.push_str("\\par\n");
+    }
+    rtf.push('}');
+    rtf
+}
+
+struct BinderWriter<'a> {
+    project_path: &'a str,
+    docs_dir: PathBuf,
+    next_id: u32,
+    folders_written: usize,
+    documents_written: usize,
+}
+
+impl BinderWriter<'_> {
+    fn write_nodes(&mut self, nodes: &[StructureNode], xml: &mut String) -> Result<(), String> {
+        for node in nodes {
+            let id = self.next_id;
+            self.next_id += 1;
+
+            if node.node_type == "scene" {
+                let content = match &node.file {
+                    Some(file) => {
+                        let path = PathBuf::from(self.project_path)
+                            .join("manuscript")
+                            .join(file);
+                        // Missing files read as empty
+                        read_scene_content(&path).map_err(|e| {
+                            format!("Failed to read scene '{}': {e}", path.display())
+                        })?
+                    }
+                    None => String::new(),
+                };
+                let rtf = paragraphs_to_rtf(&extract_paragraphs(&content));
+                atomic_write(&self.docs_dir.join(format!("{id}.rtf")), &rtf)?;
+                self.documents_written += 1;
+
+                xml.push_str(&format!(
+                    "<BinderItem ID=\"{id}\" Type=\"Text\"><Title>{}</Title></BinderItem>\n",
+                    escape_xml(&node.title)
+                ));
+            } else {
+                self.folders_written += 1;
+                xml.push_str(&format!(
+                    "<BinderItem ID=\"{id}\" Type=\"Folder\"><Title>{}</Title><Children>\n",
+                    escape_xml(&node.title)
+                ));
+                self.write_nodes(&node.children, xml)?;
+                xml.push_str("</Children></BinderItem>\n");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn resolve_package_path(path: PathBuf, title: &str) -> PathBuf {
+    if path.extension().and_then(|e| e.to_str()) == Some("scriv") {
+        path
+    } else {
+        let name = if title.trim().is_empty() {
+            "Untitled"
+        } else {
+            title.trim()
+        };
+        let safe: String = name
+            .chars()
+            .map(|c| {
+                if matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') {
+                    '-'
+                } else {
+                    c
+                }
+            })
+            .collect();
+        path.join(format!("{safe}.scriv"))
+    }
+}
+
+fn package_stem(package: &Path) -> String {
+    package
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("Project")
+        .to_string()
+}
+
+/// Writes a Scrivener 2 style package: acts and chapters become binder
+/// folders and every scene becomes a text document under `Files/Docs`.
+#[tauri::command]
+pub fn export_to_scrivener(
+    project_path: String,
+    output_path: String,
+) -> Result<ScrivenerExportResult, String> {
+    validate_no_null_bytes(&project_path, "Project path")?;
+
+    let conn = open_app_db()?;
+    let project = get_project_by_path(&conn, &project_path)?;
+    let structure = crate::commands::project::get_structure(project_path.clone())?;
+
+    let package = resolve_package_path(
+        resolve_export_path(Some(&project.path), &output_path)?,
+        &project.title,
+    );
+    if package.exists() {
+        return Err(format!(
+            "Scrivener package already exists: {}",
+            package.display()
+        ));
+    }
+
+    let docs_dir = package.join("Files").join("Docs");
+    fs::create_dir_all(&docs_dir)
+        .map_err(|e| format!("Failed to create Scrivener package directories: {e}"))?;
+
+    // IDs 0-2 are reserved for the draft, research and trash root folders.
+    let mut writer = BinderWriter {
+        project_path: &project.path,
+        docs_dir,
+        next_id: 3,
+        folders_written: 0,
+        documents_written: 0,
+    };
+    let mut draft_children = String::new();
+    writer.write_nodes(&structure, &mut draft_children)?;
+
+    let scrivx = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+<ScrivenerProject Version=\"2.0\" Creator=\"BecomeAnAuthor\">\n\
+<Binder>\n\
+<BinderItem ID=\"0\" Type=\"DraftFolder\"><Title>{}</Title><Children>\n\
+{draft_children}</Children></BinderItem>\n\
+<BinderItem ID=\"1\" Type=\"ResearchFolder\"><Title>Research</Title></BinderItem>\n\
+<BinderItem ID=\"2\" Type=\"TrashFolder\"><Title>Trash</Title></BinderItem>\n\
+</Binder>\n\
+</ScrivenerProject>\n",
+        escape_xml(&project.title)
+    );
+    atomic_write(
+        &package.join(format!("{}.scrivx", package_stem(&package))),
+        &scrivx,
+    )?;
+
+    Ok(ScrivenerExportResult {
+        package_path: package.to_string_lossy().to_string(),
+        folders_written: writer.folders_written,
+        documents_written: writer.documents_written,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrivenerImportResult {
+    pub project: ProjectMeta,
+    pub acts: usize,
+    pub chapters: usize,
+    pub scenes: usize,
+    /// Binder items that were skipped or only partially converted
+    pub skipped: Vec<String>,
+}
+
+fn decode_cp1252(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{FFFD}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{FFFD}',
+        '\u{017D}', '\u{FFFD}', '\u{FFFD}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}',
+        '\u{2022}', '\u{2013}', '\u{2014}', '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}',
+        '\u{0153}', '\u{FFFD}', '\u{017E}', '\u{0178}',
+    ];
+    match byte {
+        0x80..=0x9F => HIGH[(byte - 0x80) as usize],
+        _ => byte as char,
+    }
+}
+
+fn push_rtf_char(current: &mut String, groups: &[(bool, usize)], c: char) {
+    if !groups.last().map(|g| g.0).unwrap_or(false) {
+        current.push(c);
+    }
+}
+
+/// Converts RTF to plain paragraphs. Formatting is dropped; destinations such
+/// as font tables, pictures and annotations are skipped entirely.
+fn rtf_to_paragraphs(rtf: &str) -> Vec<String> {
+    const SKIPPED_DESTINATIONS: [&str; 12] = [
+        "fonttbl",
+        "colortbl",
+        "stylesheet",
+        "info",
+        "pict",
+        "header",
+        "footer",
+        "listtable",
+        "listoverridetable",
+        "generator",
+        "xmlnstbl",
+        "expandedcolortbl",
+    ];
+
+    let bytes = rtf.as_bytes();
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    // Per group: (skip output, unicode fallback char count)
+    let mut groups: Vec<(bool, usize)> = vec![(false, 1)];
+    let mut pending_skip = 0usize;
+    let mut utf16_high: Option<u16> = None;
+    let mut i = 0usize;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+        match byte {
+            b'{' => {
+                let inherited = *groups.last().unwrap_or(&(false, 1));
+                groups.push(inherited);
+                i += 1;
+            }
+            b'}' => {
+                if groups.len() > 1 {
+                    groups.pop();
+                }
+                i += 1;
+            }
+            b'\\' => {
+                i += 1;
+                let Some(&next) = bytes.get(i) else { break };
+                if next == b'\\' || next == b'{' || next == b'}' {
+                    if pending_skip > 0 {
+                        pending_skip -= 1;
+                    } else {
+                        push_rtf_char(&mut current, &groups, next as char);
+                    }
+                    i += 1;
+                    continue;
+                }
+                if next == b'*' {
+                    if let Some(group) = groups.last_mut() {
+                        group.0 = true;
+                    }
+                    i += 1;
+                    continue;
+                }
+                if next == b'\'' {
+                    // A malformed or truncated escape is kept as literal text
+                    let value = bytes
+                        .get(i + 1..i + 3)
+                        .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                        .and_then(|hex| std::str::from_utf8(hex).ok())
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                    let Some(value) = value else {
+                        push_rtf_char(&mut current, &groups, '\'');
+                        i += 1;
+                        continue;
+                    };
+                    if pending_skip > 0 {
+                        pending_skip -= 1;
+                    } else {
+                        push_rtf_char(&mut current, &groups, decode_cp1252(value));
+                    }
+                    i += 3;
+                    continue;
+                }
+                if next == b'~' {
+                    push_rtf_char(&mut current, &groups, '\u{00A0}');
+                    i += 1;
+                    continue;
+                }
+                if !next.is_ascii_alphabetic() {
+                    if next == b'\n' || next == b'\r' {
+                        push_rtf_char(&mut current, &groups, '\n');
+                    }
+                    i += 1;
+                    continue;
+                }
+
+                let word_start = i;
+                while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+                    i += 1;
+                }
+                let word = &rtf[word_start..i];
+                let param_start = i;
+                if i < bytes.len() && bytes[i] == b'-' {
+                    i += 1;
+                }
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let param: Option<i32> = rtf[param_start..i].parse().ok();
+                if i < bytes.len() && bytes[i] == b' ' {
+                    i += 1;
+                }
+
+                let skipping = groups.last().map(|g| g.0).unwrap_or(false);
+                match word {
+                    _ if SKIPPED_DESTINATIONS.contains(&word) => {
+                        if let Some(group) = groups.last_mut() {
+                            group.0 = true;
+                        }
+                    }
+                    "par" | "sect" | "page" if !skipping => {
+                        paragraphs.push(std::mem::take(&mut current));
+                    }
+                    "line" => push_rtf_char(&mut current, &groups, '\n'),
+                    "tab" => push_rtf_char(&mut current, &groups, '\t'),
+                    "emdash" => push_rtf_char(&mut current, &groups, '\u{2014}'),
+                    "endash" => push_rtf_char(&mut current, &groups, '\u{2013}'),
+                    "lquote" => push_rtf_char(&mut current, &groups, '\u{2018}'),
+                    "rquote" => push_rtf_char(&mut current, &groups, '\u{2019}'),
+                    "ldblquote" => push_rtf_char(&mut current, &groups, '\u{201C}'),
+                    "rdblquote" => push_rtf_char(&mut current, &groups, '\u{201D}'),
+                    "bullet" => push_rtf_char(&mut current, &groups, '\u{2022}'),
+                    "uc" => {
+                        if let Some(group) = groups.last_mut() {
+                            group.1 = param.unwrap_or(1).max(0) as usize;
+                        }
+                    }
+                    "u" => {
+                        let unit = param.unwrap_or(0) as i16 as u16;
+                        if (0xD800..0xDC00).contains(&unit) {
+                            utf16_high = Some(unit);
+                        } else {
+                            let decoded = match utf16_high.take() {
+                                Some(high) => {
+                                    char::decode_utf16([high, unit]).next().and_then(Result::ok)
+                                }
+                                None => char::from_u32(unit as u32),
+                            };
+                            if let Some(c) = decoded {
+                                push_rtf_char(&mut current, &groups, c);
+                            }
+                        }
+                        pending_skip = groups.last().map(|g| g.1).unwrap_or(1);
+                    }
+                    _ => {}
+                }
+            }
+            b'\r' | b'\n' => {
+                i += 1;
+            }
+            _ => {
+                let ch = rtf[i..].chars().next().unwrap_or('\u{FFFD}');
+                if pending_skip > 0 {
+                    pending_skip -= 1;
+                } else {
+                    push_rtf_char(&mut current, &groups, ch);
+                }
+                i += ch.len_utf8();
+            }
+        }
+    }
+
+    if !current.trim().is_empty() {
+        paragraphs.push(current);
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+#[derive(Default)]
+struct ImportCounts {
+    acts: usize,
+    chapters: usize,
+    scenes: usize,
+    skipped: Vec<String>,
+}
+
+fn binder_title(item: &XmlElement) -> String {
+    item.child("Title")
+        .map(|t| t.text.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| "Untitled".to_string())
+}
+
+fn binder_children(item: &XmlElement) -> Vec<&XmlElement> {
+    item.child("Children")
+        .map(|c| {
+            c.children
+                .iter()
+                .filter(|e| e.name == "BinderItem")
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn binder_type(item: &XmlElement) -> &str {
+    item.attrs.get("Type").map(String::as_str).unwrap_or("")
+}
+
+fn is_container(item: &XmlElement) -> bool {
+    matches!(binder_type(item), "Folder" | "DraftFolder") || !binder_children(item).is_empty()
+}
+
+/// The item's RTF text; `Ok(None)` when the package has no file for it, an
+/// error when the file is too large or unreadable.
+fn read_binder_document(package: &Path, item: &XmlElement) -> Result<Option<String>, String> {
+    let mut candidates = Vec::new();
+    if let Some(uuid) = item.attrs.get("UUID") {
+        candidates.push(
+            package
+                .join("Files")
+                .join("Data")
+                .join(uuid)
+                .join("content.rtf"),
+        );
+    }
+    if let Some(id) = item.attrs.get("ID") {
+        candidates.push(package.join("Files").join("Docs").join(format!("{id}.rtf")));
+        candidates.push(
+            package
+                .join("Files")
+                .join("Data")
+                .join(id)
+                .join("content.rtf"),
+        );
+    }
+    let Some(path) = candidates.into_iter().find(|path| path.is_file()) else {
+        return Ok(None);
+    };
+    read_package_file(&path).map(|bytes| Some(String::from_utf8_lossy(&bytes).into_owned()))
+}
+
+/// Reads a file from the (untrusted) package, refusing oversized ones
+fn read_package_file(path: &Path) -> Result<Vec<u8>, String> {
+    let metadata = fs::metadata(path).map_err(|e| format!("unreadable ({e})"))?;
+    validate_file_size(metadata.len(), MAX_SCRIVENER_DOCUMENT_SIZE, "Document")?;
+    fs::read(path).map_err(|e| format!("unreadable ({e})"))
+}
+
+fn import_scene(
+    package: &Path,
+    item: &XmlElement,
+    counts: &mut ImportCounts,
+    scenes: &mut Vec<ImportedSceneContent>,
+) -> Option<StructureNode> {
+    let title = binder_title(item);
+    if binder_type(item) != "Text" {
+        counts.skipped.push(format!(
+            "{title}: unsupported item type '{}'",
+            binder_type(item)
+        ));
+        return None;
+    }
+
+    let paragraphs = match read_binder_document(package, item) {
+        Ok(Some(rtf)) => rtf_to_paragraphs(&rtf),
+        Ok(None) => Vec::new(),
+        Err(e) => {
+            counts.skipped.push(format!("{title}: {e}"));
+            return None;
+        }
+    };
+    let node = new_structure_node("scene", title);
+    counts.scenes += 1;
+    scenes.push(ImportedSceneContent {
+        file: node.file.clone().unwrap_or_default(),
+        title: node.title.clone(),
+        paragraphs,
+    });
+    Some(node)
+}
+
+/// Collects every text document below `item` as scenes, flattening any
+/// folder nesting deeper than the act/chapter/scene hierarchy allows.
+fn collect_scenes(
+    package: &Path,
+    item: &XmlElement,
+    counts: &mut ImportCounts,
+    scenes: &mut Vec<ImportedSceneContent>,
+    output: &mut Vec<StructureNode>,
+) {
+    if binder_type(item) == "Text" {
+        if let Some(node) = import_scene(package, item, counts, scenes) {
+            output.push(node);
+        }
+    } else if !is_container(item) {
+        import_scene(package, item, counts, scenes);
+    }
+    for child in binder_children(item) {
+        collect_scenes(package, child, counts, scenes, output);
+    }
+}
+
+fn import_chapter(
+    package: &Path,
+    item: &XmlElement,
+    counts: &mut ImportCounts,
+    scenes: &mut Vec<ImportedSceneContent>,
+) -> StructureNode {
+    let mut chapter = new_structure_node("chapter", binder_title(item));
+    counts.chapters += 1;
+    if binder_type(item) == "Text" {
+        if let Some(node) = import_scene(package, item, counts, scenes) {
+            chapter.children.push(node);
+        }
+    }
+    for child in binder_children(item) {
+        if is_container(child) && binder_type(child) != "Text" {
+            counts.skipped.push(format!(
+                "{}: nested folder flattened into chapter '{}'",
+                binder_title(child),
+                chapter.title
+            ));
+        }
+        collect_scenes(package, child, counts, scenes, &mut chapter.children);
+    }
+    chapter
+}
+
+/// Folders that contain folders become acts; other folders become chapters.
+/// Loose documents are grouped into a chapter so the hierarchy stays valid.
+fn import_act_children(
+    package: &Path,
+    items: &[&XmlElement],
+    counts: &mut ImportCounts,
+    scenes: &mut Vec<ImportedSceneContent>,
+) -> Vec<StructureNode> {
+    let mut chapters = Vec::new();
+    let mut loose: Option<StructureNode> = None;
+    for item in items {
+        if is_container(item) {
+            if let Some(chapter) = loose.take() {
+                chapters.push(chapter);
+            }
+            chapters.push(import_chapter(package, item, counts, scenes));
+        } else {
+            let chapter = loose.get_or_insert_with(|| {
+                counts.chapters += 1;
+                new_structure_node("chapter", "Untitled Chapter".to_string())
+            });
+            if let Some(node) = import_scene(package, item, counts, scenes) {
+                chapter.children.push(node);
+            }
+        }
+    }
+    if let Some(chapter) = loose.take() {
+        chapters.push(chapter);
+    }
+    chapters
+}
+
+fn find_scrivx(package: &Path) -> Result<PathBuf, String> {
+    let entries = fs::read_dir(package).map_err(|e| {
+        format!(
+            "Failed to read Scrivener package '{}': {e}",
+            package.display()
+        )
+    })?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("scrivx") {
+            return Ok(path);
+        }
+    }
+    Err("No .scrivx binder found in Scrivener package".to_string())
+}
+
+/// Imports a .scriv package into a new project in the given series.
+#[tauri::command]
+pub fn import_from_scrivener(
+    scriv_path: String,
+    series_id: String,
+    series_index: String,
+) -> Result<ScrivenerImportResult, String> {
+    validate_no_null_bytes(&scriv_path, "Scrivener path")?;
+    let package = PathBuf::from(&scriv_path);
+    if !package.is_dir() {
+        return Err("Scrivener path must be a .scriv package directory".to_string());
+    }
+
+    let scrivx_path = find_scrivx(&package)?;
+    let scrivx = read_package_file(&scrivx_path)
+        .and_then(|bytes| String::from_utf8(bytes).map_err(|e| e.to_string()))
+        .map_err(|e| format!("Failed to read Scrivener binder: {e}"))?;
+    let document = parse_xml(&scrivx)?;
+    let binder = document
+        .find("Binder")
+        .ok_or("Scrivener binder is missing a <Binder> element")?;
+    let draft = binder
+        .children
+        .iter()
+        .find(|item| item.name == "BinderItem" && binder_type(item) == "DraftFolder")
+        .ok_or("Scrivener binder has no Draft folder")?;
+
+    let mut counts = ImportCounts::default();
+    let mut scenes = Vec::new();
+    let top_level = binder_children(draft);
+    let has_acts = top_level.iter().any(|item| {
+        binder_children(item)
+            .iter()
+            .any(|child| is_container(child))
+    });
+
+    let structure = if has_acts {
+        let mut acts = Vec::new();
+        let mut loose = Vec::new();
+        for item in &top_level {
+            if is_container(item) {
+                let mut act = new_structure_node("act", binder_title(item));
+                counts.acts += 1;
+                act.children =
+                    import_act_children(&package, &binder_children(item), &mut counts, &mut scenes);
+                acts.push(act);
+            } else {
+                loose.push(*item);
+            }
+        }
+        if !loose.is_empty() {
+            let mut act = new_structure_node("act", "Untitled Act".to_string());
+            counts.acts += 1;
+            act.children = import_act_children(&package, &loose, &mut counts, &mut scenes);
+            acts.push(act);
+        }
+        acts
+    } else {
+        let mut act = new_structure_node("act", "Act 1".to_string());
+        counts.acts += 1;
+        act.children = import_act_children(&package, &top_level, &mut counts, &mut scenes);
+        vec![act]
+    };
+
+    let title = scrivx_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "Imported Scrivener Project".to_string());
+    let project = create_project_from_import(title, series_id, series_index, structure, scenes)?;
+
+    Ok(ScrivenerImportResult {
+        project,
+        acts: counts.acts,
+        chapters: counts.chapters,
+        scenes: counts.scenes,
+        skipped: counts.skipped,
+    })
+}
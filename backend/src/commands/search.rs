@@ -9,7 +9,7 @@ use crate::storage::{
     clear_search_index, get_search_signature, open_app_db, set_search_signature,
     upsert_search_document,
 };
-use crate::utils::timestamp;
+use crate::utils::{read_text_file, timestamp};
 
 fn add_path_metadata_fingerprint(path: &Path, hasher: &mut DefaultHasher) -> Result<(), String> {
     path.to_string_lossy().hash(hasher);
@@ -125,7 +125,7 @@ fn rebuild_search_index_for_project(
         let scene_path = PathBuf::from(project_path)
             .join("manuscript")
             .join(&scene_file);
-        let body = fs::read_to_string(&scene_path)
+        let body = read_text_file(&scene_path)
             .map_err(|e| format!("Failed to read scene '{}': {e}", scene_path.display()))?;
         upsert_search_document(
             &conn,
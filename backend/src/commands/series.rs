@@ -3,6 +3,8 @@
 use rusqlite::{params, Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
+use crate::commands::codex_category::{default_codex_categories, store_codex_categories};
+use crate::commands::codex_images::{resolve_entry_images, store_entry_images};
 use crate::models::{CodexEntry, CodexRelation, Series};
 use crate::storage::{open_app_db, with_transaction};
 use crate::utils::validate_project_title;
@@ -45,15 +47,17 @@ fn row_to_series(row: &rusqlite::Row<'_>) -> Result<Series, rusqlite::Error> {
         author: row.get(3)?,
         genre: row.get(4)?,
         status: row.get(5)?,
+        cover_image: row.get(8)?,
         created_at: row.get(6)?,
         updated_at: row.get(7)?,
     })
 }
 
-fn get_series(conn: &Connection, series_id: &str) -> Result<Option<Series>, String> {
+pub(crate) fn get_series(conn: &Connection, series_id: &str) -> Result<Option<Series>, String> {
     conn.query_row(
         r#"
-        SELECT id, title, description, author, genre, status, created_at, updated_at
+        SELECT id, title, description, author, genre, status, created_at, updated_at,
+               cover_image
         FROM series
         WHERE id = ?1
         "#,
@@ -67,15 +71,16 @@ fn get_series(conn: &Connection, series_id: &str) -> Result<Option<Series>, Stri
 fn upsert_series(conn: &Connection, series: &Series) -> Result<(), String> {
     conn.execute(
         r#"
-        INSERT INTO series(id, title, description, author, genre, status, created_at, updated_at)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        INSERT INTO series(id, title, description, author, genre, status, created_at, updated_at, cover_image)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
         ON CONFLICT(id) DO UPDATE SET
             title = excluded.title,
             description = excluded.description,
             author = excluded.author,
             genre = excluded.genre,
             status = excluded.status,
-            updated_at = excluded.updated_at
+            updated_at = excluded.updated_at,
+            cover_image = excluded.cover_image
         "#,
         params![
             series.id,
@@ -86,6 +91,7 @@ fn upsert_series(conn: &Connection, series: &Series) -> Result<(), String> {
             series.status,
             series.created_at,
             series.updated_at,
+            series.cover_image,
         ],
     )
     .map_err(|e| format!("Failed to upsert series: {e}"))?;
@@ -276,7 +282,8 @@ pub fn list_series() -> Result<Vec<Series>, String> {
     let mut stmt = conn
         .prepare(
             r#"
-            SELECT id, title, description, author, genre, status, created_at, updated_at
+            SELECT id, title, description, author, genre, status, created_at, updated_at,
+                   cover_image
             FROM series
             ORDER BY updated_at DESC
             "#,
@@ -295,6 +302,53 @@ pub fn list_series() -> Result<Vec<Series>, String> {
     Ok(all_series)
 }
 
+pub(crate) fn count_series_projects(conn: &Connection, series_id: &str) -> Result<i64, String> {
+    conn.query_row(
+        "SELECT COUNT(1) FROM projects WHERE series_id = ?1",
+        params![series_id],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to count linked projects: {e}"))
+}
+
+/// Whether the series has no projects left (trashed projects don't count)
+#[tauri::command]
+pub fn is_series_empty(series_id: String) -> Result<bool, String> {
+    let conn = open_app_db()?;
+    if get_series(&conn, &series_id)?.is_none() {
+        return Err(format!("Series not found: {series_id}"));
+    }
+    Ok(count_series_projects(&conn, &series_id)? == 0)
+}
+
+/// Series without any projects, for cleaning up after books were deleted
+#[tauri::command]
+pub fn list_empty_series() -> Result<Vec<Series>, String> {
+    let conn = open_app_db()?;
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT id, title, description, author, genre, status, created_at, updated_at,
+                   cover_image
+            FROM series
+            WHERE NOT EXISTS (SELECT 1 FROM projects WHERE projects.series_id = series.id)
+            ORDER BY updated_at DESC
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare empty series query: {e}"))?;
+
+    let rows = stmt
+        .query_map([], row_to_series)
+        .map_err(|e| format!("Failed to execute empty series query: {e}"))?;
+
+    let mut empty_series = Vec::new();
+    for row in rows {
+        empty_series.push(row.map_err(|e| format!("Failed to decode series row: {e}"))?);
+    }
+
+    Ok(empty_series)
+}
+
 #[tauri::command]
 pub fn create_series(
     title: String,
@@ -313,12 +367,14 @@ pub fn create_series(
         author,
         genre,
         status,
+        cover_image: None,
         created_at: now,
         updated_at: now,
     };
 
     let conn = open_app_db()?;
     upsert_series(&conn, &series)?;
+    store_codex_categories(&conn, &series.id, &default_codex_categories())?;
     Ok(series)
 }
 
@@ -352,6 +408,11 @@ pub fn update_series(series_id: String, updates: serde_json::Value) -> Result<()
     } else if updates.get("status").is_some_and(|v| v.is_null()) {
         series.status = None;
     }
+    if let Some(cover_image) = updates.get("coverImage").and_then(|v| v.as_str()) {
+        series.cover_image = Some(cover_image.to_string());
+    } else if updates.get("coverImage").is_some_and(|v| v.is_null()) {
+        series.cover_image = None;
+    }
 
     series.updated_at = now();
     upsert_series(&conn, &series)
@@ -361,13 +422,7 @@ pub fn update_series(series_id: String, updates: serde_json::Value) -> Result<()
 pub fn delete_series(series_id: String) -> Result<(), String> {
     let conn = open_app_db()?;
 
-    let linked_projects: i64 = conn
-        .query_row(
-            "SELECT COUNT(1) FROM projects WHERE series_id = ?1",
-            params![series_id],
-            |row| row.get(0),
-        )
-        .map_err(|e| format!("Failed to count linked projects: {e}"))?;
+    let linked_projects = count_series_projects(&conn, &series_id)?;
     if linked_projects > 0 {
         return Err(format!(
             "Cannot delete series with {} linked novel(s). Use delete_series_cascade instead.",
@@ -381,6 +436,11 @@ pub fn delete_series(series_id: String) -> Result<(), String> {
 
     conn.execute("DELETE FROM series WHERE id = ?1", params![series_id])
         .map_err(|e| format!("Failed to delete series row: {e}"))?;
+    conn.execute(
+        "DELETE FROM series_settings WHERE series_id = ?1",
+        params![series_id],
+    )
+    .map_err(|e| format!("Failed to delete series setting rows: {e}"))?;
 
     Ok(())
 }
@@ -440,7 +500,9 @@ pub fn list_series_codex_entries(
 
         for row in rows {
             let payload = row.map_err(|e| format!("Failed to decode codex entry row: {e}"))?;
-            entries.push(parse_json_payload::<CodexEntry>(&payload, "codex entry")?);
+            let mut entry = parse_json_payload::<CodexEntry>(&payload, "codex entry")?;
+            resolve_entry_images(&series_id, &mut entry);
+            entries.push(entry);
         }
         return Ok(entries);
     }
@@ -462,12 +524,84 @@ pub fn list_series_codex_entries(
 
     for row in rows {
         let payload = row.map_err(|e| format!("Failed to decode codex entry row: {e}"))?;
-        entries.push(parse_json_payload::<CodexEntry>(&payload, "codex entry")?);
+        let mut entry = parse_json_payload::<CodexEntry>(&payload, "codex entry")?;
+        resolve_entry_images(&series_id, &mut entry);
+        entries.push(entry);
     }
 
     Ok(entries)
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CorruptCodexEntry {
+    pub id: String,
+    pub category: String,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CodexEntryListing {
+    pub entries: Vec<CodexEntry>,
+    pub corrupt: Vec<CorruptCodexEntry>,
+}
+
+/// Like `list_series_codex_entries`, but reports rows whose payload fails to
+/// parse instead of failing the whole listing.
+#[tauri::command]
+pub fn list_series_codex_entries_verbose(
+    series_id: String,
+    category: Option<String>,
+) -> Result<CodexEntryListing, String> {
+    let conn = open_app_db()?;
+    let mut stmt = conn
+        .prepare(
+            r#"
+            SELECT id, category, payload_json
+            FROM codex_entries
+            WHERE series_id = ?1 AND (?2 IS NULL OR category = ?2)
+            ORDER BY updated_at DESC
+            "#,
+        )
+        .map_err(|e| format!("Failed to prepare codex entry query: {e}"))?;
+
+    let rows = stmt
+        .query_map(params![series_id, category], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to execute codex entry query: {e}"))?;
+
+    let mut listing = CodexEntryListing {
+        entries: Vec::new(),
+        corrupt: Vec::new(),
+    };
+    for row in rows {
+        let (id, category, payload) =
+            row.map_err(|e| format!("Failed to decode codex entry row: {e}"))?;
+        match parse_json_payload::<CodexEntry>(&payload, "codex entry") {
+            Ok(mut entry) => {
+                resolve_entry_images(&series_id, &mut entry);
+                listing.entries.push(entry);
+            }
+            Err(error) => {
+                log::warn!("Skipping corrupt codex entry {id} in series {series_id}: {error}");
+                listing.corrupt.push(CorruptCodexEntry {
+                    id,
+                    category,
+                    error,
+                });
+            }
+        }
+    }
+
+    Ok(listing)
+}
+
 #[tauri::command]
 pub fn get_series_codex_entry(
     series_id: String,
@@ -487,15 +621,15 @@ pub fn get_series_codex_entry(
         return Ok(None);
     };
 
-    Ok(Some(parse_json_payload::<CodexEntry>(
-        &payload,
-        "codex entry",
-    )?))
+    let mut entry = parse_json_payload::<CodexEntry>(&payload, "codex entry")?;
+    resolve_entry_images(&series_id, &mut entry);
+    Ok(Some(entry))
 }
 
 #[tauri::command]
 pub fn save_series_codex_entry(series_id: String, entry: CodexEntry) -> Result<(), String> {
     let conn = open_app_db()?;
+    let entry = store_entry_images(&series_id, &entry)?;
     let payload_json = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
     let aliases_json = serde_json::to_string(&entry.aliases).map_err(|e| e.to_string())?;
 
@@ -594,12 +728,44 @@ pub fn list_series_codex_relations(series_id: String) -> Result<Vec<CodexRelatio
     Ok(relations)
 }
 
+fn series_codex_entry_exists(
+    conn: &Connection,
+    series_id: &str,
+    entry_id: &str,
+) -> Result<bool, String> {
+    conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM codex_entries WHERE series_id = ?1 AND id = ?2)",
+        params![series_id, entry_id],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to check codex entry existence: {e}"))
+}
+
+fn validate_series_relation_endpoints(
+    conn: &Connection,
+    series_id: &str,
+    relation: &CodexRelation,
+) -> Result<(), String> {
+    if relation.parent_id == relation.child_id {
+        return Err("A codex entry cannot be related to itself".to_string());
+    }
+    for endpoint in [&relation.parent_id, &relation.child_id] {
+        if !series_codex_entry_exists(conn, series_id, endpoint)? {
+            return Err(format!(
+                "Codex entry '{endpoint}' does not exist in this series"
+            ));
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn save_series_codex_relation(
     series_id: String,
     relation: CodexRelation,
 ) -> Result<(), String> {
     let conn = open_app_db()?;
+    validate_series_relation_endpoints(&conn, &series_id, &relation)?;
     let payload_json = serde_json::to_string(&relation).map_err(|e| e.to_string())?;
 
     conn.execute(
@@ -638,3 +804,30 @@ pub fn delete_series_codex_relation(series_id: String, relation_id: String) -> R
     .map_err(|e| format!("Failed to delete series codex relation: {e}"))?;
     Ok(())
 }
+
+/// Removes series relations that point at missing entries or at themselves.
+/// Returns the ids of the relations that were deleted.
+#[tauri::command]
+pub fn repair_series_codex_relations(series_id: String) -> Result<Vec<String>, String> {
+    let conn = open_app_db()?;
+    let relations = list_series_codex_relations(series_id.clone())?;
+
+    with_transaction(&conn, |conn| {
+        let mut removed = Vec::new();
+        for relation in relations {
+            let dangling = relation.parent_id == relation.child_id
+                || !series_codex_entry_exists(conn, &series_id, &relation.parent_id)?
+                || !series_codex_entry_exists(conn, &series_id, &relation.child_id)?;
+            if !dangling {
+                continue;
+            }
+            conn.execute(
+                "DELETE FROM codex_relations WHERE series_id = ?1 AND id = ?2",
+                params![series_id, relation.id],
+            )
+            .map_err(|e| format!("Failed to delete dangling series codex relation: {e}"))?;
+            removed.push(relation.id);
+        }
+        Ok(removed)
+    })
+}
@@ -0,0 +1,279 @@
+// Series bible export (whole-series codex reference document)
+
+use std::collections::{BTreeMap, HashMap};
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::codex_category::{
+    category_display_name, codex_categories_for_series, CodexCategory,
+};
+use crate::commands::series::{get_series, list_series_codex_entries, list_series_codex_relations};
+use crate::models::{CodexEntry, CodexRelation, CodexRelationType};
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, render_markdown, resolve_export_path, validate_no_null_bytes, write_docx,
+    DocBlock,
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesBibleExportResult {
+    pub output_path: String,
+    pub format: String,
+    pub entry_count: usize,
+    pub relation_count: usize,
+}
+
+/// Position in the series' category order; unknown categories sort last
+fn category_rank(categories: &[CodexCategory], category: &str) -> usize {
+    categories
+        .iter()
+        .position(|known| known.id == category)
+        .unwrap_or(categories.len())
+}
+
+fn load_relation_types(series_id: &str) -> Result<HashMap<String, String>, String> {
+    let conn = open_app_db()?;
+    let mut stmt = conn
+        .prepare("SELECT payload_json FROM codex_relation_types WHERE series_id = ?1")
+        .map_err(|e| format!("Failed to prepare relation type query: {e}"))?;
+    let rows = stmt
+        .query_map(params![series_id], |row| row.get::<_, String>(0))
+        .map_err(|e| format!("Failed to execute relation type query: {e}"))?;
+
+    let mut names = HashMap::new();
+    for row in rows {
+        let payload = row.map_err(|e| format!("Failed to decode relation type row: {e}"))?;
+        // A malformed custom type shouldn't block the export; fall back to the raw label.
+        if let Ok(relation_type) = serde_json::from_str::<CodexRelationType>(&payload) {
+            names.insert(relation_type.id, relation_type.name);
+        }
+    }
+    Ok(names)
+}
+
+fn relation_label(relation: &CodexRelation, type_names: &HashMap<String, String>) -> String {
+    relation
+        .label
+        .as_deref()
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .map(str::to_string)
+        .or_else(|| {
+            relation
+                .type_id
+                .as_ref()
+                .and_then(|type_id| type_names.get(type_id).cloned())
+        })
+        .unwrap_or_else(|| "related to".to_string())
+}
+
+fn relationship_summary(
+    entry: &CodexEntry,
+    relations: &[&CodexRelation],
+    by_id: &HashMap<&str, &CodexEntry>,
+    categories: &[CodexCategory],
+) -> String {
+    let mut counts: BTreeMap<usize, (String, usize)> = BTreeMap::new();
+    for relation in relations {
+        let other_id = if relation.parent_id == entry.id {
+            &relation.child_id
+        } else {
+            &relation.parent_id
+        };
+        if let Some(other) = by_id.get(other_id.as_str()) {
+            let slot = counts
+                .entry(category_rank(categories, &other.category))
+                .or_insert_with(|| {
+                    (
+                        category_display_name(categories, &other.category).to_lowercase(),
+                        0,
+                    )
+                });
+            slot.1 += 1;
+        }
+    }
+    let parts: Vec<String> = counts
+        .into_values()
+        .map(|(label, count)| format!("{count} {label}"))
+        .collect();
+    format!("Connected to {}.", parts.join(", "))
+}
+
+fn entry_blocks(
+    entry: &CodexEntry,
+    relations: &[&CodexRelation],
+    by_id: &HashMap<&str, &CodexEntry>,
+    type_names: &HashMap<String, String>,
+    categories: &[CodexCategory],
+) -> Vec<DocBlock> {
+    let mut blocks = vec![DocBlock::Heading(3, entry.name.clone())];
+
+    let aliases: Vec<&str> = entry
+        .aliases
+        .iter()
+        .map(|alias| alias.trim())
+        .filter(|alias| !alias.is_empty())
+        .collect();
+    if !aliases.is_empty() {
+        blocks.push(DocBlock::Paragraph(format!(
+            "Also known as: {}",
+            aliases.join(", ")
+        )));
+    }
+
+    blocks.extend(
+        entry
+            .description
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+            .map(|paragraph| DocBlock::Paragraph(paragraph.to_string())),
+    );
+
+    let mut attributes: Vec<(&String, &String)> = entry
+        .attributes
+        .iter()
+        .filter(|(_, value)| !value.trim().is_empty())
+        .collect();
+    attributes.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    blocks.extend(
+        attributes
+            .into_iter()
+            .map(|(key, value)| DocBlock::Bullet(format!("{key}: {}", value.trim()))),
+    );
+
+    if let Some(notes) = entry.notes.as_deref().map(str::trim) {
+        if !notes.is_empty() {
+            blocks.push(DocBlock::Paragraph(format!("Notes: {notes}")));
+        }
+    }
+
+    if !relations.is_empty() {
+        blocks.push(DocBlock::Paragraph("Relations:".to_string()));
+        if entry.category == "character" {
+            blocks.push(DocBlock::Paragraph(relationship_summary(
+                entry, relations, by_id, categories,
+            )));
+        }
+        for relation in relations {
+            let label = relation_label(relation, type_names);
+            let line = if relation.parent_id == entry.id {
+                let other = by_id
+                    .get(relation.child_id.as_str())
+                    .map_or(relation.child_id.as_str(), |other| other.name.as_str());
+                format!("{label} \u{2192} {other}")
+            } else {
+                let other = by_id
+                    .get(relation.parent_id.as_str())
+                    .map_or(relation.parent_id.as_str(), |other| other.name.as_str());
+                format!("{other} \u{2192} {label}")
+            };
+            blocks.push(DocBlock::Bullet(line));
+        }
+    }
+
+    blocks
+}
+
+/// Compiles the series codex into a reference document grouped by category.
+/// `format` is "markdown" (or "md") or "docx".
+#[tauri::command]
+pub fn export_series_bible(
+    series_id: String,
+    output_path: String,
+    format: String,
+) -> Result<SeriesBibleExportResult, String> {
+    validate_no_null_bytes(&series_id, "Series id")?;
+    let target = resolve_export_path(None, &output_path)?;
+    let format = match format.trim().to_lowercase().as_str() {
+        "markdown" | "md" => "markdown",
+        "docx" => "docx",
+        other => return Err(format!("Unsupported series bible format: {other}")),
+    };
+
+    let (series, categories) = {
+        let conn = open_app_db()?;
+        let series =
+            get_series(&conn, &series_id)?.ok_or_else(|| "Series not found".to_string())?;
+        (series, codex_categories_for_series(&conn, &series_id))
+    };
+    let mut entries = list_series_codex_entries(series_id.clone(), None)?;
+    let relations = list_series_codex_relations(series_id.clone())?;
+    let type_names = load_relation_types(&series_id)?;
+
+    entries.sort_by(|a, b| {
+        category_rank(&categories, &a.category)
+            .cmp(&category_rank(&categories, &b.category))
+            .then_with(|| a.category.cmp(&b.category))
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    let by_id: HashMap<&str, &CodexEntry> = entries
+        .iter()
+        .map(|entry| (entry.id.as_str(), entry))
+        .collect();
+
+    let mut blocks = vec![DocBlock::Heading(
+        1,
+        format!("{} \u{2014} Series Bible", series.title),
+    )];
+    if let Some(description) = series.description.as_deref().map(str::trim) {
+        if !description.is_empty() {
+            blocks.push(DocBlock::Paragraph(description.to_string()));
+        }
+    }
+
+    let mut current_category: Option<&str> = None;
+    for entry in &entries {
+        if current_category != Some(entry.category.as_str()) {
+            blocks.push(DocBlock::Heading(
+                2,
+                category_display_name(&categories, &entry.category),
+            ));
+            current_category = Some(entry.category.as_str());
+        }
+        let entry_relations: Vec<&CodexRelation> = relations
+            .iter()
+            .filter(|relation| relation.parent_id == entry.id || relation.child_id == entry.id)
+            .collect();
+        blocks.extend(entry_blocks(
+            entry,
+            &entry_relations,
+            &by_id,
+            &type_names,
+            &categories,
+        ));
+    }
+
+    if !relations.is_empty() {
+        blocks.push(DocBlock::Heading(2, "Relationship Index".to_string()));
+        let mut lines: Vec<String> = relations
+            .iter()
+            .filter_map(|relation| {
+                let parent = by_id.get(relation.parent_id.as_str())?;
+                let child = by_id.get(relation.child_id.as_str())?;
+                Some(format!(
+                    "{} \u{2014} {} \u{2014} {}",
+                    parent.name,
+                    relation_label(relation, &type_names),
+                    child.name
+                ))
+            })
+            .collect();
+        lines.sort_by_key(|line| line.to_lowercase());
+        blocks.extend(lines.into_iter().map(DocBlock::Bullet));
+    }
+
+    match format {
+        "docx" => write_docx(&target, &blocks)?,
+        _ => atomic_write(&target, &render_markdown(&blocks))?,
+    }
+
+    Ok(SeriesBibleExportResult {
+        output_path: target.to_string_lossy().to_string(),
+        format: format.to_string(),
+        entry_count: entries.len(),
+        relation_count: relations.len(),
+    })
+}
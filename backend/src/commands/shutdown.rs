@@ -0,0 +1,47 @@
+// App shutdown
+// Runs when the last window closes (and again on exit, where it is a no-op):
+// writes queued autosaves, releases this instance's project locks and prunes
+// expired emergency backups.
+
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+use crate::commands::backup_emergency::cleanup_emergency_backups;
+use crate::commands::project_lock::release_held_project_locks;
+use crate::commands::scene_autosave::SceneSaveQueue;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ShutdownSummary {
+    pub scenes_flushed: usize,
+    pub locks_released: usize,
+    pub emergency_backups_removed: usize,
+}
+
+/// Best effort: each step runs even if an earlier one failed.
+pub fn run_shutdown(queue: &SceneSaveQueue) -> ShutdownSummary {
+    let scenes_flushed = queue.flush(None);
+    let locks_released = release_held_project_locks();
+    let emergency_backups_removed = match cleanup_emergency_backups() {
+        Ok(cleanup) => cleanup.removed_count,
+        Err(e) => {
+            log::warn!("Emergency backup cleanup on shutdown failed: {e}");
+            0
+        }
+    };
+    log::info!(
+        "Shutdown: flushed {scenes_flushed} scene(s), released {locks_released} lock(s), removed {emergency_backups_removed} expired backup(s)"
+    );
+    ShutdownSummary {
+        scenes_flushed,
+        locks_released,
+        emergency_backups_removed,
+    }
+}
+
+/// Lets the frontend run the shutdown steps before it closes the app itself
+/// (e.g. through the process plugin's `exit`).
+#[tauri::command]
+pub fn shutdown(queue: State<'_, SceneSaveQueue>) -> Result<ShutdownSummary, String> {
+    Ok(run_shutdown(&queue))
+}
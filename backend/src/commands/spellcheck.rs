@@ -0,0 +1,307 @@
+// Offline spell-check against a local word list
+// The dictionary is a plain word list or a Hunspell `.dic` file; when a `.aff`
+// file sits next to the `.dic`, its prefix/suffix rules are applied so inflected
+// forms are known too. Without an explicit dictionary the bundled en_US one is
+// used. Words in the project's `.meta/dictionary.txt` and codex names/aliases
+// are always allowed; the project dictionary is seeded from the codex on
+// first use.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::scene::{
+    project_id_for_path, read_scene_content, resolve_scene_file, scene_file_path,
+};
+use crate::commands::series::list_series_codex_entries;
+use crate::storage::open_app_db;
+use crate::utils::{
+    atomic_write, expand_dictionary, extract_plain_text, parse_affix_rules, validate_file_size,
+    validate_no_null_bytes, validate_project_path, MAX_DICTIONARY_SIZE,
+};
+
+const PROJECT_DICTIONARY_FILE: &str = "dictionary.txt";
+const MAX_DICTIONARY_WORD_LENGTH: usize = 100;
+const BUNDLED_DICTIONARY: &str = "dictionaries/en_US.dic";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownWord {
+    pub word: String,
+    pub occurrences: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellcheckResult {
+    pub scene_id: String,
+    pub checked_words: usize,
+    pub unknown_words: Vec<UnknownWord>,
+}
+
+fn project_dictionary_path(project_path: &str) -> PathBuf {
+    Path::new(project_path)
+        .join(".meta")
+        .join(PROJECT_DICTIONARY_FILE)
+}
+
+/// Words of a dictionary file. A Hunspell `.dic` is expanded through the
+/// affix rules of the `.aff` beside it, if there is one.
+fn load_word_list(path: &Path) -> Result<HashSet<String>, String> {
+    let read = |path: &Path| -> Result<String, String> {
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Failed to read dictionary '{}': {e}", path.display()))?;
+        validate_file_size(metadata.len(), MAX_DICTIONARY_SIZE, "Dictionary")?;
+        fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read dictionary '{}': {e}", path.display()))
+    };
+    let content = read(path)?;
+    let aff_path = path.with_extension("aff");
+    let affixes = if path.extension().is_some_and(|ext| ext == "dic") && aff_path.is_file() {
+        Some(parse_affix_rules(&read(&aff_path)?))
+    } else {
+        None
+    };
+    Ok(expand_dictionary(&content, affixes.as_ref()))
+}
+
+/// The en_US dictionary shipped in the app's resources
+fn bundled_dictionary_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve(BUNDLED_DICTIONARY, BaseDirectory::Resource)
+        .map_err(|e| format!("Failed to locate bundled dictionary: {e}"))
+}
+
+pub(crate) fn normalize_apostrophes(word: &str) -> String {
+    word.replace('\u{2019}', "'").to_lowercase()
+}
+
+/// Letter runs with internal apostrophes, e.g. "don't" or "O’Brien". Tokens
+/// containing digits ("x2", "1990s") are not words and are dropped.
+pub(crate) fn tokenize_words(text: &str) -> Vec<&str> {
+    let is_apostrophe = |c: char| c == '\'' || c == '\u{2019}';
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let continues_word = c.is_alphanumeric()
+            || (is_apostrophe(c)
+                && start.is_some()
+                && chars.peek().is_some_and(|(_, next)| next.is_alphanumeric()));
+        match (continues_word, start) {
+            (true, None) => start = Some(index),
+            (false, Some(begin)) => {
+                words.push(&text[begin..index]);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push(&text[begin..]);
+    }
+    words.retain(|word| !word.chars().any(char::is_numeric));
+    words
+}
+
+fn project_series_id(project_path: &str) -> Result<String, String> {
+    let conn = open_app_db()?;
+    conn.query_row(
+        "SELECT series_id FROM projects WHERE path = ?1",
+        [project_path],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Failed to resolve project series: {e}"))
+}
+
+fn codex_name_words(series_id: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    for entry in list_series_codex_entries(series_id.to_string(), None)? {
+        for name in std::iter::once(&entry.name).chain(entry.aliases.iter()) {
+            words.extend(tokenize_words(name).into_iter().map(str::to_string));
+        }
+    }
+    Ok(words)
+}
+
+/// Sorted, case-insensitively unique word list
+fn dedupe_words(words: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut unique: Vec<String> = words
+        .into_iter()
+        .filter(|word| seen.insert(normalize_apostrophes(word)))
+        .collect();
+    unique.sort_by_key(|word| word.to_lowercase());
+    unique
+}
+
+fn write_project_dictionary(project_path: &str, words: &[String]) -> Result<(), String> {
+    let path = project_dictionary_path(project_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create .meta directory: {e}"))?;
+    }
+    let mut content = words.join("\n");
+    content.push('\n');
+    atomic_write(&path, &content)
+}
+
+/// The project's custom words as stored, seeding the file from codex entry
+/// names and aliases the first time it is needed.
+fn load_project_dictionary(project_path: &str, series_id: &str) -> Result<Vec<String>, String> {
+    let path = project_dictionary_path(project_path);
+    if !path.exists() {
+        let seeded = dedupe_words(codex_name_words(series_id)?);
+        write_project_dictionary(project_path, &seeded)?;
+        return Ok(seeded);
+    }
+
+    let metadata =
+        fs::metadata(&path).map_err(|e| format!("Failed to read project dictionary: {e}"))?;
+    validate_file_size(metadata.len(), MAX_DICTIONARY_SIZE, "Project dictionary")?;
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read project dictionary: {e}"))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+fn validate_dictionary_word(word: &str) -> Result<String, String> {
+    validate_no_null_bytes(word, "Dictionary word")?;
+    let word = word.trim();
+    if word.is_empty() {
+        return Err("Dictionary word cannot be empty".to_string());
+    }
+    if word.chars().any(char::is_whitespace) {
+        return Err("Dictionary word cannot contain whitespace".to_string());
+    }
+    if word.chars().count() > MAX_DICTIONARY_WORD_LENGTH {
+        return Err(format!(
+            "Dictionary word exceeds {MAX_DICTIONARY_WORD_LENGTH} characters"
+        ));
+    }
+    Ok(word.to_string())
+}
+
+/// Lowercased words that are always allowed for this project: the custom
+/// dictionary plus every word of codex entry names and aliases.
+pub(crate) fn project_allowed_words(
+    project_path: &str,
+    series_id: &str,
+) -> Result<HashSet<String>, String> {
+    let custom = load_project_dictionary(project_path, series_id)?;
+    Ok(custom
+        .into_iter()
+        .chain(codex_name_words(series_id)?)
+        .map(|word| normalize_apostrophes(&word))
+        .collect())
+}
+
+#[tauri::command]
+pub fn list_dictionary_words(project_path: String) -> Result<Vec<String>, String> {
+    validate_project_path(&project_path)?;
+    let series_id = project_series_id(&project_path)?;
+    load_project_dictionary(&project_path, &series_id)
+}
+
+/// Adds a word to the project dictionary. Returns false if it was already
+/// there (compared case-insensitively).
+#[tauri::command]
+pub fn add_dictionary_word(project_path: String, word: String) -> Result<bool, String> {
+    validate_project_path(&project_path)?;
+    let word = validate_dictionary_word(&word)?;
+    let series_id = project_series_id(&project_path)?;
+    let mut words = load_project_dictionary(&project_path, &series_id)?;
+
+    let key = normalize_apostrophes(&word);
+    if words
+        .iter()
+        .any(|existing| normalize_apostrophes(existing) == key)
+    {
+        return Ok(false);
+    }
+    words.push(word);
+    write_project_dictionary(&project_path, &dedupe_words(words))?;
+    Ok(true)
+}
+
+/// Removes a word (case-insensitively). Returns whether it was present.
+#[tauri::command]
+pub fn remove_dictionary_word(project_path: String, word: String) -> Result<bool, String> {
+    validate_project_path(&project_path)?;
+    let word = validate_dictionary_word(&word)?;
+    let series_id = project_series_id(&project_path)?;
+    let mut words = load_project_dictionary(&project_path, &series_id)?;
+
+    let key = normalize_apostrophes(&word);
+    let before = words.len();
+    words.retain(|existing| normalize_apostrophes(existing) != key);
+    if words.len() == before {
+        return Ok(false);
+    }
+    write_project_dictionary(&project_path, &words)?;
+    Ok(true)
+}
+
+fn is_known(word: &str, dictionary: &HashSet<String>, allowed: &HashSet<String>) -> bool {
+    let normalized = normalize_apostrophes(word);
+    let base = normalized
+        .strip_suffix("'s")
+        .unwrap_or(&normalized)
+        .to_string();
+    [&normalized, &base]
+        .iter()
+        .any(|candidate| dictionary.contains(*candidate) || allowed.contains(*candidate))
+}
+
+/// Words in the scene that are in neither the dictionary file nor the
+/// project's allowed words, with how often each occurs. `dictionary`
+/// defaults to the bundled en_US dictionary.
+#[tauri::command]
+pub fn spellcheck_scene(
+    app: AppHandle,
+    project_path: String,
+    scene_id: String,
+    dictionary: Option<String>,
+) -> Result<SpellcheckResult, String> {
+    validate_project_path(&project_path)?;
+    let dictionary = match dictionary.filter(|path| !path.trim().is_empty()) {
+        Some(path) => {
+            validate_no_null_bytes(&path, "Dictionary path")?;
+            PathBuf::from(path)
+        }
+        None => bundled_dictionary_path(&app)?,
+    };
+    let conn = open_app_db()?;
+    let project_id = project_id_for_path(&conn, &project_path)?;
+    let series_id = project_series_id(&project_path)?;
+    let scene_file = resolve_scene_file(&conn, &project_id, &scene_id)?;
+
+    let dictionary_words = load_word_list(&dictionary)?;
+    let allowed = project_allowed_words(&project_path, &series_id)?;
+    let content = read_scene_content(&scene_file_path(&project_path, &scene_file))?;
+    let text = extract_plain_text(&content);
+
+    let words = tokenize_words(&text);
+    let mut unknown: BTreeMap<String, usize> = BTreeMap::new();
+    for word in &words {
+        if !is_known(word, &dictionary_words, &allowed) {
+            *unknown.entry(word.to_string()).or_default() += 1;
+        }
+    }
+
+    Ok(SpellcheckResult {
+        scene_id,
+        checked_words: words.len(),
+        unknown_words: unknown
+            .into_iter()
+            .map(|(word, occurrences)| UnknownWord { word, occurrences })
+            .collect(),
+    })
+}
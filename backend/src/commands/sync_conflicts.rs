@@ -0,0 +1,108 @@
+// Cloud-sync conflict detection
+// Dropbox, OneDrive, Syncthing and iCloud keep both sides of a conflicting
+// edit by writing a renamed copy next to the original ("x (conflicted copy).md",
+// "x.sync-conflict-….md", "x 2.md"). Nothing merges these back, so the edits
+// in the copy are silently lost unless the user is told about them.
+
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+
+use crate::utils::validate_project_path;
+
+const CONFLICT_MARKERS: [&str; 3] = ["conflicted copy", "sync-conflict", "(conflict"];
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflictFile {
+    /// Relative to the project folder
+    pub path: String,
+    /// The file this looks like a copy of, when it exists
+    pub original_path: Option<String>,
+    /// "conflict-marker" or "duplicate-copy"
+    pub kind: String,
+    pub modified_at: Option<i64>,
+    pub original_modified_at: Option<i64>,
+    pub size_bytes: u64,
+}
+
+fn modified_millis(path: &Path) -> Option<i64> {
+    let modified = path.metadata().ok()?.modified().ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_millis() as i64)
+}
+
+/// For "name (2).ext" or "name 2.ext", the original "name.ext"
+fn duplicate_original_name(file_name: &str) -> Option<String> {
+    let (stem, extension) = match file_name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, Some(extension)),
+        _ => (file_name, None),
+    };
+    let base = if let Some(inner) = stem.strip_suffix(')') {
+        let (base, number) = inner.rsplit_once(" (")?;
+        number.parse::<u32>().ok()?;
+        base
+    } else {
+        let (base, number) = stem.rsplit_once(' ')?;
+        if !(2..=9).contains(&number.parse::<u32>().ok()?) {
+            return None;
+        }
+        base
+    };
+    Some(match extension {
+        Some(extension) => format!("{base}.{extension}"),
+        None => base.to_string(),
+    })
+}
+
+/// Files in the project folder that look like sync-service conflict copies.
+/// Numbered copies ("x 2.md") are only reported when the original exists.
+#[tauri::command]
+pub fn find_sync_conflicts(project_path: String) -> Result<Vec<SyncConflictFile>, String> {
+    validate_project_path(&project_path)?;
+    let root = Path::new(&project_path);
+    let mut conflicts = Vec::new();
+
+    let files = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file());
+    for entry in files {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let lower = file_name.to_lowercase();
+        let original = entry
+            .path()
+            .parent()
+            .zip(duplicate_original_name(&file_name))
+            .map(|(dir, name)| dir.join(name))
+            .filter(|original| original.is_file());
+
+        let kind = if CONFLICT_MARKERS.iter().any(|marker| lower.contains(marker)) {
+            "conflict-marker"
+        } else if original.is_some() {
+            "duplicate-copy"
+        } else {
+            continue;
+        };
+
+        let relative = |path: &Path| {
+            path.strip_prefix(root)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .to_string()
+        };
+        conflicts.push(SyncConflictFile {
+            path: relative(entry.path()),
+            original_path: original.as_deref().map(relative),
+            kind: kind.to_string(),
+            modified_at: modified_millis(entry.path()),
+            original_modified_at: original.as_deref().and_then(modified_millis),
+            size_bytes: entry.metadata().map(|metadata| metadata.len()).unwrap_or(0),
+        });
+    }
+
+    conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(conflicts)
+}
@@ -13,14 +13,27 @@ pub mod utils;
 
 use commands::*;
 use std::env;
+use tauri::Manager;
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
 
+/// App identity plus diagnostics for support requests. Storage fields are
+/// null when the data directory or database can't be read.
 #[tauri::command]
 fn get_app_info() -> serde_json::Value {
+    let storage = get_storage_info().ok();
     serde_json::json!({
         "name": "Become An Author",
         "version": env!("CARGO_PKG_VERSION"),
         "platform": std::env::consts::OS,
-        "arch": std::env::consts::ARCH
+        "arch": std::env::consts::ARCH,
+        "tauriVersion": tauri::VERSION,
+        "appDataDir": storage.as_ref().map(|info| info.app_dir.clone()),
+        "projectCount": storage.as_ref().map(|info| info.project_count),
+        "seriesCount": storage.as_ref().map(|info| info.series_count),
+        "availableDiskBytes": storage.as_ref().and_then(|info| info.available_bytes),
+        // Secrets are AES-GCM encrypted in the app database; no OS keychain is used
+        "keychainAvailable": false,
+        "secretStorage": "encrypted-sqlite"
     })
 }
 
@@ -36,7 +49,15 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(
             tauri_plugin_log::Builder::default()
-                .level(log::LevelFilter::Info)
+                .clear_targets()
+                .target(Target::new(TargetKind::Stdout))
+                .target(Target::new(TargetKind::LogDir {
+                    file_name: Some(LOG_FILE_NAME.to_string()),
+                }))
+                .max_file_size(MAX_LOG_FILE_SIZE)
+                .rotation_strategy(RotationStrategy::KeepSome(KEEP_LOG_FILES))
+                // Everything reaches the logger; `set_log_level` filters at runtime
+                .level(log::LevelFilter::Trace)
                 // Silence noisy windowing library trace logs
                 .level_for("tao", log::LevelFilter::Warn)
                 .level_for("wry", log::LevelFilter::Warn)
@@ -48,6 +69,23 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(updater_builder.build())
         .plugin(tauri_plugin_process::init())
+        .manage(AutoBackupScheduler::default())
+        .manage(SceneSaveQueue::default())
+        .manage(ExportJobs::default())
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event {
+                let app = window.app_handle();
+                if app.webview_windows().len() <= 1 {
+                    run_shutdown(&app.state::<SceneSaveQueue>());
+                }
+            }
+        })
+        .setup(|app| {
+            restore_log_level();
+            allow_codex_image_assets(app.handle());
+            resume_auto_backups(app.handle());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Project commands
             get_projects_path,
@@ -55,29 +93,76 @@ pub fn run() {
             list_recent_projects,
             add_to_recent,
             remove_from_recent,
+            rebuild_recent_projects,
             open_project,
+            release_project_lock,
+            override_project_lock,
+            find_sync_conflicts,
             create_project,
             delete_project,
             list_project_trash,
             restore_trashed_project,
             permanently_delete_trashed_project,
             update_project,
+            move_project_to_series,
+            validate_series_index,
             archive_project,
             get_structure,
+            get_structure_with_counts,
             save_structure,
+            apply_structure_operations,
             create_node,
             rename_node,
+            set_scene_file_naming,
+            rename_scene_file,
+            renumber_chapters,
+            normalize_structure_order,
             delete_node,
+            get_linear_scenes,
+            list_pov_characters,
+            get_scenes_by_pov,
             // Scene commands
             load_scene,
             save_scene,
             update_scene_metadata,
+            update_scene_meta,
             save_scene_by_id,
+            save_scene_debounced,
+            flush_scene_saves,
+            list_scene_templates,
+            save_scene_template,
+            delete_scene_template,
+            normalize_scene_typography,
+            normalize_scene_encoding,
+            count_words_for_scenes,
+            audit_word_counts,
+            recount_word_counts,
+            count_text_words,
+            count_text_stats,
+            spellcheck_scene,
+            list_dictionary_words,
+            add_dictionary_word,
+            remove_dictionary_word,
             delete_scene,
             // Codex commands
             list_codex_entries,
             save_codex_entry,
             delete_codex_entry,
+            delete_codex_entries,
+            rename_codex_entry,
+            find_duplicate_codex,
+            search_codex_attributes,
+            get_codex_entries_by_ids,
+            list_codex_summaries,
+            list_codex_entries_since,
+            get_codex_last_modified,
+            export_codex_graph,
+            export_relation_matrix,
+            gc_codex_images,
+            validate_codex_images,
+            reorder_codex_gallery,
+            set_primary_codex_image,
+            merge_codex_entries,
             // Snippet commands
             list_snippets,
             save_snippet,
@@ -111,12 +196,31 @@ pub fn run() {
             delete_codex_relation_type,
             list_scene_codex_links,
             save_scene_codex_link,
+            list_link_roles,
+            set_link_role_vocabulary,
+            get_codex_categories,
+            set_codex_categories,
             delete_scene_codex_link,
+            scenes_without_codex_links,
+            check_name_collisions,
             // Emergency backup commands
             save_emergency_backup,
+            save_emergency_backup_with_ttl,
             get_emergency_backup,
+            list_recoverable_backups,
             delete_emergency_backup,
             cleanup_emergency_backups,
+            // Progress commands
+            get_progress_chart_data,
+            set_project_word_target,
+            get_completion_estimate,
+            // Prose analysis commands
+            get_stopwords,
+            set_custom_stopwords,
+            analyze_prose,
+            find_echoes,
+            analyze_sentence_rhythm,
+            get_saved_analysis,
             // Search
             search_project,
             // Export commands
@@ -124,10 +228,27 @@ pub fn run() {
             export_series_package,
             export_novel_package,
             inspect_backup_package,
+            inspect_backup,
+            verify_backup,
             import_backup_package,
+            import_backups,
             read_file_bytes,
             write_temp_backup_file,
             write_export_file,
+            export_manuscript_text,
+            export_manuscript_to_file,
+            export_chapter_wordcounts_csv,
+            start_manuscript_export,
+            cancel_export,
+            export_chapter_pdf,
+            export_scene,
+            export_selected_scenes,
+            export_outline,
+            get_beat_sheet,
+            export_to_scrivener,
+            import_from_scrivener,
+            import_manuscript_file,
+            export_series_bible,
             // Series commands
             list_series,
             list_deleted_series,
@@ -136,15 +257,19 @@ pub fn run() {
             update_series,
             delete_series,
             delete_series_cascade,
+            is_series_empty,
+            list_empty_series,
             restore_deleted_series,
             // Series Codex commands
             list_series_codex_entries,
+            list_series_codex_entries_verbose,
             get_series_codex_entry,
             save_series_codex_entry,
             delete_series_codex_entry,
             list_series_codex_relations,
             save_series_codex_relation,
             delete_series_codex_relation,
+            repair_series_codex_relations,
             // Security commands
             security::store_api_key,
             security::get_api_key,
@@ -166,9 +291,16 @@ pub fn run() {
             google_oauth_get_access_token,
             google_oauth_get_user,
             google_oauth_sign_out,
+            upload_backup_to_drive,
+            list_drive_backups,
+            restore_from_drive,
+            enable_auto_backup,
+            disable_auto_backup,
             // Mention tracking commands
             find_mentions,
             count_mentions,
+            search_scene_with_entities,
+            scan_mentions,
             // Collaboration commands (Yjs state persistence)
             save_yjs_state,
             load_yjs_state,
@@ -178,9 +310,26 @@ pub fn run() {
             get_scene_note,
             save_scene_note,
             delete_scene_note,
+            list_scene_notes,
+            // Maintenance commands
+            normalize_project_storage,
+            get_storage_info,
+            get_data_root,
+            set_data_root,
+            migrate_data_root,
             // App info
             get_app_info,
+            get_log_path,
+            export_logs,
+            set_log_level,
+            shutdown,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                // Don't lose autosaves still waiting out their debounce window
+                run_shutdown(&app.state::<SceneSaveQueue>());
+            }
+        });
 }
@@ -9,4 +9,10 @@ pub struct EmergencyBackup {
     pub timestamp: i64,
     #[serde(rename = "expiresAt")]
     pub expires_at: i64,
+    #[serde(
+        rename = "contentHash",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub content_hash: Option<String>,
 }
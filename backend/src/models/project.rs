@@ -59,6 +59,9 @@ pub struct Series {
     pub genre: Option<String>,
     #[serde(default)]
     pub status: Option<String>,
+    /// Cover image as base64 data URL
+    #[serde(default, rename = "coverImage", alias = "cover_image")]
+    pub cover_image: Option<String>,
     #[serde(rename = "createdAt")]
     pub created_at: i64,
     #[serde(rename = "updatedAt")]
@@ -34,6 +34,19 @@ pub struct SceneMeta {
     pub updated_at: i64,
 }
 
+/// Reusable starting body for new scenes (e.g. a beat sheet)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SceneTemplate {
+    pub id: String,
+    pub name: String,
+    /// Editor JSON document, same format as scene files
+    pub content: String,
+    #[serde(default, rename = "createdAt")]
+    pub created_at: i64,
+    #[serde(default, rename = "updatedAt")]
+    pub updated_at: i64,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Scene {
     #[serde(flatten)]
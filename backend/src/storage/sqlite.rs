@@ -5,7 +5,12 @@ use std::time::Duration;
 
 use crate::utils::get_app_dir;
 
-const SCHEMA_VERSION: i64 = 3;
+const SCHEMA_VERSION: i64 = 4;
+
+/// Oldest schema a backup package may carry. Version 4 only moved rows out of
+/// app_preferences, which packages never include, so version 3 packages still
+/// restore.
+pub const MIN_PACKAGE_SCHEMA_VERSION: i64 = 3;
 
 fn app_database_path() -> Result<PathBuf, String> {
     let app_dir = get_app_dir()?;
@@ -78,7 +83,8 @@ fn initialize_schema(conn: &Connection) -> Result<(), String> {
             genre TEXT,
             status TEXT,
             created_at INTEGER NOT NULL,
-            updated_at INTEGER NOT NULL
+            updated_at INTEGER NOT NULL,
+            cover_image TEXT
         );
 
         CREATE TABLE IF NOT EXISTS deleted_series_registry (
@@ -371,10 +377,49 @@ fn initialize_schema(conn: &Connection) -> Result<(), String> {
 
         CREATE INDEX IF NOT EXISTS idx_yjs_update_log_project_scene_seq
             ON yjs_update_log(project_path, scene_id, seq);
+
+        CREATE TABLE IF NOT EXISTS writing_progress (
+            project_id TEXT NOT NULL,
+            day TEXT NOT NULL,
+            total_words INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (project_id, day)
+        );
+
+        CREATE TABLE IF NOT EXISTS series_settings (
+            series_id TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value_json TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (series_id, key)
+        );
+
+        CREATE TABLE IF NOT EXISTS project_settings (
+            project_id TEXT NOT NULL,
+            key TEXT NOT NULL,
+            value_json TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (project_id, key)
+        );
+
+        CREATE TABLE IF NOT EXISTS scene_templates (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            content TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
         "#,
     )
     .map_err(|e| format!("Failed to initialize SQLite schema: {e}"))?;
 
+    // Columns added after a table first shipped; CREATE TABLE IF NOT EXISTS
+    // leaves existing tables untouched.
+    ensure_column(conn, "series", "cover_image", "TEXT")?;
+    if user_version < 4 {
+        with_transaction(conn, move_scoped_preferences)?;
+    }
+
     conn.execute_batch(&format!(
         "PRAGMA user_version = {SCHEMA_VERSION}; PRAGMA optimize;"
     ))
@@ -382,6 +427,81 @@ fn initialize_schema(conn: &Connection) -> Result<(), String> {
     Ok(())
 }
 
+fn ensure_column(
+    conn: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let exists: bool = conn
+        .query_row(
+            &format!("SELECT EXISTS(SELECT 1 FROM pragma_table_info('{table}') WHERE name = ?1)"),
+            params![column],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to inspect {table} columns: {e}"))?;
+    if !exists {
+        conn.execute_batch(&format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"))
+            .map_err(|e| format!("Failed to add {table}.{column}: {e}"))?;
+    }
+    Ok(())
+}
+
+/// Keys of the series_settings and project_settings tables
+pub const SERIES_CODEX_CATEGORIES: &str = "codex_categories";
+pub const SERIES_LINK_ROLES: &str = "link_roles";
+pub const PROJECT_TARGET_WORDS: &str = "target_words";
+pub const PROJECT_SCENE_FILE_NAMING: &str = "scene_file_naming";
+
+/// Series and project settings that first shipped as `series.<id>.<key>` /
+/// `project.<id>.<key>` app preferences. Backups drop app_preferences and
+/// imports remap ids, so these live in their own scoped tables instead.
+const SCOPED_PREFERENCES: [(&str, &str, &str, &str); 4] = [
+    (
+        "series_settings",
+        "series_id",
+        "series.",
+        SERIES_CODEX_CATEGORIES,
+    ),
+    ("series_settings", "series_id", "series.", SERIES_LINK_ROLES),
+    (
+        "project_settings",
+        "project_id",
+        "project.",
+        PROJECT_TARGET_WORDS,
+    ),
+    (
+        "project_settings",
+        "project_id",
+        "project.",
+        PROJECT_SCENE_FILE_NAMING,
+    ),
+];
+
+fn move_scoped_preferences(conn: &Connection) -> Result<(), String> {
+    for (table, scope_column, prefix, key) in SCOPED_PREFERENCES {
+        let suffix = format!(".{key}");
+        let matches_key = "substr(key, 1, length(?1)) = ?1 AND substr(key, -length(?2)) = ?2 \
+                           AND length(key) > length(?1) + length(?2)";
+        conn.execute(
+            &format!(
+                "INSERT OR IGNORE INTO {table}({scope_column}, key, value_json, updated_at)
+                 SELECT substr(key, length(?1) + 1, length(key) - length(?1) - length(?2)),
+                        ?3, value_json, updated_at
+                 FROM app_preferences WHERE {matches_key}"
+            ),
+            params![prefix, suffix, key],
+        )
+        .map_err(|e| format!("Failed to move {key} preferences into {table}: {e}"))?;
+        conn.execute(
+            &format!("DELETE FROM app_preferences WHERE {matches_key}"),
+            params![prefix, suffix],
+        )
+        .map_err(|e| format!("Failed to clear moved {key} preferences: {e}"))?;
+    }
+    Ok(())
+}
+
 pub fn open_app_db() -> Result<Connection, String> {
     let db_path = app_database_path()?;
     let conn = Connection::open(&db_path)
@@ -423,6 +543,8 @@ pub fn run_migrations(conn: &Connection) -> Result<(), String> {
     }
     // Version 1 → 2: handled by CREATE TABLE IF NOT EXISTS (initial schema)
     // Version 2 → 3: no schema changes; bump marks migration system adoption
+    // Version 3 → 4: scoped app_preferences moved into series_settings and
+    // project_settings by initialize_schema
     conn.execute_batch(&format!("PRAGMA user_version = {}", SCHEMA_VERSION))
         .map_err(|e| e.to_string())?;
     Ok(())
@@ -740,6 +862,98 @@ pub fn app_pref_delete(conn: &Connection, key: &str) -> Result<(), String> {
     Ok(())
 }
 
+pub fn series_setting_get(
+    conn: &Connection,
+    series_id: &str,
+    key: &str,
+) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT value_json FROM series_settings WHERE series_id = ?1 AND key = ?2",
+        params![series_id, key],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .map_err(|e| format!("Failed to read series setting: {e}"))
+}
+
+pub fn series_setting_set(
+    conn: &Connection,
+    series_id: &str,
+    key: &str,
+    value_json: &str,
+    updated_at: i64,
+) -> Result<(), String> {
+    conn.execute(
+        r#"
+        INSERT INTO series_settings(series_id, key, value_json, updated_at)
+        VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT(series_id, key) DO UPDATE SET
+            value_json = excluded.value_json,
+            updated_at = excluded.updated_at
+        "#,
+        params![series_id, key, value_json, updated_at],
+    )
+    .map_err(|e| format!("Failed to write series setting: {e}"))?;
+    Ok(())
+}
+
+pub fn series_setting_delete(conn: &Connection, series_id: &str, key: &str) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM series_settings WHERE series_id = ?1 AND key = ?2",
+        params![series_id, key],
+    )
+    .map_err(|e| format!("Failed to delete series setting: {e}"))?;
+    Ok(())
+}
+
+pub fn project_setting_get(
+    conn: &Connection,
+    project_id: &str,
+    key: &str,
+) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT value_json FROM project_settings WHERE project_id = ?1 AND key = ?2",
+        params![project_id, key],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .map_err(|e| format!("Failed to read project setting: {e}"))
+}
+
+pub fn project_setting_set(
+    conn: &Connection,
+    project_id: &str,
+    key: &str,
+    value_json: &str,
+    updated_at: i64,
+) -> Result<(), String> {
+    conn.execute(
+        r#"
+        INSERT INTO project_settings(project_id, key, value_json, updated_at)
+        VALUES (?1, ?2, ?3, ?4)
+        ON CONFLICT(project_id, key) DO UPDATE SET
+            value_json = excluded.value_json,
+            updated_at = excluded.updated_at
+        "#,
+        params![project_id, key, value_json, updated_at],
+    )
+    .map_err(|e| format!("Failed to write project setting: {e}"))?;
+    Ok(())
+}
+
+pub fn project_setting_delete(
+    conn: &Connection,
+    project_id: &str,
+    key: &str,
+) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM project_settings WHERE project_id = ?1 AND key = ?2",
+        params![project_id, key],
+    )
+    .map_err(|e| format!("Failed to delete project setting: {e}"))?;
+    Ok(())
+}
+
 pub fn list_ai_connections(conn: &Connection) -> Result<Vec<AIConnectionRecord>, String> {
     let mut stmt = conn
         .prepare(
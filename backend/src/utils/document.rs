@@ -0,0 +1,140 @@
+// Simple document model rendered to Markdown or a minimal DOCX package
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::xml::escape_xml;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DocBlock {
+    /// Heading level 1-3
+    Heading(u8, String),
+    Paragraph(String),
+    Bullet(String),
+}
+
+pub fn render_markdown(blocks: &[DocBlock]) -> String {
+    let mut out = String::new();
+    let mut previous_bullet = false;
+    for block in blocks {
+        let is_bullet = matches!(block, DocBlock::Bullet(_));
+        if !out.is_empty() {
+            out.push_str(if is_bullet && previous_bullet {
+                "\n"
+            } else {
+                "\n\n"
+            });
+        }
+        match block {
+            DocBlock::Heading(level, text) => {
+                out.push_str(&"#".repeat((*level).clamp(1, 6) as usize));
+                out.push(' ');
+                out.push_str(text);
+            }
+            DocBlock::Paragraph(text) => out.push_str(text),
+            DocBlock::Bullet(text) => {
+                out.push_str("- ");
+                out.push_str(text);
+            }
+        }
+        previous_bullet = is_bullet;
+    }
+    out.push('\n');
+    out
+}
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+</Types>"#;
+
+const ROOT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#;
+
+const DOCUMENT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>"#;
+
+const STYLES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:pPr><w:spacing w:after="120"/></w:pPr><w:rPr><w:sz w:val="24"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="360" w:after="120"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="36"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="120"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="30"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading3"><w:name w:val="heading 3"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="80"/><w:outlineLvl w:val="2"/></w:pPr><w:rPr><w:b/><w:sz w:val="26"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="ListBullet"><w:name w:val="List Bullet"/><w:basedOn w:val="Normal"/><w:pPr><w:ind w:left="360" w:hanging="360"/><w:spacing w:after="40"/></w:pPr></w:style>
+</w:styles>"#;
+
+fn docx_paragraph(style: Option<&str>, text: &str) -> String {
+    let props = style
+        .map(|style| format!("<w:pPr><w:pStyle w:val=\"{style}\"/></w:pPr>"))
+        .unwrap_or_default();
+    let runs = text
+        .split('\n')
+        .map(|line| format!("<w:t xml:space=\"preserve\">{}</w:t>", escape_xml(line)))
+        .collect::<Vec<_>>()
+        .join("<w:br/>");
+    format!("<w:p>{props}<w:r>{runs}</w:r></w:p>")
+}
+
+pub fn render_docx_document_xml(blocks: &[DocBlock]) -> String {
+    let mut body = String::new();
+    for block in blocks {
+        let paragraph = match block {
+            DocBlock::Heading(level, text) => {
+                let style = format!("Heading{}", (*level).clamp(1, 3));
+                docx_paragraph(Some(&style), text)
+            }
+            DocBlock::Paragraph(text) => docx_paragraph(None, text),
+            DocBlock::Bullet(text) => {
+                docx_paragraph(Some("ListBullet"), &format!("\u{2022}\t{text}"))
+            }
+        };
+        body.push_str(&paragraph);
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+         <w:document xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\">\
+         <w:body>{body}<w:sectPr><w:pgSz w:w=\"12240\" w:h=\"15840\"/>\
+         <w:pgMar w:top=\"1440\" w:right=\"1440\" w:bottom=\"1440\" w:left=\"1440\"/></w:sectPr>\
+         </w:body></w:document>"
+    )
+}
+
+/// Writes `blocks` as a .docx file (no numbering or images, just styled paragraphs).
+pub fn write_docx(path: &Path, blocks: &[DocBlock]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let file = File::create(path)
+        .map_err(|e| format!("Failed to create DOCX '{}': {e}", path.display()))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let document_xml = render_docx_document_xml(blocks);
+    let parts = [
+        ("[Content_Types].xml", CONTENT_TYPES_XML),
+        ("_rels/.rels", ROOT_RELS_XML),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS_XML),
+        ("word/styles.xml", STYLES_XML),
+        ("word/document.xml", document_xml.as_str()),
+    ];
+    for (name, content) in parts {
+        zip.start_file(name, options)
+            .map_err(|e| format!("Failed adding '{name}' to DOCX: {e}"))?;
+        zip.write_all(content.as_bytes())
+            .map_err(|e| format!("Failed writing '{name}' to DOCX: {e}"))?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize DOCX: {e}"))?;
+    Ok(())
+}
@@ -0,0 +1,322 @@
+// Hunspell dictionary loading
+// Expands `.dic` stems through the PFX/SFX rules of the matching `.aff` file
+// into the full set of accepted word forms. Compounding, suggestion tables and
+// affix continuation classes are not supported; spell-check only needs the
+// forms a stem can take.
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FlagMode {
+    /// One character per flag (the Hunspell default)
+    Char,
+    /// Two characters per flag (`FLAG long`)
+    Long,
+    /// Comma-separated decimal numbers (`FLAG num`)
+    Num,
+}
+
+fn parse_flags(raw: &str, mode: FlagMode) -> Vec<String> {
+    match mode {
+        FlagMode::Char => raw.chars().map(String::from).collect(),
+        FlagMode::Long => raw
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|pair| pair.iter().collect())
+            .collect(),
+        FlagMode::Num => raw
+            .split(',')
+            .map(str::trim)
+            .filter(|flag| !flag.is_empty())
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ConditionChar {
+    Any,
+    Literal(char),
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl ConditionChar {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            ConditionChar::Any => true,
+            ConditionChar::Literal(expected) => *expected == c,
+            ConditionChar::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+/// Parses an affix condition such as `[^aeiou]y`; `.` matches anything.
+fn parse_condition(raw: &str) -> Vec<ConditionChar> {
+    let mut condition = Vec::new();
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => condition.push(ConditionChar::Any),
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                for (index, inner) in chars.by_ref().enumerate() {
+                    match inner {
+                        ']' => break,
+                        '^' if index == 0 => negated = true,
+                        other => set.push(other),
+                    }
+                }
+                condition.push(ConditionChar::Set {
+                    chars: set,
+                    negated,
+                });
+            }
+            other => condition.push(ConditionChar::Literal(other)),
+        }
+    }
+    condition
+}
+
+#[derive(Clone, Debug)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    condition: Vec<ConditionChar>,
+}
+
+#[derive(Clone, Debug)]
+struct AffixClass {
+    is_prefix: bool,
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+impl AffixRule {
+    fn apply_suffix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < self.condition.len() || !word.ends_with(&self.strip) {
+            return None;
+        }
+        let tail = &chars[chars.len() - self.condition.len()..];
+        if !self
+            .condition
+            .iter()
+            .zip(tail)
+            .all(|(cond, c)| cond.matches(*c))
+        {
+            return None;
+        }
+        let stem = &word[..word.len() - self.strip.len()];
+        let form = format!("{stem}{}", self.add);
+        (!form.is_empty()).then_some(form)
+    }
+
+    fn apply_prefix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < self.condition.len() || !word.starts_with(&self.strip) {
+            return None;
+        }
+        if !self
+            .condition
+            .iter()
+            .zip(&chars)
+            .all(|(cond, c)| cond.matches(*c))
+        {
+            return None;
+        }
+        let form = format!("{}{}", self.add, &word[self.strip.len()..]);
+        (!form.is_empty()).then_some(form)
+    }
+}
+
+/// The affix classes of a Hunspell `.aff` file, keyed by flag
+#[derive(Clone, Debug)]
+pub struct AffixRules {
+    mode: FlagMode,
+    classes: HashMap<String, AffixClass>,
+}
+
+/// Reads PFX/SFX classes and the FLAG type from `.aff` content. Lines the
+/// expander doesn't use (TRY, REP, compounding options...) are skipped.
+pub fn parse_affix_rules(content: &str) -> AffixRules {
+    let mut rules = AffixRules {
+        mode: FlagMode::Char,
+        classes: HashMap::new(),
+    };
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("");
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            ["FLAG", "long", ..] => rules.mode = FlagMode::Long,
+            ["FLAG", "num", ..] => rules.mode = FlagMode::Num,
+            [kind @ ("PFX" | "SFX"), flag, cross, count] if count.parse::<usize>().is_ok() => {
+                rules.classes.insert(
+                    flag.to_string(),
+                    AffixClass {
+                        is_prefix: *kind == "PFX",
+                        cross_product: *cross == "Y",
+                        rules: Vec::new(),
+                    },
+                );
+            }
+            [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                let Some(class) = rules.classes.get_mut(*flag) else {
+                    continue;
+                };
+                if class.is_prefix != (*kind == "PFX") {
+                    continue;
+                }
+                let zero = |value: &str| {
+                    if value == "0" {
+                        String::new()
+                    } else {
+                        value.to_string()
+                    }
+                };
+                // `add/FLAGS` continuation classes are ignored
+                let add = add.split('/').next().unwrap_or("");
+                class.rules.push(AffixRule {
+                    strip: zero(strip),
+                    add: zero(add),
+                    condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                });
+            }
+            _ => {}
+        }
+    }
+    rules
+}
+
+impl AffixRules {
+    /// Every form of `stem` allowed by `flags`: the stem itself, each prefix
+    /// and suffix form, and prefix+suffix forms when both classes allow
+    /// cross products.
+    pub fn expand(&self, stem: &str, flags: &str) -> Vec<String> {
+        let mut forms = vec![stem.to_string()];
+        let classes: Vec<&AffixClass> = parse_flags(flags, self.mode)
+            .iter()
+            .filter_map(|flag| self.classes.get(flag))
+            .collect();
+
+        let mut cross_suffixed = Vec::new();
+        for class in classes.iter().filter(|class| !class.is_prefix) {
+            for rule in &class.rules {
+                if let Some(form) = rule.apply_suffix(stem) {
+                    if class.cross_product {
+                        cross_suffixed.push(form.clone());
+                    }
+                    forms.push(form);
+                }
+            }
+        }
+        for class in classes.iter().filter(|class| class.is_prefix) {
+            for rule in &class.rules {
+                if let Some(form) = rule.apply_prefix(stem) {
+                    forms.push(form);
+                }
+                if class.cross_product {
+                    forms.extend(
+                        cross_suffixed
+                            .iter()
+                            .filter_map(|suffixed| rule.apply_prefix(suffixed)),
+                    );
+                }
+            }
+        }
+        forms
+    }
+}
+
+/// Lowercased word forms of a Hunspell `.dic` file, expanded through `affixes`
+/// when given. Skips the leading entry count, comments and morphological
+/// fields after the flags.
+pub fn expand_dictionary(dic: &str, affixes: Option<&AffixRules>) -> HashSet<String> {
+    let mut words = HashSet::new();
+    for (index, line) in dic.lines().enumerate() {
+        let entry = line.split(['\t', ' ']).next().unwrap_or("").trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+        if index == 0 && entry.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        let (stem, flags) = entry.split_once('/').unwrap_or((entry, ""));
+        if stem.is_empty() {
+            continue;
+        }
+        match affixes {
+            Some(affixes) if !flags.is_empty() => {
+                words.extend(
+                    affixes
+                        .expand(stem, flags)
+                        .into_iter()
+                        .map(|form| form.to_lowercase()),
+                );
+            }
+            _ => {
+                words.insert(stem.to_lowercase());
+            }
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "\
+SET UTF-8
+PFX U Y 1
+PFX U   0     un         .
+
+SFX S Y 4
+SFX S   y     ies        [^aeiou]y
+SFX S   0     s          [aeiou]y
+SFX S   0     es         [sxzh]
+SFX S   0     s          [^sxzhy]
+
+SFX D Y 4
+SFX D   0     d          e
+SFX D   y     ied        [^aeiou]y
+SFX D   0     ed         [^ey]
+SFX D   0     ed         [aeiou]y
+
+SFX M Y 1
+SFX M   0     's         .
+";
+
+    #[test]
+    fn expands_suffix_and_prefix_rules() {
+        let affixes = parse_affix_rules(AFF);
+        let words = expand_dictionary("4\ncarry/SDU\nbox/SM\nplay/SD\nwaltz\n", Some(&affixes));
+        for expected in [
+            "carry",
+            "carries",
+            "carried",
+            "uncarry",
+            "uncarried",
+            "box",
+            "boxes",
+            "box's",
+            "plays",
+            "played",
+            "waltz",
+        ] {
+            assert!(words.contains(expected), "missing {expected}");
+        }
+        assert!(!words.contains("carrys"));
+        assert!(!words.contains("plaied"));
+        assert!(!words.contains("4"));
+    }
+
+    #[test]
+    fn reads_long_and_numeric_flags() {
+        let long = parse_affix_rules("FLAG long\nSFX Aa Y 1\nSFX Aa 0 ing .\n");
+        assert!(long.expand("sing", "AaBb").contains(&"singing".to_string()));
+        let num = parse_affix_rules("FLAG num\nSFX 12 Y 1\nSFX 12 0 er .\n");
+        assert!(num.expand("sing", "7,12").contains(&"singer".to_string()));
+    }
+}
@@ -5,6 +5,8 @@ use std::io::Write;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::text::decode_text;
+
 fn now_nanos() -> u128 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -12,6 +14,12 @@ fn now_nanos() -> u128 {
         .unwrap_or(0)
 }
 
+/// Reads a text file, decoding it with [`decode_text`]
+pub fn read_text_file(path: &Path) -> Result<String, String> {
+    let bytes = fs::read(path).map_err(|e| e.to_string())?;
+    decode_text(&bytes)
+}
+
 /// Atomic text write with durability best-effort. See [`atomic_write_bytes`].
 pub fn atomic_write(path: &Path, content: &str) -> Result<(), String> {
     atomic_write_bytes(path, content.as_bytes())
@@ -0,0 +1,25 @@
+// Size and length limits shared by validation and the commands that enforce them
+
+/// Maximum length for project titles
+pub const MAX_PROJECT_TITLE_LENGTH: usize = 200;
+
+/// Maximum file size for scenes (10 MB)
+pub const MAX_SCENE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Maximum JSON payload size (5 MB)
+pub const MAX_JSON_SIZE: usize = 5 * 1024 * 1024;
+
+/// Maximum size of a .md/.docx manuscript accepted by the importer (100 MB)
+pub const MAX_MANUSCRIPT_IMPORT_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Maximum size of one RTF document (or the binder XML) read from an
+/// imported Scrivener package (50 MB)
+pub const MAX_SCRIVENER_DOCUMENT_SIZE: u64 = 50 * 1024 * 1024;
+
+/// Upper bound on the size the renderer may slurp through `read_file_bytes`.
+/// Generous enough for full-snapshot `.baa` packages while bounding memory use
+/// and rejecting attempts to read arbitrarily large files.
+pub const MAX_READ_FILE_BYTES: u64 = 4 * 1024 * 1024 * 1024; // 4 GiB
+
+/// Maximum size of a spell-check word list or Hunspell `.dic` file (50 MB)
+pub const MAX_DICTIONARY_SIZE: u64 = 50 * 1024 * 1024;
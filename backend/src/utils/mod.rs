@@ -1,12 +1,26 @@
 // Utility modules
 
+pub mod document;
+pub mod hunspell;
 pub mod io;
+pub mod limits;
+pub mod path_sanitization;
 pub mod paths;
+pub mod pdf;
 pub mod text;
 pub mod timestamp;
+pub mod truetype;
 pub mod validation;
+pub mod xml;
 
+pub use document::*;
+pub use hunspell::*;
 pub use io::*;
+pub use limits::*;
+pub use path_sanitization::*;
 pub use paths::*;
+pub use pdf::*;
 pub use text::*;
+pub use truetype::*;
 pub use validation::*;
+pub use xml::*;
@@ -0,0 +1,87 @@
+// Path sanitization for user-supplied file names and project paths
+// Rejects traversal instead of silently rewriting it
+
+use std::path::{Component, Path, PathBuf};
+
+use super::validation::validate_no_null_bytes;
+
+/// Validate a single path component (a file or folder name) supplied by the UI
+///
+/// # Rules
+/// - Not empty after trimming
+/// - No null bytes
+/// - Not `.` or `..`
+/// - No path separators (/, \) or drive prefixes, so it cannot be absolute
+///
+/// Returns the trimmed component.
+pub fn sanitize_path_component(component: &str) -> Result<String, String> {
+    let trimmed = component.trim();
+    if trimmed.is_empty() {
+        return Err("Path component cannot be empty".to_string());
+    }
+    validate_no_null_bytes(trimmed, "Path component")?;
+
+    if trimmed == "." || trimmed == ".." {
+        return Err("Path component cannot be '.' or '..'".to_string());
+    }
+    if trimmed.contains('/') || trimmed.contains('\\') {
+        return Err("Path component cannot contain path separators (/ or \\)".to_string());
+    }
+    if Path::new(trimmed).is_absolute() || trimmed.contains(':') {
+        return Err("Path component must be relative".to_string());
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// Validate a project directory path passed in from the UI
+///
+/// # Rules
+/// - No null bytes
+/// - Absolute
+/// - No `..` components
+pub fn validate_project_path(project_path: &str) -> Result<PathBuf, String> {
+    validate_no_null_bytes(project_path, "Project path")?;
+    let path = Path::new(project_path.trim());
+    if !path.is_absolute() {
+        return Err("Project path must be absolute".to_string());
+    }
+    if path
+        .components()
+        .any(|component| matches!(component, Component::ParentDir))
+    {
+        return Err("Project path cannot contain '..'".to_string());
+    }
+    Ok(path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_path_component_valid() {
+        assert_eq!(sanitize_path_component("scene.md").unwrap(), "scene.md");
+        assert_eq!(sanitize_path_component("  notes  ").unwrap(), "notes");
+    }
+
+    #[test]
+    fn test_sanitize_path_component_rejects_traversal() {
+        assert!(sanitize_path_component("..").is_err());
+        assert!(sanitize_path_component(".").is_err());
+        assert!(sanitize_path_component("../escape.md").is_err());
+        assert!(sanitize_path_component("dir/file.md").is_err());
+        assert!(sanitize_path_component("dir\\file.md").is_err());
+        assert!(sanitize_path_component("/etc/passwd").is_err());
+        assert!(sanitize_path_component("C:evil").is_err());
+        assert!(sanitize_path_component("").is_err());
+    }
+
+    #[test]
+    fn test_validate_project_path() {
+        assert!(validate_project_path("/home/user/BecomeAnAuthor/Projects/novel").is_ok());
+        assert!(validate_project_path("relative/novel").is_err());
+        assert!(validate_project_path("/home/user/../../etc").is_err());
+        assert!(validate_project_path("/home/user/no\0vel").is_err());
+    }
+}
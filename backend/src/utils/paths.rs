@@ -1,7 +1,11 @@
 // Path utilities
 
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use super::validation::validate_no_null_bytes;
 
 const APP_DATA_DIR_NAME: &str = "BecomeAnAuthor";
 const DEV_DATA_CHANNEL: &str = "dev";
@@ -35,9 +39,133 @@ fn app_data_root_dir() -> Result<PathBuf, String> {
     Ok(home.join(APP_DATA_DIR_NAME))
 }
 
+/// Resolves an exporter's `output_path`. Absolute paths are used as given;
+/// relative ones land in the project's `exports` folder and may not climb out
+/// of it. Without a project, relative paths are rejected instead of being
+/// resolved against the process working directory. Missing parent
+/// directories are created.
+pub fn resolve_export_path(
+    project_path: Option<&str>,
+    output_path: &str,
+) -> Result<PathBuf, String> {
+    let exports_dir = project_path.map(|path| Path::new(path).join("exports"));
+    resolve_output_path(exports_dir.as_deref(), output_path)
+}
+
+/// [`resolve_export_path`] with relative paths landing in `base_dir`, for
+/// output that doesn't belong to a project (e.g. the backups folder).
+pub fn resolve_output_path(base_dir: Option<&Path>, output_path: &str) -> Result<PathBuf, String> {
+    validate_no_null_bytes(output_path, "Output path")?;
+    let requested = Path::new(output_path.trim());
+    if requested.as_os_str().is_empty() {
+        return Err("Output path is required".to_string());
+    }
+
+    let target = if requested.is_absolute() {
+        requested.to_path_buf()
+    } else {
+        let base_dir =
+            base_dir.ok_or_else(|| "Output path must be an absolute path".to_string())?;
+        if !requested
+            .components()
+            .all(|part| matches!(part, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(format!(
+                "Relative output paths must stay inside {}",
+                base_dir.display()
+            ));
+        }
+        base_dir.join(requested)
+    };
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create export directory: {e}"))?;
+    }
+    Ok(target)
+}
+
+pub const DATA_ROOT_CONFIG_FILE: &str = "data_root.json";
+
+/// The built-in app directory for the current channel. It always holds the
+/// data-root config, even after the data itself has been relocated.
+pub fn default_app_dir() -> Result<PathBuf, String> {
+    let app_dir = app_data_root_dir()?.join(data_channel_dir());
+    fs::create_dir_all(&app_dir).map_err(|e| e.to_string())?;
+    Ok(app_dir)
+}
+
+fn data_root_cache() -> &'static Mutex<HashMap<PathBuf, Option<PathBuf>>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, Option<PathBuf>>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn read_data_root_config(config_path: &Path) -> Option<PathBuf> {
+    if !config_path.exists() {
+        return None;
+    }
+    let config = fs::read_to_string(config_path)
+        .map_err(|e| format!("Failed to read data root config: {e}"))
+        .and_then(|raw| {
+            serde_json::from_str::<serde_json::Value>(&raw)
+                .map_err(|e| format!("Failed to parse data root config: {e}"))
+        });
+    match config {
+        Ok(config) => config
+            .get("dataRoot")
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from),
+        Err(error) => {
+            log::warn!("{error}; using the default data directory");
+            None
+        }
+    }
+}
+
+/// User-selected data directory, if one has been configured. The config is
+/// read once per channel; an unreadable config falls back to the default.
+pub fn configured_data_root() -> Result<Option<PathBuf>, String> {
+    let config_path = default_app_dir()?.join(DATA_ROOT_CONFIG_FILE);
+    let Ok(mut cache) = data_root_cache().lock() else {
+        return Ok(read_data_root_config(&config_path));
+    };
+    Ok(cache
+        .entry(config_path)
+        .or_insert_with_key(|path| read_data_root_config(path))
+        .clone())
+}
+
+/// Point the app at a different data directory (`None` restores the default)
+pub fn set_configured_data_root(data_root: Option<&Path>) -> Result<(), String> {
+    let config_path = default_app_dir()?.join(DATA_ROOT_CONFIG_FILE);
+    let result = match data_root {
+        Some(path) => {
+            let config = serde_json::json!({ "dataRoot": path.to_string_lossy() });
+            crate::utils::atomic_write(&config_path, &config.to_string())
+        }
+        None => {
+            if config_path.exists() {
+                fs::remove_file(&config_path)
+                    .map_err(|e| format!("Failed to remove data root config: {e}"))
+            } else {
+                Ok(())
+            }
+        }
+    };
+    if let Ok(mut cache) = data_root_cache().lock() {
+        cache.remove(&config_path);
+    }
+    result
+}
+
 /// Get the application root directory
 pub fn get_app_dir() -> Result<PathBuf, String> {
-    let app_dir = app_data_root_dir()?.join(data_channel_dir());
+    let app_dir = match configured_data_root()? {
+        Some(path) => path,
+        None => default_app_dir()?,
+    };
     fs::create_dir_all(&app_dir).map_err(|e| e.to_string())?;
     Ok(app_dir)
 }
@@ -57,3 +185,60 @@ pub fn project_dir(project_path: &str) -> Result<PathBuf, String> {
     Ok(projects_dir.join(project_path))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("baa-paths-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn absolute_output_paths_are_used_as_given() {
+        let project = temp_project();
+        let target = project.join("elsewhere").join("book.md");
+        let resolved =
+            resolve_export_path(None, target.to_str().unwrap()).expect("absolute path resolves");
+        assert_eq!(resolved, target);
+        assert!(project.join("elsewhere").is_dir());
+        let _ = fs::remove_dir_all(project);
+    }
+
+    #[test]
+    fn relative_output_paths_land_in_the_exports_folder() {
+        let project = temp_project();
+        let resolved = resolve_export_path(project.to_str(), "drafts/./book.md")
+            .expect("relative path resolves");
+        assert_eq!(resolved, project.join("exports").join("drafts/./book.md"));
+        assert!(project.join("exports").join("drafts").is_dir());
+        let _ = fs::remove_dir_all(project);
+    }
+
+    #[test]
+    fn relative_output_paths_may_not_climb_out() {
+        let project = temp_project();
+        assert!(resolve_export_path(project.to_str(), "../book.md").is_err());
+        assert!(resolve_export_path(project.to_str(), "drafts/../../book.md").is_err());
+        assert!(resolve_output_path(Some(&project), "../backup.baa").is_err());
+        assert!(!project.join("exports").exists());
+        let _ = fs::remove_dir_all(project);
+    }
+
+    #[test]
+    fn relative_output_paths_need_a_base_folder() {
+        assert!(resolve_export_path(None, "book.md").is_err());
+        assert!(resolve_output_path(None, "backup.baa").is_err());
+        assert!(resolve_export_path(None, "  ").is_err());
+    }
+
+    #[test]
+    fn output_paths_resolve_against_the_given_base() {
+        let backups = temp_project();
+        let resolved =
+            resolve_output_path(Some(&backups), "series.baa").expect("relative path resolves");
+        assert_eq!(resolved, backups.join("series.baa"));
+        let _ = fs::remove_dir_all(backups);
+    }
+}
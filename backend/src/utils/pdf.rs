@@ -0,0 +1,612 @@
+// Minimal PDF writer for the simple document model
+// Text is set in a Unicode TrueType font from the system (DejaVu Sans, Noto
+// Sans, Liberation Sans, Arial...), embedded as a CID font with a ToUnicode
+// map so other scripts print and copy correctly. When no such font covers the
+// text better, it falls back to the standard Helvetica fonts with WinAnsi
+// encoding, where characters outside Latin-1 print as "?".
+// Every page gets a "Page N of M" footer.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use super::document::DocBlock;
+use super::io::atomic_write_bytes;
+use super::truetype::TrueTypeFont;
+
+const PAGE_WIDTH: f64 = 612.0;
+const PAGE_HEIGHT: f64 = 792.0;
+const MARGIN: f64 = 72.0;
+const FOOTER_Y: f64 = 40.0;
+const FOOTER_SIZE: f64 = 9.0;
+const BULLET_INDENT: f64 = 14.0;
+const BULLET: char = '\u{2022}';
+/// Helvetica-Bold runs roughly this much wider than the regular widths below
+const BOLD_WIDTH_FACTOR: f64 = 1.1;
+/// Outline stroke (in 1/1000 em) that fakes bold when the system has no bold face
+const SYNTHETIC_BOLD_STROKE: f64 = 30.0;
+
+/// Helvetica advance widths (1/1000 em) for ASCII 32..=126
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+struct BlockStyle {
+    bold: bool,
+    size: f64,
+    leading: f64,
+    space_before: f64,
+}
+
+fn block_style(block: &DocBlock) -> BlockStyle {
+    let (bold, size, leading, space_before) = match block {
+        DocBlock::Heading(1, _) => (true, 18.0, 24.0, 18.0),
+        DocBlock::Heading(2, _) => (true, 14.0, 20.0, 14.0),
+        DocBlock::Heading(_, _) => (true, 12.0, 16.0, 12.0),
+        DocBlock::Paragraph(_) => (false, 11.0, 15.0, 6.0),
+        DocBlock::Bullet(_) => (false, 11.0, 15.0, 2.0),
+    };
+    BlockStyle {
+        bold,
+        size,
+        leading,
+        space_before,
+    }
+}
+
+/// WinAnsi byte for a character, or `?` when it has none
+fn encode_char(c: char) -> u8 {
+    match c {
+        ' '..='~' | '\u{A0}'..='\u{FF}' => c as u8,
+        '\u{2018}' => 0x91,
+        '\u{2019}' => 0x92,
+        '\u{201C}' => 0x93,
+        '\u{201D}' => 0x94,
+        '\u{2022}' => 0x95,
+        '\u{2013}' => 0x96,
+        '\u{2014}' => 0x97,
+        '\u{2026}' => 0x85,
+        _ => b'?',
+    }
+}
+
+/// A font as used on the page
+enum Face {
+    /// Standard Helvetica or Helvetica-Bold, WinAnsi-encoded
+    Helvetica { bold: bool },
+    /// Embedded TrueType font; `synthetic_bold` strokes the outlines of the
+    /// regular face when the system has no matching bold one
+    TrueType {
+        font: Rc<TrueTypeFont>,
+        synthetic_bold: bool,
+    },
+}
+
+impl Face {
+    fn text_width(&self, text: &str, size: f64) -> f64 {
+        let units: f64 = match self {
+            Face::Helvetica { bold } => {
+                let units: u32 = text
+                    .chars()
+                    .map(|c| match encode_char(c) {
+                        byte @ 32..=126 => u32::from(HELVETICA_WIDTHS[(byte - 32) as usize]),
+                        _ => 556,
+                    })
+                    .sum();
+                let factor = if *bold { BOLD_WIDTH_FACTOR } else { 1.0 };
+                f64::from(units) * factor
+            }
+            Face::TrueType { font, .. } => {
+                text.chars().map(|c| font.advance(font.glyph_id(c))).sum()
+            }
+        };
+        units * size / 1000.0
+    }
+
+    /// The string operand of a `Tj`: a literal WinAnsi string for Helvetica,
+    /// a hex string of glyph ids for an embedded font
+    fn show(&self, text: &str) -> Vec<u8> {
+        match self {
+            Face::Helvetica { .. } => {
+                let mut shown = vec![b'('];
+                for byte in text.chars().map(encode_char) {
+                    if matches!(byte, b'(' | b')' | b'\\') {
+                        shown.push(b'\\');
+                    }
+                    shown.push(byte);
+                }
+                shown.push(b')');
+                shown
+            }
+            Face::TrueType { font, .. } => {
+                let hex: String = text
+                    .chars()
+                    .map(|c| format!("{:04X}", font.glyph_id(c)))
+                    .collect();
+                format!("<{hex}>").into_bytes()
+            }
+        }
+    }
+
+    fn text_op(&self, resource: &str, size: f64, x: f64, y: f64, text: &str) -> Vec<u8> {
+        let render_mode = match self {
+            Face::TrueType {
+                synthetic_bold: true,
+                ..
+            } => format!("2 Tr {:.2} w ", SYNTHETIC_BOLD_STROKE * size / 1000.0),
+            _ => String::new(),
+        };
+        let mut op =
+            format!("BT /{resource} {size} Tf {render_mode}{x:.2} {y:.2} Td ").into_bytes();
+        op.extend(self.show(text));
+        op.extend_from_slice(b" Tj ET\n");
+        op
+    }
+}
+
+/// The regular (`F1`) and bold (`F2`) faces of a document
+struct Fonts {
+    regular: Face,
+    bold: Face,
+}
+
+impl Fonts {
+    fn helvetica() -> Self {
+        Fonts {
+            regular: Face::Helvetica { bold: false },
+            bold: Face::Helvetica { bold: true },
+        }
+    }
+
+    fn from_truetype(regular: TrueTypeFont, bold: Option<TrueTypeFont>) -> Self {
+        let regular = Rc::new(regular);
+        let bold = match bold {
+            Some(bold) => Face::TrueType {
+                font: Rc::new(bold),
+                synthetic_bold: false,
+            },
+            None => Face::TrueType {
+                font: Rc::clone(&regular),
+                synthetic_bold: true,
+            },
+        };
+        Fonts {
+            regular: Face::TrueType {
+                font: regular,
+                synthetic_bold: false,
+            },
+            bold,
+        }
+    }
+
+    fn face(&self, bold: bool) -> (&'static str, &Face) {
+        if bold {
+            ("F2", &self.bold)
+        } else {
+            ("F1", &self.regular)
+        }
+    }
+}
+
+/// Regular and bold font files to try, in order of preference
+fn system_font_candidates() -> Vec<(PathBuf, Option<PathBuf>)> {
+    let mut candidates = Vec::new();
+    if cfg!(target_os = "windows") {
+        let windir = std::env::var("WINDIR").unwrap_or_else(|_| "C:\\Windows".to_string());
+        let fonts = PathBuf::from(windir).join("Fonts");
+        candidates.push((fonts.join("arial.ttf"), Some(fonts.join("arialbd.ttf"))));
+        candidates.push((fonts.join("segoeui.ttf"), Some(fonts.join("segoeuib.ttf"))));
+    } else if cfg!(target_os = "macos") {
+        let supplemental = Path::new("/System/Library/Fonts/Supplemental");
+        candidates.push((
+            supplemental.join("Arial.ttf"),
+            Some(supplemental.join("Arial Bold.ttf")),
+        ));
+        candidates.push((supplemental.join("Arial Unicode.ttf"), None));
+        candidates.push((PathBuf::from("/Library/Fonts/Arial Unicode.ttf"), None));
+    } else {
+        for dir in [
+            "/usr/share/fonts/truetype/dejavu",
+            "/usr/share/fonts/TTF",
+            "/usr/share/fonts/dejavu",
+            "/usr/share/fonts/dejavu-sans-fonts",
+        ] {
+            let dir = Path::new(dir);
+            candidates.push((
+                dir.join("DejaVuSans.ttf"),
+                Some(dir.join("DejaVuSans-Bold.ttf")),
+            ));
+        }
+        for dir in ["/usr/share/fonts/truetype/noto", "/usr/share/fonts/noto"] {
+            let dir = Path::new(dir);
+            candidates.push((
+                dir.join("NotoSans-Regular.ttf"),
+                Some(dir.join("NotoSans-Bold.ttf")),
+            ));
+        }
+        for dir in [
+            "/usr/share/fonts/truetype/liberation",
+            "/usr/share/fonts/liberation-sans",
+        ] {
+            let dir = Path::new(dir);
+            candidates.push((
+                dir.join("LiberationSans-Regular.ttf"),
+                Some(dir.join("LiberationSans-Bold.ttf")),
+            ));
+        }
+    }
+    candidates
+}
+
+fn load_font(path: &Path) -> Option<TrueTypeFont> {
+    TrueTypeFont::parse(std::fs::read(path).ok()?)
+}
+
+/// Every character the document shows, footers and bullets included. Wrapped
+/// lines only ever contain plain spaces between words.
+fn document_chars(blocks: &[DocBlock]) -> Vec<char> {
+    let mut chars: Vec<char> = blocks
+        .iter()
+        .flat_map(|block| match block {
+            DocBlock::Heading(_, text) | DocBlock::Paragraph(text) | DocBlock::Bullet(text) => {
+                text.chars()
+            }
+        })
+        .chain("Page 0123456789 of".chars())
+        .chain(std::iter::once(BULLET))
+        .filter(|c| *c == ' ' || !c.is_whitespace())
+        .collect();
+    chars.sort_unstable();
+    chars.dedup();
+    chars
+}
+
+/// The first system font that covers every character in the document, or
+/// the one covering the most; Helvetica when nothing beats its Latin-1 set.
+fn system_fonts(blocks: &[DocBlock]) -> Fonts {
+    let chars = document_chars(blocks);
+    let mut best: Option<(usize, TrueTypeFont, Option<PathBuf>)> = None;
+    for (regular, bold) in system_font_candidates() {
+        let Some(font) = load_font(&regular) else {
+            continue;
+        };
+        let missing = chars.iter().filter(|c| !font.has_glyph(**c)).count();
+        let improves = match &best {
+            Some((best_missing, ..)) => missing < *best_missing,
+            None => true,
+        };
+        if improves {
+            best = Some((missing, font, bold));
+        }
+        if missing == 0 {
+            break;
+        }
+    }
+
+    let helvetica_missing = chars.iter().filter(|c| encode_char(**c) == b'?').count();
+    match best {
+        Some((missing, regular, bold)) if missing <= helvetica_missing => {
+            let bold = bold.and_then(|path| load_font(&path));
+            Fonts::from_truetype(regular, bold)
+        }
+        _ => Fonts::helvetica(),
+    }
+}
+
+/// Greedy word wrap; a word wider than the line gets a line of its own
+fn wrap_text(text: &str, max_width: f64, size: f64, face: &Face) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+        if line.is_empty() || face.text_width(&candidate, size) <= max_width {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Lays the blocks out on pages and returns each page's content stream
+fn layout_pages(blocks: &[DocBlock], fonts: &Fonts) -> Vec<Vec<u8>> {
+    let top = PAGE_HEIGHT - MARGIN;
+    let mut pages: Vec<Vec<u8>> = vec![Vec::new()];
+    let mut y = top;
+
+    for block in blocks {
+        let style = block_style(block);
+        let (resource, face) = fonts.face(style.bold);
+        let (text, indent) = match block {
+            DocBlock::Heading(_, text) | DocBlock::Paragraph(text) => (text.as_str(), 0.0),
+            DocBlock::Bullet(text) => (text.as_str(), BULLET_INDENT),
+        };
+        let lines = wrap_text(text, PAGE_WIDTH - 2.0 * MARGIN - indent, style.size, face);
+
+        if y < top {
+            y -= style.space_before;
+        }
+        for (index, line) in lines.iter().enumerate() {
+            if y - style.leading < MARGIN {
+                pages.push(Vec::new());
+                y = top;
+            }
+            y -= style.leading;
+            let page = pages.last_mut().expect("at least one page");
+            if index == 0 && matches!(block, DocBlock::Bullet(_)) {
+                page.extend(face.text_op(resource, style.size, MARGIN, y, &BULLET.to_string()));
+            }
+            page.extend(face.text_op(resource, style.size, MARGIN + indent, y, line));
+        }
+    }
+
+    let page_count = pages.len();
+    let (resource, face) = fonts.face(false);
+    for (index, page) in pages.iter_mut().enumerate() {
+        let footer = format!("Page {} of {page_count}", index + 1);
+        let x = (PAGE_WIDTH - face.text_width(&footer, FOOTER_SIZE)) / 2.0;
+        page.extend(face.text_op(resource, FOOTER_SIZE, x, FOOTER_Y, &footer));
+    }
+    pages
+}
+
+fn stream_object(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let mut object = format!("<< {dictionary} /Length {} >>\nstream\n", data.len()).into_bytes();
+    object.extend_from_slice(data);
+    object.extend_from_slice(b"\nendstream");
+    object
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing to a Vec cannot fail
+    let _ = encoder.write_all(data);
+    encoder.finish().unwrap_or_default()
+}
+
+fn utf16_hex(c: char) -> String {
+    c.encode_utf16(&mut [0; 2])
+        .iter()
+        .map(|unit| format!("{unit:04X}"))
+        .collect()
+}
+
+/// CMap mapping glyph ids back to Unicode, so text can be searched and copied
+fn to_unicode_cmap(glyphs: &BTreeMap<u16, char>) -> Vec<u8> {
+    let mut cmap = String::from(
+        "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+         /CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+         1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n",
+    );
+    let entries: Vec<(&u16, &char)> = glyphs.iter().collect();
+    for chunk in entries.chunks(100) {
+        cmap.push_str(&format!("{} beginbfchar\n", chunk.len()));
+        for (glyph, c) in chunk {
+            cmap.push_str(&format!("<{glyph:04X}> <{}>\n", utf16_hex(**c)));
+        }
+        cmap.push_str("endbfchar\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend\n");
+    cmap.into_bytes()
+}
+
+/// Appends the objects of an embedded Type0 font and returns its object id
+fn truetype_font_objects(font: &TrueTypeFont, chars: &[char], objects: &mut Vec<Vec<u8>>) -> usize {
+    let mut glyphs: BTreeMap<u16, char> = BTreeMap::new();
+    for &c in chars {
+        let glyph = font.glyph_id(c);
+        if glyph != 0 {
+            glyphs.entry(glyph).or_insert(c);
+        }
+    }
+
+    let file_id = objects.len() + 1;
+    objects.push(stream_object(
+        &format!("/Filter /FlateDecode /Length1 {}", font.data.len()),
+        &deflate(&font.data),
+    ));
+
+    let scale = |units: i16| font.to_milli_em(i32::from(units)).round();
+    let [x_min, y_min, x_max, y_max] = font.bbox;
+    let name = &font.postscript_name;
+    let descriptor_id = objects.len() + 1;
+    objects.push(
+        format!(
+            "<< /Type /FontDescriptor /FontName /{name} /Flags 32 \
+             /FontBBox [{} {} {} {}] /ItalicAngle {:.1} /Ascent {} /Descent {} \
+             /CapHeight {} /StemV 80 /FontFile2 {file_id} 0 R >>",
+            scale(x_min),
+            scale(y_min),
+            scale(x_max),
+            scale(y_max),
+            font.italic_angle,
+            scale(font.ascent),
+            scale(font.descent),
+            scale(font.cap_height),
+        )
+        .into_bytes(),
+    );
+
+    let widths: String = glyphs
+        .keys()
+        .map(|glyph| format!("{glyph} [{}]", font.advance(*glyph).round()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let cid_font_id = objects.len() + 1;
+    objects.push(
+        format!(
+            "<< /Type /Font /Subtype /CIDFontType2 /BaseFont /{name} \
+             /CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> \
+             /FontDescriptor {descriptor_id} 0 R /DW {} /W [{widths}] /CIDToGIDMap /Identity >>",
+            font.advance(0).round()
+        )
+        .into_bytes(),
+    );
+
+    let to_unicode_id = objects.len() + 1;
+    objects.push(stream_object("", &to_unicode_cmap(&glyphs)));
+
+    objects.push(
+        format!(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /{name} /Encoding /Identity-H \
+             /DescendantFonts [{cid_font_id} 0 R] /ToUnicode {to_unicode_id} 0 R >>"
+        )
+        .into_bytes(),
+    );
+    objects.len()
+}
+
+/// Appends the font object(s) for a face and returns the id to reference
+fn face_objects(face: &Face, chars: &[char], objects: &mut Vec<Vec<u8>>) -> usize {
+    match face {
+        Face::Helvetica { bold } => {
+            let base = if *bold { "Helvetica-Bold" } else { "Helvetica" };
+            objects.push(
+                format!("<< /Type /Font /Subtype /Type1 /BaseFont /{base} /Encoding /WinAnsiEncoding >>")
+                    .into_bytes(),
+            );
+            objects.len()
+        }
+        Face::TrueType { font, .. } => truetype_font_objects(font, chars, objects),
+    }
+}
+
+fn render_pdf_with_fonts(blocks: &[DocBlock], fonts: &Fonts) -> (Vec<u8>, usize) {
+    let pages = layout_pages(blocks, fonts);
+    let page_count = pages.len();
+    let chars = document_chars(blocks);
+
+    // 1 is the catalog and 2 the page tree, filled in once the page ids are known
+    let mut objects: Vec<Vec<u8>> = vec![Vec::new(), Vec::new()];
+    let regular_id = face_objects(&fonts.regular, &chars, &mut objects);
+    let bold_id = match (&fonts.regular, &fonts.bold) {
+        (Face::TrueType { font: regular, .. }, Face::TrueType { font: bold, .. })
+            if Rc::ptr_eq(regular, bold) =>
+        {
+            regular_id
+        }
+        _ => face_objects(&fonts.bold, &chars, &mut objects),
+    };
+
+    let mut page_ids = Vec::with_capacity(page_count);
+    for content in pages {
+        let page_id = objects.len() + 1;
+        page_ids.push(page_id);
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 {regular_id} 0 R /F2 {bold_id} 0 R >> >> \
+                 /Contents {} 0 R >>",
+                page_id + 1
+            )
+            .into_bytes(),
+        );
+        objects.push(stream_object("", &content));
+    }
+    objects[0] = b"<< /Type /Catalog /Pages 2 0 R >>".to_vec();
+    objects[1] = format!(
+        "<< /Type /Pages /Kids [{}] /Count {page_count} >>",
+        page_ids
+            .iter()
+            .map(|id| format!("{id} 0 R"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    )
+    .into_bytes();
+
+    let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend(format!("{} 0 obj\n", index + 1).into_bytes());
+        out.extend_from_slice(object);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref_offset = out.len();
+    out.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+    for offset in offsets {
+        out.extend(format!("{offset:010} 00000 n \n").into_bytes());
+    }
+    out.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .into_bytes(),
+    );
+    (out, page_count)
+}
+
+/// Renders the blocks as a PDF file and returns the bytes and page count
+pub fn render_pdf(blocks: &[DocBlock]) -> (Vec<u8>, usize) {
+    render_pdf_with_fonts(blocks, &system_fonts(blocks))
+}
+
+/// Writes the blocks as a PDF and returns the number of pages
+pub fn write_pdf(path: &Path, blocks: &[DocBlock]) -> Result<usize, String> {
+    let (bytes, page_count) = render_pdf(blocks);
+    atomic_write_bytes(path, &bytes)?;
+    Ok(page_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_chapter() -> Vec<DocBlock> {
+        std::iter::once(DocBlock::Heading(1, "Chapter".into()))
+            .chain((0..80).map(|_| DocBlock::Paragraph("word ".repeat(60))))
+            .collect()
+    }
+
+    #[test]
+    fn paginates_long_text_with_page_count_footers() {
+        let (bytes, page_count) = render_pdf_with_fonts(&long_chapter(), &Fonts::helvetica());
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(page_count > 1);
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.contains(&format!("(Page {page_count} of {page_count}) Tj")));
+        assert!(text.ends_with("%%EOF\n"));
+    }
+
+    #[test]
+    fn embeds_a_unicode_font_with_a_to_unicode_map() {
+        // Any embeddable TrueType font on this machine will do
+        let Some(font) = system_font_candidates()
+            .into_iter()
+            .find_map(|(regular, _)| load_font(&regular))
+        else {
+            return;
+        };
+        let glyph = font.glyph_id('é');
+        let blocks = vec![
+            DocBlock::Heading(1, "Chapter".into()),
+            DocBlock::Paragraph("Café".into()),
+        ];
+        let (bytes, page_count) = render_pdf_with_fonts(&blocks, &Fonts::from_truetype(font, None));
+        let text = String::from_utf8_lossy(&bytes);
+        assert_eq!(page_count, 1);
+        assert!(text.contains("/Subtype /CIDFontType2"));
+        assert!(text.contains("/FontFile2"));
+        assert!(text.contains(&format!("<{glyph:04X}> <00E9>")));
+        // Headings fall back to stroked outlines without a bold face
+        assert!(text.contains("2 Tr"));
+        assert!(!text.contains("/Helvetica"));
+    }
+}
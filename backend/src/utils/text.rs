@@ -1,5 +1,7 @@
 // Text utilities
 
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use slug::slugify as slug_slugify;
 
 /// Convert a string to a URL-safe slug
@@ -12,3 +14,597 @@ pub fn slugify(s: &str) -> String {
 pub fn count_words(text: &str) -> i32 {
     text.split_whitespace().count() as i32
 }
+
+/// Count sentences the way `split_sentences` splits them, so scene stats and
+/// the rhythm analysis agree
+pub fn count_sentences(text: &str) -> usize {
+    split_sentences(text).len()
+}
+
+/// Words that end in a period without ending the sentence
+const SENTENCE_ABBREVIATIONS: [&str; 12] = [
+    "mr", "mrs", "ms", "dr", "st", "prof", "jr", "sr", "vs", "etc", "e.g", "i.e",
+];
+
+/// Split a paragraph into sentences at `.`, `!`, `?` or `…` (plus any closing
+/// quotes or brackets) followed by whitespace. A period after a common
+/// abbreviation such as "Mr." or a single initial does not end a sentence, and
+/// pieces without a letter or digit (a lone "...") are not sentences.
+pub fn split_sentences(text: &str) -> Vec<&str> {
+    let is_terminator = |c: char| matches!(c, '.' | '!' | '?' | '\u{2026}');
+    let is_closer = |c: char| matches!(c, '"' | '\'' | ')' | ']' | '\u{201D}' | '\u{2019}');
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        if !is_terminator(c) {
+            continue;
+        }
+        let mut end = index + c.len_utf8();
+        while let Some(&(next_index, next)) = chars.peek() {
+            if is_terminator(next) || is_closer(next) {
+                end = next_index + next.len_utf8();
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if !chars.peek().map_or(true, |(_, next)| next.is_whitespace()) {
+            continue;
+        }
+        if c == '.' {
+            let last_word = text[start..index]
+                .rsplit(char::is_whitespace)
+                .next()
+                .unwrap_or("")
+                .trim_start_matches(|c: char| !c.is_alphanumeric());
+            // "J." is an initial; "I." usually ends a sentence
+            let is_initial = last_word.chars().count() == 1
+                && last_word.chars().all(|c| c.is_uppercase() && c != 'I');
+            let last_word = last_word.to_lowercase();
+            if is_initial || SENTENCE_ABBREVIATIONS.contains(&last_word.as_str()) {
+                continue;
+            }
+        }
+        let sentence = text[start..end].trim();
+        if sentence.chars().any(char::is_alphanumeric) {
+            sentences.push(sentence);
+        }
+        start = end;
+    }
+    let rest = text[start..].trim();
+    if rest.chars().any(char::is_alphanumeric) {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Byte offsets of every whole-word, case-sensitive occurrence of `term` in `text`
+pub fn find_whole_word_matches(text: &str, term: &str) -> Vec<usize> {
+    if term.is_empty() {
+        return Vec::new();
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut matches = Vec::new();
+    let mut cursor = 0usize;
+
+    while let Some(pos) = text[cursor..].find(term) {
+        let start = cursor + pos;
+        let end = start + term.len();
+        let before_ok = text[..start]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !is_word_char(c));
+        let after_ok = text[end..]
+            .chars()
+            .next()
+            .map_or(true, |c| !is_word_char(c));
+        if before_ok && after_ok {
+            matches.push(start);
+        }
+        cursor = start + term.chars().next().map_or(1, char::len_utf8);
+    }
+
+    matches
+}
+
+/// Byte ranges of every whole-word occurrence of `term` in `text`, ignoring
+/// case. Comparison is done character by character on the original text, so
+/// the ranges stay valid where lowercasing would change the text's length.
+pub fn find_whole_word_matches_ignore_case(text: &str, term: &str) -> Vec<(usize, usize)> {
+    let term: Vec<char> = term.chars().flat_map(char::to_lowercase).collect();
+    if term.is_empty() {
+        return Vec::new();
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut matches = Vec::new();
+    for (start, _) in text.char_indices() {
+        let mut expected = term.iter();
+        let mut end = None;
+        for (offset, c) in text[start..].char_indices() {
+            if !c
+                .to_lowercase()
+                .all(|lower| expected.next() == Some(&lower))
+            {
+                break;
+            }
+            if expected.len() == 0 {
+                end = Some(start + offset + c.len_utf8());
+                break;
+            }
+        }
+        let Some(end) = end else {
+            continue;
+        };
+        let before_ok = text[..start]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !is_word_char(c));
+        let after_ok = text[end..]
+            .chars()
+            .next()
+            .map_or(true, |c| !is_word_char(c));
+        if before_ok && after_ok {
+            matches.push((start, end));
+        }
+    }
+
+    matches
+}
+
+/// Replace every whole-word occurrence of `term` with `replacement`
+pub fn replace_whole_word(text: &str, term: &str, replacement: &str) -> (String, usize) {
+    let matches = find_whole_word_matches(text, term);
+    if matches.is_empty() {
+        return (text.to_string(), 0);
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut last = 0usize;
+    let mut replaced = 0usize;
+    for start in matches {
+        if start < last {
+            continue;
+        }
+        output.push_str(&text[last..start]);
+        output.push_str(replacement);
+        last = start + term.len();
+        replaced += 1;
+    }
+    output.push_str(&text[last..]);
+    (output, replaced)
+}
+
+fn rename_in_node(node: &mut Value, old_name: &str, new_name: &str, entry_id: &str) -> usize {
+    match node.get("type").and_then(Value::as_str).unwrap_or("") {
+        "text" => {
+            let Some(text) = node.get("text").and_then(Value::as_str) else {
+                return 0;
+            };
+            let (updated, count) = replace_whole_word(text, old_name, new_name);
+            if count > 0 {
+                node["text"] = Value::String(updated);
+            }
+            count
+        }
+        "mention" => {
+            let Some(attrs) = node.get_mut("attrs").and_then(Value::as_object_mut) else {
+                return 0;
+            };
+            if attrs.get("id").and_then(Value::as_str) != Some(entry_id) {
+                return 0;
+            }
+            let label = match attrs.get("label").and_then(Value::as_str) {
+                Some(label) if label.starts_with('@') => format!("@{new_name}"),
+                _ => new_name.to_string(),
+            };
+            if attrs.get("label").and_then(Value::as_str) == Some(label.as_str()) {
+                return 0;
+            }
+            attrs.insert("label".to_string(), Value::String(label));
+            1
+        }
+        _ => node
+            .get_mut("content")
+            .and_then(Value::as_array_mut)
+            .map_or(0, |children| {
+                children
+                    .iter_mut()
+                    .map(|child| rename_in_node(child, old_name, new_name, entry_id))
+                    .sum()
+            }),
+    }
+}
+
+/// Rename a codex entry inside a scene body: whole-word occurrences of
+/// `old_name` in text nodes and the labels of mentions pointing at
+/// `entry_id`. Keys, marks and other attributes are never touched. Returns
+/// the rewritten body (unchanged when nothing matched) and the number of
+/// replacements.
+pub fn rename_in_scene_content(
+    content: &str,
+    old_name: &str,
+    new_name: &str,
+    entry_id: &str,
+) -> (String, usize) {
+    let trimmed = content.trim_start();
+    if !trimmed.starts_with('{') {
+        return replace_whole_word(content, old_name, new_name);
+    }
+    let Ok(mut doc) = serde_json::from_str::<Value>(trimmed) else {
+        return (content.to_string(), 0);
+    };
+    let count = rename_in_node(&mut doc, old_name, new_name, entry_id);
+    if count == 0 {
+        return (content.to_string(), 0);
+    }
+    (doc.to_string(), count)
+}
+
+/// Extract readable text from a scene body.
+/// Scene files hold the editor's JSON document; anything that doesn't parse
+/// as one is returned unchanged. Paragraphs are separated by blank lines.
+pub fn extract_plain_text(content: &str) -> String {
+    extract_paragraphs(content).join("\n\n")
+}
+
+/// Same as [`extract_plain_text`] but keeps each block as its own entry
+pub fn extract_paragraphs(content: &str) -> Vec<String> {
+    let trimmed = content.trim_start();
+    if !trimmed.starts_with('{') {
+        return content
+            .split("\n\n")
+            .map(|block| block.trim().to_string())
+            .filter(|block| !block.is_empty())
+            .collect();
+    }
+
+    let Ok(doc) = serde_json::from_str::<Value>(trimmed) else {
+        return vec![content.to_string()];
+    };
+
+    let mut blocks = Vec::new();
+    collect_text_blocks(&doc, &mut blocks);
+    blocks
+}
+
+fn collect_text_blocks(node: &Value, blocks: &mut Vec<String>) {
+    let node_type = node.get("type").and_then(Value::as_str).unwrap_or("");
+    match node_type {
+        "paragraph" | "heading" | "codeBlock" => {
+            let mut text = String::new();
+            collect_inline_text(node, &mut text);
+            blocks.push(text);
+        }
+        "text" => {
+            if let Some(text) = node.get("text").and_then(Value::as_str) {
+                blocks.push(text.to_string());
+            }
+        }
+        _ => {
+            if let Some(children) = node.get("content").and_then(Value::as_array) {
+                for child in children {
+                    collect_text_blocks(child, blocks);
+                }
+            }
+        }
+    }
+}
+
+fn collect_inline_text(node: &Value, output: &mut String) {
+    match node.get("type").and_then(Value::as_str).unwrap_or("") {
+        "text" => {
+            if let Some(text) = node.get("text").and_then(Value::as_str) {
+                output.push_str(text);
+            }
+        }
+        "hardBreak" => output.push('\n'),
+        _ => {
+            if let Some(children) = node.get("content").and_then(Value::as_array) {
+                for child in children {
+                    collect_inline_text(child, output);
+                }
+            }
+        }
+    }
+}
+
+/// Build an editor JSON document with one paragraph per entry
+pub fn paragraphs_to_doc(paragraphs: &[String]) -> String {
+    let content: Vec<Value> = paragraphs
+        .iter()
+        .map(|paragraph| {
+            if paragraph.is_empty() {
+                serde_json::json!({ "type": "paragraph" })
+            } else {
+                serde_json::json!({
+                    "type": "paragraph",
+                    "content": [{ "type": "text", "text": paragraph }]
+                })
+            }
+        })
+        .collect();
+
+    serde_json::json!({ "type": "doc", "content": content }).to_string()
+}
+
+fn opens_quote(previous: Option<char>) -> bool {
+    match previous {
+        None => true,
+        Some(c) => {
+            c.is_whitespace()
+                || matches!(
+                    c,
+                    '(' | '[' | '{' | '\u{2014}' | '\u{2013}' | '\u{201C}' | '\u{2018}'
+                )
+        }
+    }
+}
+
+/// Curly quotes, em-dashes for `--`/`---` and single spaces. `previous` is the
+/// character before `text` in the same block, for quote direction.
+pub fn normalize_typography_text(text: &str, previous: Option<char>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut last = previous;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let normalized = match c {
+            '"' if opens_quote(last) => '\u{201C}',
+            '"' => '\u{201D}',
+            '\'' if opens_quote(last) => '\u{2018}',
+            '\'' => '\u{2019}',
+            '-' if chars.peek() == Some(&'-') => {
+                while chars.peek() == Some(&'-') {
+                    chars.next();
+                }
+                '\u{2014}'
+            }
+            ' ' if last == Some(' ') => continue,
+            other => other,
+        };
+        output.push(normalized);
+        last = Some(normalized);
+    }
+    output
+}
+
+fn has_code_mark(node: &Value) -> bool {
+    node.get("marks")
+        .and_then(Value::as_array)
+        .is_some_and(|marks| {
+            marks
+                .iter()
+                .any(|mark| mark.get("type").and_then(Value::as_str) == Some("code"))
+        })
+}
+
+/// Returns whether any text node changed.
+fn normalize_typography_node(node: &mut Value, previous: &mut Option<char>) -> bool {
+    match node.get("type").and_then(Value::as_str).unwrap_or("") {
+        "codeBlock" => {
+            *previous = None;
+            false
+        }
+        "hardBreak" => {
+            *previous = Some('\n');
+            false
+        }
+        "text" => {
+            let Some(text) = node.get("text").and_then(Value::as_str) else {
+                return false;
+            };
+            if has_code_mark(node) {
+                *previous = text.chars().last().or(*previous);
+                return false;
+            }
+            let normalized = normalize_typography_text(text, *previous);
+            *previous = normalized.chars().last().or(*previous);
+            if normalized == text {
+                return false;
+            }
+            node["text"] = Value::String(normalized);
+            true
+        }
+        _ => {
+            let Some(children) = node.get_mut("content").and_then(Value::as_array_mut) else {
+                return false;
+            };
+            // Text nodes only live directly inside blocks, so quote context
+            // restarts at every node that has children.
+            *previous = None;
+            let mut changed = false;
+            for child in children.iter_mut() {
+                changed |= normalize_typography_node(child, previous);
+            }
+            // Collapsed spacing can empty a text node, which the editor rejects.
+            children.retain(|child| {
+                child.get("type").and_then(Value::as_str) != Some("text")
+                    || child
+                        .get("text")
+                        .and_then(Value::as_str)
+                        .is_some_and(|text| !text.is_empty())
+            });
+            *previous = None;
+            changed
+        }
+    }
+}
+
+/// Apply [`normalize_typography_text`] to the text nodes of a scene body,
+/// leaving document structure, marks and code untouched. Content that isn't
+/// an editor JSON document is normalized as plain text. When no text node
+/// changes, `content` comes back byte-for-byte so callers can skip the write.
+pub fn normalize_typography(content: &str) -> String {
+    let trimmed = content.trim_start();
+    if !trimmed.starts_with('{') {
+        return normalize_typography_text(content, None);
+    }
+    let Ok(mut doc) = serde_json::from_str::<Value>(trimmed) else {
+        return content.to_string();
+    };
+    if !normalize_typography_node(&mut doc, &mut None) {
+        return content.to_string();
+    }
+    doc.to_string()
+}
+
+/// Decode a text file's bytes: a UTF-8 byte-order mark is dropped, UTF-16
+/// files with a BOM are transcoded, and CRLF / lone CR line endings become LF.
+/// Files exported by Windows tools commonly arrive in one of these shapes.
+pub fn decode_text(bytes: &[u8]) -> Result<String, String> {
+    let text = match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8(rest.to_vec()),
+        [0xFF, 0xFE, rest @ ..] | [0xFE, 0xFF, rest @ ..] => {
+            if rest.len() % 2 != 0 {
+                return Err("Truncated UTF-16 text".to_string());
+            }
+            let little_endian = bytes[0] == 0xFF;
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|pair| {
+                    if little_endian {
+                        u16::from_le_bytes([pair[0], pair[1]])
+                    } else {
+                        u16::from_be_bytes([pair[0], pair[1]])
+                    }
+                })
+                .collect();
+            return String::from_utf16(&units)
+                .map(|text| normalize_line_endings(&text))
+                .map_err(|e| format!("Invalid UTF-16 text: {e}"));
+        }
+        _ => String::from_utf8(bytes.to_vec()),
+    };
+    text.map(|text| normalize_line_endings(&text))
+        .map_err(|e| format!("Invalid UTF-8 text: {e}"))
+}
+
+fn normalize_line_endings(text: &str) -> String {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n").replace('\r', "\n")
+    } else {
+        text.to_string()
+    }
+}
+
+/// SHA-256 of text content as lowercase hex, for change detection
+pub fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Quote a CSV field (RFC 4180) when it contains a delimiter, quote or line
+/// break; embedded quotes are doubled.
+pub fn escape_csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_already_normalized_documents_untouched() {
+        let content = r#"{"type":"doc","content":[{"type":"paragraph","content":[{"type":"text","text":"Done."}]}]}"#;
+        assert_eq!(normalize_typography(content), content);
+        let spaced = r#"{"type":"doc","content":[{"type":"paragraph","content":[{"type":"text","text":"a  b"}]}]}"#;
+        assert!(normalize_typography(spaced).contains(r#""text":"a b""#));
+    }
+
+    #[test]
+    fn normalizes_quotes_dashes_and_spaces() {
+        assert_eq!(
+            normalize_typography_text("He said \"it's  fine\" -- then 'left'.", None),
+            "He said \u{201C}it\u{2019}s fine\u{201D} \u{2014} then \u{2018}left\u{2019}."
+        );
+    }
+
+    #[test]
+    fn leaves_document_structure_and_code_alone() {
+        let doc = r#"{"type":"doc","content":[{"type":"paragraph","content":[{"type":"text","text":"\"Hi"},{"type":"text","marks":[{"type":"code"}],"text":"a--b"},{"type":"text","text":"\" "}]}]}"#;
+        let normalized: Value = serde_json::from_str(&normalize_typography(doc)).unwrap();
+        let texts: Vec<&str> = normalized["content"][0]["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| node["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(texts, vec!["\u{201C}Hi", "a--b", "\u{201D} "]);
+    }
+
+    #[test]
+    fn counts_sentences_once_per_terminator_run() {
+        assert_eq!(count_sentences("Wait... what?! She left. Then"), 4);
+        assert_eq!(count_sentences("  ...  "), 0);
+    }
+
+    #[test]
+    fn splits_sentences_around_abbreviations_and_quotes() {
+        assert_eq!(
+            split_sentences("Mr. Hale left. \"Why?\" she asked. J. R. waved at I. Then"),
+            vec![
+                "Mr. Hale left.",
+                "\"Why?\"",
+                "she asked.",
+                "J. R. waved at I.",
+                "Then"
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_bom_prefixed_and_crlf_text() {
+        assert_eq!(
+            decode_text(b"\xEF\xBB\xBF{\"type\":\"doc\"}\r\n").unwrap(),
+            "{\"type\":\"doc\"}\n"
+        );
+        assert_eq!(
+            decode_text(&[0xFF, 0xFE, b'a', 0, b'\r', 0, b'b', 0]).unwrap(),
+            "a\nb"
+        );
+        assert!(decode_text(&[0xC3, 0x28]).is_err());
+    }
+
+    #[test]
+    fn renames_text_and_mentions_without_touching_keys() {
+        let doc = r#"{"type":"doc","content":[{"type":"paragraph","attrs":{"class":"Kola"},"content":[{"type":"text","text":"Kola met Kolam."},{"type":"mention","attrs":{"id":"c1","label":"@Kola"}},{"type":"mention","attrs":{"id":"c2","label":"Kola"}}]}]}"#;
+        let (updated, count) = rename_in_scene_content(doc, "Kola", "Koli", "c1");
+        let updated: Value = serde_json::from_str(&updated).unwrap();
+        let paragraph = &updated["content"][0];
+        assert_eq!(count, 2);
+        assert_eq!(paragraph["attrs"]["class"], "Kola");
+        assert_eq!(paragraph["content"][0]["text"], "Koli met Kolam.");
+        assert_eq!(paragraph["content"][1]["attrs"]["label"], "@Koli");
+        assert_eq!(paragraph["content"][2]["attrs"]["label"], "Kola");
+    }
+
+    #[test]
+    fn quotes_csv_fields_only_when_needed() {
+        assert_eq!(escape_csv_field("Plain title"), "Plain title");
+        assert_eq!(escape_csv_field("Storms, Part 2"), "\"Storms, Part 2\"");
+        assert_eq!(escape_csv_field("The \"Keep\""), "\"The \"\"Keep\"\"\"");
+    }
+
+    #[test]
+    fn matches_names_case_insensitively_on_the_original_text() {
+        // "İ" lowercases to two characters, which used to shift later offsets
+        let text = "İstanbul met ANNA. Annabel and anna_x did not.";
+        assert_eq!(
+            find_whole_word_matches_ignore_case(text, "anna"),
+            vec![(14, 18)]
+        );
+        assert_eq!(&text[14..18], "ANNA");
+        assert_eq!(
+            find_whole_word_matches_ignore_case("Élodie, élodie", "élodie"),
+            vec![(0, 7), (9, 16)]
+        );
+    }
+}
@@ -0,0 +1,252 @@
+// TrueType font parsing for PDF embedding
+// Reads only what a PDF font descriptor and text layout need: metrics, glyph
+// advances and the character-to-glyph map. Collections (.ttc), CFF-flavoured
+// OpenType fonts and fonts whose license forbids embedding are rejected.
+
+use std::collections::HashMap;
+
+pub struct TrueTypeFont {
+    /// The complete font file, embedded as-is
+    pub data: Vec<u8>,
+    pub postscript_name: String,
+    pub units_per_em: u16,
+    pub ascent: i16,
+    pub descent: i16,
+    pub cap_height: i16,
+    /// xMin, yMin, xMax, yMax in font units
+    pub bbox: [i16; 4],
+    pub italic_angle: f64,
+    advances: Vec<u16>,
+    glyphs: HashMap<char, u16>,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|value| value as i16)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn find_table<'a>(data: &'a [u8], tag: &[u8; 4]) -> Option<&'a [u8]> {
+    let table_count = usize::from(read_u16(data, 4)?);
+    (0..table_count).find_map(|index| {
+        let record = 12 + index * 16;
+        if data.get(record..record + 4)? != tag {
+            return None;
+        }
+        let offset = read_u32(data, record + 8)? as usize;
+        let length = read_u32(data, record + 12)? as usize;
+        data.get(offset..offset.checked_add(length)?)
+    })
+}
+
+/// Segment mapping to delta values (BMP only)
+fn parse_cmap_format4(subtable: &[u8], glyphs: &mut HashMap<char, u16>) -> Option<()> {
+    let segment_count = usize::from(read_u16(subtable, 6)? / 2);
+    let end_codes = 14;
+    let start_codes = end_codes + segment_count * 2 + 2;
+    let deltas = start_codes + segment_count * 2;
+    let range_offsets = deltas + segment_count * 2;
+    for segment in 0..segment_count {
+        let end = read_u16(subtable, end_codes + segment * 2)?;
+        let start = read_u16(subtable, start_codes + segment * 2)?;
+        let delta = read_u16(subtable, deltas + segment * 2)?;
+        let range_offset_position = range_offsets + segment * 2;
+        let range_offset = usize::from(read_u16(subtable, range_offset_position)?);
+        for code in start..=end.min(0xFFFE) {
+            let glyph = if range_offset == 0 {
+                code.wrapping_add(delta)
+            } else {
+                let address = range_offset_position + range_offset + usize::from(code - start) * 2;
+                match read_u16(subtable, address)? {
+                    0 => 0,
+                    glyph => glyph.wrapping_add(delta),
+                }
+            };
+            if let (Some(c), true) = (char::from_u32(u32::from(code)), glyph != 0) {
+                glyphs.entry(c).or_insert(glyph);
+            }
+        }
+    }
+    Some(())
+}
+
+/// Segmented coverage (full Unicode range)
+fn parse_cmap_format12(subtable: &[u8], glyphs: &mut HashMap<char, u16>) -> Option<()> {
+    let group_count = read_u32(subtable, 12)? as usize;
+    for group in 0..group_count {
+        let record = 16 + group * 12;
+        let start = read_u32(subtable, record)?;
+        let end = read_u32(subtable, record + 4)?.min(0x10FFFF);
+        let start_glyph = read_u32(subtable, record + 8)?;
+        for code in start..=end {
+            let Ok(glyph) = u16::try_from(start_glyph + (code - start)) else {
+                break;
+            };
+            if let (Some(c), true) = (char::from_u32(code), glyph != 0) {
+                glyphs.entry(c).or_insert(glyph);
+            }
+        }
+    }
+    Some(())
+}
+
+/// Character map from the Unicode subtables, preferring the full-range one
+fn parse_cmap(cmap: &[u8]) -> Option<HashMap<char, u16>> {
+    let subtable_count = usize::from(read_u16(cmap, 2)?);
+    let mut format4 = None;
+    let mut format12 = None;
+    for index in 0..subtable_count {
+        let record = 4 + index * 8;
+        let platform = read_u16(cmap, record)?;
+        let encoding = read_u16(cmap, record + 2)?;
+        let offset = read_u32(cmap, record + 4)? as usize;
+        let is_unicode = platform == 0 || (platform == 3 && matches!(encoding, 1 | 10));
+        let Some(subtable) = cmap.get(offset..).filter(|_| is_unicode) else {
+            continue;
+        };
+        match read_u16(subtable, 0)? {
+            4 => format4 = format4.or(Some(subtable)),
+            12 => format12 = format12.or(Some(subtable)),
+            _ => {}
+        }
+    }
+
+    let mut glyphs = HashMap::new();
+    match (format12, format4) {
+        (Some(subtable), _) => parse_cmap_format12(subtable, &mut glyphs)?,
+        (None, Some(subtable)) => parse_cmap_format4(subtable, &mut glyphs)?,
+        (None, None) => return None,
+    }
+    Some(glyphs)
+}
+
+/// PostScript name (name id 6), reduced to characters valid in a PDF name
+fn parse_postscript_name(name: &[u8]) -> Option<String> {
+    let count = usize::from(read_u16(name, 2)?);
+    let strings = usize::from(read_u16(name, 4)?);
+    for index in 0..count {
+        let record = 6 + index * 12;
+        if read_u16(name, record + 6)? != 6 {
+            continue;
+        }
+        let platform = read_u16(name, record)?;
+        let length = usize::from(read_u16(name, record + 8)?);
+        let offset = strings + usize::from(read_u16(name, record + 10)?);
+        let bytes = name.get(offset..offset + length)?;
+        let raw = if platform == 3 || platform == 0 {
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        } else {
+            bytes.iter().map(|&byte| char::from(byte)).collect()
+        };
+        let cleaned: String = raw
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        if !cleaned.is_empty() {
+            return Some(cleaned);
+        }
+    }
+    None
+}
+
+impl TrueTypeFont {
+    /// Parses a `.ttf` file; `None` when it isn't a usable, embeddable
+    /// TrueType font.
+    pub fn parse(data: Vec<u8>) -> Option<Self> {
+        let version = read_u32(&data, 0)?;
+        // 0x00010000 or 'true'; 'OTTO' (CFF) and 'ttcf' are not supported
+        if version != 0x0001_0000 && version != 0x7472_7565 {
+            return None;
+        }
+        find_table(&data, b"glyf")?;
+
+        let head = find_table(&data, b"head")?;
+        let hhea = find_table(&data, b"hhea")?;
+        let hmtx = find_table(&data, b"hmtx")?;
+        let maxp = find_table(&data, b"maxp")?;
+        let units_per_em = read_u16(head, 18)?.max(1);
+        let bbox = [
+            read_i16(head, 36)?,
+            read_i16(head, 38)?,
+            read_i16(head, 40)?,
+            read_i16(head, 42)?,
+        ];
+        let ascent = read_i16(hhea, 4)?;
+        let descent = read_i16(hhea, 6)?;
+
+        let mut cap_height = ascent;
+        if let Some(os2) = find_table(&data, b"OS/2") {
+            // Restricted License embedding: the font may not be embedded
+            if read_u16(os2, 8)? & 0x000F == 0x0002 {
+                return None;
+            }
+            if read_u16(os2, 0)? >= 2 {
+                cap_height = read_i16(os2, 88).unwrap_or(ascent);
+            }
+        }
+        let italic_angle = find_table(&data, b"post")
+            .and_then(|post| read_u32(post, 4))
+            .map_or(0.0, |fixed| f64::from(fixed as i32) / 65536.0);
+
+        let glyph_count = usize::from(read_u16(maxp, 4)?);
+        let metric_count = usize::from(read_u16(hhea, 34)?).clamp(1, glyph_count.max(1));
+        let advances = (0..metric_count)
+            .map(|index| read_u16(hmtx, index * 4))
+            .collect::<Option<Vec<u16>>>()?;
+
+        let glyphs = parse_cmap(find_table(&data, b"cmap")?)?;
+        let postscript_name = find_table(&data, b"name")
+            .and_then(parse_postscript_name)
+            .unwrap_or_else(|| "EmbeddedFont".to_string());
+
+        Some(Self {
+            data,
+            postscript_name,
+            units_per_em,
+            ascent,
+            descent,
+            cap_height,
+            bbox,
+            italic_angle,
+            advances,
+            glyphs,
+        })
+    }
+
+    /// Glyph for a character; 0 (.notdef) when the font lacks it
+    pub fn glyph_id(&self, c: char) -> u16 {
+        self.glyphs.get(&c).copied().unwrap_or(0)
+    }
+
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.glyphs.contains_key(&c)
+    }
+
+    /// Advance width of a glyph in 1/1000 em
+    pub fn advance(&self, glyph: u16) -> f64 {
+        let units = self
+            .advances
+            .get(usize::from(glyph))
+            .or(self.advances.last())
+            .copied()
+            .unwrap_or(0);
+        self.to_milli_em(i32::from(units))
+    }
+
+    /// Converts font units to 1/1000 em, the unit PDF font metrics use
+    pub fn to_milli_em(&self, units: i32) -> f64 {
+        f64::from(units) * 1000.0 / f64::from(self.units_per_em)
+    }
+}
@@ -5,14 +5,7 @@
 // Constants
 // ============================================================================
 
-/// Maximum length for project titles
-pub const MAX_PROJECT_TITLE_LENGTH: usize = 200;
-
-/// Maximum file size for scenes (10 MB)
-pub const MAX_SCENE_SIZE: u64 = 10 * 1024 * 1024;
-
-/// Maximum JSON payload size (5 MB)
-pub const MAX_JSON_SIZE: usize = 5 * 1024 * 1024;
+pub use super::limits::{MAX_JSON_SIZE, MAX_PROJECT_TITLE_LENGTH, MAX_SCENE_SIZE};
 
 // ============================================================================
 // Path Validation
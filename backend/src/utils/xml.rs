@@ -0,0 +1,181 @@
+// XML utilities
+
+use std::collections::HashMap;
+
+/// Parsed XML element. Text is the concatenation of all direct text nodes.
+#[derive(Debug, Default)]
+pub struct XmlElement {
+    pub name: String,
+    pub attrs: HashMap<String, String>,
+    pub children: Vec<XmlElement>,
+    pub text: String,
+}
+
+impl XmlElement {
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Depth-first search for the first element with `name`, including self
+    pub fn find(&self, name: &str) -> Option<&XmlElement> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|c| c.find(name))
+    }
+}
+
+/// Escape text for use in element content or attribute values
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn decode_xml_entities(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        let after = &rest[amp..];
+        let Some(semi) = after.find(';') else {
+            decoded.push_str(after);
+            return decoded;
+        };
+        let entity = &after[1..semi];
+        let replacement = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") || entity.starts_with("#X") => {
+                u32::from_str_radix(&entity[2..], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            }
+            _ if entity.starts_with('#') => entity[1..].parse().ok().and_then(char::from_u32),
+            _ => None,
+        };
+        match replacement {
+            Some(c) => {
+                decoded.push(c);
+                rest = &after[semi + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &after[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn parse_xml_attrs(source: &str) -> HashMap<String, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = source.trim();
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(end) = after[1..].find(quote) else {
+            break;
+        };
+        attrs.insert(key, decode_xml_entities(&after[1..end + 1]));
+        rest = after[end + 2..].trim_start();
+    }
+    attrs
+}
+
+/// Minimal XML reader. Handles elements, attributes, text, comments, CDATA
+/// and processing instructions; namespaces are kept as part of the name.
+pub fn parse_xml(source: &str) -> Result<XmlElement, String> {
+    let mut stack: Vec<XmlElement> = vec![XmlElement::default()];
+    let mut rest = source;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&decode_xml_entities(rest));
+            }
+            break;
+        };
+        if lt > 0 {
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&decode_xml_entities(&rest[..lt]));
+            }
+        }
+        rest = &rest[lt..];
+
+        if let Some(body) = rest.strip_prefix("<!--") {
+            let end = body.find("-->").ok_or("Unterminated XML comment")?;
+            rest = &body[end + 3..];
+        } else if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").ok_or("Unterminated CDATA section")?;
+            if let Some(top) = stack.last_mut() {
+                top.text.push_str(&body[..end]);
+            }
+            rest = &body[end + 3..];
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            let end = rest.find('>').ok_or("Unterminated XML declaration")?;
+            rest = &rest[end + 1..];
+        } else if let Some(body) = rest.strip_prefix("</") {
+            let end = body.find('>').ok_or("Unterminated closing tag")?;
+            let name = body[..end].trim();
+            let element = stack.pop().ok_or("Unbalanced XML closing tag")?;
+            if element.name != name {
+                return Err(format!(
+                    "Mismatched XML closing tag: expected </{}>, found </{name}>",
+                    element.name
+                ));
+            }
+            stack
+                .last_mut()
+                .ok_or("Unbalanced XML closing tag")?
+                .children
+                .push(element);
+            rest = &body[end + 1..];
+        } else {
+            let end = rest.find('>').ok_or("Unterminated XML tag")?;
+            let inner = &rest[1..end];
+            let self_closing = inner.ends_with('/');
+            let inner = inner.trim_end_matches('/');
+            let (name, attrs) = match inner.find(char::is_whitespace) {
+                Some(split) => (&inner[..split], parse_xml_attrs(&inner[split..])),
+                None => (inner, HashMap::new()),
+            };
+            let element = XmlElement {
+                name: name.to_string(),
+                attrs,
+                ..Default::default()
+            };
+            if self_closing {
+                stack
+                    .last_mut()
+                    .ok_or("Malformed XML document")?
+                    .children
+                    .push(element);
+            } else {
+                stack.push(element);
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err("XML document ended with unclosed elements".to_string());
+    }
+    Ok(stack.pop().unwrap_or_default())
+}
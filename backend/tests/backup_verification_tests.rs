@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard};
+
+    use app_lib::commands::{
+        create_node, create_project, create_series, export_novel_package, get_projects_path,
+        save_scene, verify_backup, BackupVerification,
+    };
+
+    // BAA_DATA_CHANNEL is process-wide, so tests in this binary take turns.
+    static CHANNEL_LOCK: Mutex<()> = Mutex::new(());
+
+    struct TestChannelGuard {
+        previous_channel: Option<String>,
+        app_dir: PathBuf,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl TestChannelGuard {
+        fn new(prefix: &str) -> Self {
+            let lock = CHANNEL_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let previous_channel = env::var("BAA_DATA_CHANNEL").ok();
+            let channel = format!("{}-{}", prefix, uuid::Uuid::new_v4());
+            env::set_var("BAA_DATA_CHANNEL", channel);
+
+            let app_dir = app_lib::utils::get_app_dir().expect("resolve app dir for test channel");
+            let _ = fs::remove_dir_all(&app_dir);
+            fs::create_dir_all(&app_dir).expect("create isolated test app dir");
+
+            Self {
+                previous_channel,
+                app_dir,
+                _lock: lock,
+            }
+        }
+    }
+
+    impl Drop for TestChannelGuard {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.app_dir);
+            if let Some(previous) = &self.previous_channel {
+                env::set_var("BAA_DATA_CHANNEL", previous);
+            } else {
+                env::remove_var("BAA_DATA_CHANNEL");
+            }
+        }
+    }
+
+    /// Project with one saved scene: the project id, scene file name and scene path
+    fn project_with_scene() -> (String, String, PathBuf) {
+        let series = create_series("Verified Series".to_string(), None, None, None, None)
+            .expect("create series");
+        let project = create_project(
+            "Verified Novel".to_string(),
+            "Author".to_string(),
+            get_projects_path().expect("get projects path"),
+            series.id,
+            "Book 1".to_string(),
+        )
+        .expect("create project");
+        let scene = create_node(
+            project.path.clone(),
+            None,
+            "scene".to_string(),
+            "Opening".to_string(),
+            None,
+        )
+        .expect("create scene node");
+        let scene_file = scene.file.expect("scene node has a file");
+        save_scene(
+            project.path.clone(),
+            scene_file.clone(),
+            "The storm broke over the harbour.".to_string(),
+            None,
+            -1,
+            None,
+        )
+        .expect("save scene");
+
+        let scene_path = PathBuf::from(&project.path)
+            .join("manuscript")
+            .join(&scene_file);
+        (project.id, scene_file, scene_path)
+    }
+
+    fn package_and_verify(
+        guard: &TestChannelGuard,
+        project_id: &str,
+        name: &str,
+    ) -> BackupVerification {
+        let package_path = guard.app_dir.join(name);
+        export_novel_package(
+            project_id.to_string(),
+            Some(package_path.to_string_lossy().to_string()),
+        )
+        .expect("export novel package");
+        verify_backup(package_path.to_string_lossy().to_string()).expect("verify package")
+    }
+
+    #[test]
+    fn packaged_project_passes_verification() {
+        let guard = TestChannelGuard::new("verify-backup-ok-test");
+        let (project_id, _, _) = project_with_scene();
+
+        let report = package_and_verify(&guard, &project_id, "verified.baa");
+        assert!(report.passed, "unexpected problems: {:?}", report.problems);
+        assert!(report.checksum_verified);
+        assert!(report.schema_compatible);
+        assert_eq!(report.scenes_checked, 1);
+    }
+
+    #[test]
+    fn corrupt_scene_file_is_reported() {
+        let guard = TestChannelGuard::new("verify-backup-corrupt-test");
+        let (project_id, scene_file, scene_path) = project_with_scene();
+        fs::write(&scene_path, r#"{"type":"doc","content":["#).expect("corrupt scene file");
+
+        let report = package_and_verify(&guard, &project_id, "corrupt.baa");
+        assert!(!report.passed);
+        assert!(
+            report
+                .problems
+                .iter()
+                .any(|problem| problem.contains(&scene_file) && problem.contains("not valid JSON")),
+            "corrupt scene not reported: {:?}",
+            report.problems
+        );
+    }
+
+    #[test]
+    fn missing_scene_file_is_reported() {
+        let guard = TestChannelGuard::new("verify-backup-missing-test");
+        let (project_id, scene_file, scene_path) = project_with_scene();
+        fs::remove_file(&scene_path).expect("remove scene file");
+
+        let report = package_and_verify(&guard, &project_id, "missing.baa");
+        assert!(!report.passed);
+        assert!(
+            report
+                .problems
+                .iter()
+                .any(|problem| problem.contains(&scene_file) && problem.contains("missing")),
+            "missing scene not reported: {:?}",
+            report.problems
+        );
+    }
+}
@@ -3,19 +3,28 @@ mod tests {
     use std::env;
     use std::fs;
     use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard};
 
     use app_lib::commands::{
-        create_project, create_series, delete_project, delete_series, get_projects_path,
-        list_deleted_series, list_project_trash, list_series, restore_trashed_project,
+        create_project, create_series, delete_project, delete_series, export_novel_package,
+        get_projects_path, import_backup_package, list_deleted_series, list_project_trash,
+        list_projects, list_series, restore_trashed_project, BackupImportOptions,
     };
 
+    // BAA_DATA_CHANNEL is process-wide, so tests in this binary take turns.
+    static CHANNEL_LOCK: Mutex<()> = Mutex::new(());
+
     struct TestChannelGuard {
         previous_channel: Option<String>,
         app_dir: PathBuf,
+        _lock: MutexGuard<'static, ()>,
     }
 
     impl TestChannelGuard {
         fn new(prefix: &str) -> Self {
+            let lock = CHANNEL_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
             let previous_channel = env::var("BAA_DATA_CHANNEL").ok();
             let channel = format!("{}-{}", prefix, uuid::Uuid::new_v4());
             env::set_var("BAA_DATA_CHANNEL", channel);
@@ -27,6 +36,7 @@ mod tests {
             Self {
                 previous_channel,
                 app_dir,
+                _lock: lock,
             }
         }
     }
@@ -98,4 +108,49 @@ mod tests {
             "recovery series should not be created when deleted-series mapping exists"
         );
     }
+
+    #[test]
+    fn importing_novel_package_rejects_duplicate_book_number() {
+        let guard = TestChannelGuard::new("import-duplicate-index-test");
+
+        let series = create_series("Numbered Series".to_string(), None, None, None, None)
+            .expect("create series");
+        let projects_path = get_projects_path().expect("get projects path");
+        let project = create_project(
+            "Novel Two".to_string(),
+            "Author".to_string(),
+            projects_path,
+            series.id.clone(),
+            "Book 2".to_string(),
+        )
+        .expect("create project");
+
+        let package_path = guard.app_dir.join("novel-two.baa");
+        export_novel_package(
+            project.id.clone(),
+            Some(package_path.to_string_lossy().to_string()),
+        )
+        .expect("export novel package");
+
+        let error = import_backup_package(
+            package_path.to_string_lossy().to_string(),
+            Some(BackupImportOptions {
+                target_series_id: Some(series.id.clone()),
+                create_series_title: None,
+                series_index: None,
+            }),
+        )
+        .expect_err("importing a second Book 2 into the same series should fail");
+        assert!(
+            error.contains("already exists in this series"),
+            "unexpected error: {error}"
+        );
+
+        let projects = list_projects().expect("list projects");
+        assert_eq!(
+            projects.len(),
+            1,
+            "a rejected import must not leave a partial project behind"
+        );
+    }
 }
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard};
+
+    use app_lib::commands::{
+        create_node, create_project, create_series, get_projects_path, get_structure, load_scene,
+        save_scene, update_scene_metadata, SceneMetadataUpdates,
+    };
+
+    // BAA_DATA_CHANNEL is process-wide, so tests in this binary take turns.
+    static CHANNEL_LOCK: Mutex<()> = Mutex::new(());
+
+    struct TestChannelGuard {
+        previous_channel: Option<String>,
+        app_dir: PathBuf,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl TestChannelGuard {
+        fn new(prefix: &str) -> Self {
+            let lock = CHANNEL_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let previous_channel = env::var("BAA_DATA_CHANNEL").ok();
+            let channel = format!("{}-{}", prefix, uuid::Uuid::new_v4());
+            env::set_var("BAA_DATA_CHANNEL", channel);
+
+            let app_dir = app_lib::utils::get_app_dir().expect("resolve app dir for test channel");
+            let _ = fs::remove_dir_all(&app_dir);
+            fs::create_dir_all(&app_dir).expect("create isolated test app dir");
+
+            Self {
+                previous_channel,
+                app_dir,
+                _lock: lock,
+            }
+        }
+    }
+
+    impl Drop for TestChannelGuard {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.app_dir);
+            if let Some(previous) = &self.previous_channel {
+                env::set_var("BAA_DATA_CHANNEL", previous);
+            } else {
+                env::remove_var("BAA_DATA_CHANNEL");
+            }
+        }
+    }
+
+    fn project_with_scene(title: &str) -> (String, String) {
+        let series = create_series("Metadata Series".to_string(), None, None, None, None)
+            .expect("create series");
+        let project = create_project(
+            "Metadata Novel".to_string(),
+            "Author".to_string(),
+            get_projects_path().expect("get projects path"),
+            series.id,
+            "Book 1".to_string(),
+        )
+        .expect("create project");
+        let scene = create_node(
+            project.path.clone(),
+            None,
+            "scene".to_string(),
+            title.to_string(),
+            None,
+        )
+        .expect("create scene node");
+        (project.path, scene.file.expect("scene node has a file"))
+    }
+
+    #[test]
+    fn save_scene_keeps_colon_titles_and_existing_metadata() {
+        let _guard = TestChannelGuard::new("scene-meta-colon-test");
+        let (project_path, scene_file) = project_with_scene("Draft");
+
+        update_scene_metadata(
+            project_path.clone(),
+            scene_file.clone(),
+            SceneMetadataUpdates {
+                pov: Some("Reyes".to_string()),
+                labels: Some(vec!["flashback".to_string()]),
+                ..Default::default()
+            },
+        )
+        .expect("update scene metadata");
+
+        save_scene(
+            project_path.clone(),
+            scene_file.clone(),
+            "Body text".to_string(),
+            Some("Chapter 3: The Fall".to_string()),
+            -1,
+            None,
+        )
+        .expect("save scene");
+
+        let loaded = load_scene(project_path, scene_file).expect("load scene");
+        assert_eq!(loaded.meta.title, "Chapter 3: The Fall");
+        assert_eq!(loaded.meta.pov_character.as_deref(), Some("Reyes"));
+        assert_eq!(loaded.meta.labels, vec!["flashback".to_string()]);
+        assert_eq!(loaded.content, "Body text");
+    }
+
+    #[test]
+    fn titles_with_quotes_and_backslashes_round_trip() {
+        let _guard = TestChannelGuard::new("scene-meta-escape-test");
+        let title = r#"The "Best" Day \ Ever"#;
+        let (project_path, scene_file) = project_with_scene(title);
+
+        let created = load_scene(project_path.clone(), scene_file.clone()).expect("load scene");
+        assert_eq!(created.meta.title, title);
+
+        save_scene(
+            project_path.clone(),
+            scene_file.clone(),
+            "Body".to_string(),
+            Some(title.to_string()),
+            -1,
+            None,
+        )
+        .expect("save scene");
+
+        let structure = get_structure(project_path.clone()).expect("get structure");
+        assert_eq!(structure[0].title, title);
+
+        let loaded = load_scene(project_path, scene_file).expect("reload scene");
+        assert_eq!(loaded.meta.title, title);
+    }
+}
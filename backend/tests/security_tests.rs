@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use app_lib::commands::scene;
+    use app_lib::utils::path_sanitization::{sanitize_path_component, validate_project_path};
     use app_lib::utils::validate_project_title;
 
     #[test]
@@ -10,6 +11,16 @@ mod tests {
         assert!(validate_project_title("/root").is_err());
     }
 
+    #[test]
+    fn test_path_sanitization_rejects_traversal() {
+        assert!(sanitize_path_component("..").is_err());
+        assert!(sanitize_path_component("../escape.md").is_err());
+        assert!(sanitize_path_component("/etc/passwd").is_err());
+        assert!(sanitize_path_component("chapter-1.md").is_ok());
+        assert!(validate_project_path("/tmp/../etc").is_err());
+        assert!(validate_project_path("relative/project").is_err());
+    }
+
     #[test]
     fn test_scene_commands_reject_path_traversal_filenames() {
         let result = scene::save_scene(
@@ -18,6 +29,7 @@ mod tests {
             "content".to_string(),
             None,
             0,
+            None,
         );
         assert!(result.is_err());
     }
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::{Mutex, MutexGuard};
+
+    use app_lib::commands::{
+        create_series, delete_series_codex_entry, list_series_codex_relations,
+        save_series_codex_entry, save_series_codex_relation,
+    };
+    use app_lib::models::{CodexEntry, CodexRelation};
+
+    // BAA_DATA_CHANNEL is process-wide, so tests in this binary take turns.
+    static CHANNEL_LOCK: Mutex<()> = Mutex::new(());
+
+    struct TestChannelGuard {
+        previous_channel: Option<String>,
+        app_dir: PathBuf,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl TestChannelGuard {
+        fn new(prefix: &str) -> Self {
+            let lock = CHANNEL_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let previous_channel = env::var("BAA_DATA_CHANNEL").ok();
+            let channel = format!("{}-{}", prefix, uuid::Uuid::new_v4());
+            env::set_var("BAA_DATA_CHANNEL", channel);
+
+            let app_dir = app_lib::utils::get_app_dir().expect("resolve app dir for test channel");
+            let _ = fs::remove_dir_all(&app_dir);
+            fs::create_dir_all(&app_dir).expect("create isolated test app dir");
+
+            Self {
+                previous_channel,
+                app_dir,
+                _lock: lock,
+            }
+        }
+    }
+
+    impl Drop for TestChannelGuard {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.app_dir);
+            if let Some(previous) = &self.previous_channel {
+                env::set_var("BAA_DATA_CHANNEL", previous);
+            } else {
+                env::remove_var("BAA_DATA_CHANNEL");
+            }
+        }
+    }
+
+    fn codex_entry(id: &str, name: &str) -> CodexEntry {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "category": "character",
+            "createdAt": 1,
+            "updatedAt": 1
+        }))
+        .expect("build codex entry")
+    }
+
+    fn codex_relation(id: &str, parent_id: &str, child_id: &str) -> CodexRelation {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "parentId": parent_id,
+            "childId": child_id,
+            "createdAt": 1,
+            "updatedAt": 1
+        }))
+        .expect("build codex relation")
+    }
+
+    #[test]
+    fn delete_series_codex_entry_removes_relations_referencing_it() {
+        let _guard = TestChannelGuard::new("series-codex-cascade-test");
+
+        let series = create_series("Cascade Series".to_string(), None, None, None, None)
+            .expect("create series");
+
+        save_series_codex_entry(series.id.clone(), codex_entry("reyes", "Captain Reyes"))
+            .expect("save first entry");
+        save_series_codex_entry(series.id.clone(), codex_entry("vega", "Lt. Vega"))
+            .expect("save second entry");
+        save_series_codex_entry(series.id.clone(), codex_entry("orin", "Orin"))
+            .expect("save third entry");
+
+        save_series_codex_relation(series.id.clone(), codex_relation("r1", "reyes", "vega"))
+            .expect("save parent relation");
+        save_series_codex_relation(series.id.clone(), codex_relation("r2", "orin", "reyes"))
+            .expect("save child relation");
+        save_series_codex_relation(series.id.clone(), codex_relation("r3", "vega", "orin"))
+            .expect("save unrelated relation");
+
+        delete_series_codex_entry(
+            series.id.clone(),
+            "reyes".to_string(),
+            "character".to_string(),
+        )
+        .expect("delete series codex entry");
+
+        let remaining = list_series_codex_relations(series.id.clone()).expect("list relations");
+        let remaining_ids: Vec<&str> = remaining.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(
+            remaining_ids,
+            vec!["r3"],
+            "only relations not touching the deleted entry should remain"
+        );
+    }
+}
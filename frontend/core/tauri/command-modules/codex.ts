@@ -16,6 +16,51 @@ export async function listCodexEntries(
   return invoke<CodexEntry[]>("list_codex_entries", { projectPath, category });
 }
 
+export interface CodexEntrySummary {
+  id: string;
+  name: string;
+  category: string;
+  thumbnail: string | null;
+  completeness: number | null;
+}
+
+export async function listCodexSummaries(
+  projectPathOrSeriesId: string,
+): Promise<CodexEntrySummary[]> {
+  return invoke<CodexEntrySummary[]>("list_codex_summaries", {
+    projectPathOrSeriesId,
+  });
+}
+
+export interface CodexChanges {
+  entries: CodexEntry[];
+  /** Every entry id the series still has; cached ids missing here were deleted */
+  entryIds: string[];
+}
+
+export async function listCodexEntriesSince(
+  projectPathOrSeriesId: string,
+  sinceMillis: number,
+): Promise<CodexChanges> {
+  return invoke<CodexChanges>("list_codex_entries_since", {
+    projectPathOrSeriesId,
+    sinceMillis,
+  });
+}
+
+export interface CodexStamp {
+  entryCount: number;
+  lastModified: number | null;
+}
+
+export async function getCodexLastModified(
+  projectPathOrSeriesId: string,
+): Promise<CodexStamp> {
+  return invoke<CodexStamp>("get_codex_last_modified", {
+    projectPathOrSeriesId,
+  });
+}
+
 export async function saveCodexEntry(
   projectPath: string,
   entry: CodexEntry,
@@ -5,6 +5,7 @@ import type {
   BackupImportResult,
   BackupPackageInfo,
   BackupPackageSummary,
+  BatchImportItem,
 } from "./types";
 
 export async function exportFullSnapshot(
@@ -51,6 +52,13 @@ export async function importBackupPackage(
   });
 }
 
+export async function importBackups(
+  paths: string[],
+  seriesId: string,
+): Promise<BatchImportItem[]> {
+  return invoke<BatchImportItem[]>("import_backups", { paths, seriesId });
+}
+
 export async function readFileBytes(filePath: string): Promise<number[]> {
   return invoke<number[]>("read_file_bytes", { filePath });
 }
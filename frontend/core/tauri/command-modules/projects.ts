@@ -30,8 +30,16 @@ export async function createProject(
   });
 }
 
-export async function deleteProject(projectPath: string): Promise<void> {
-  return invoke("delete_project", { projectPath });
+export interface ProjectDeletion {
+  seriesId: string;
+  /** True when the deleted project was the last one in its series */
+  seriesEmpty: boolean;
+}
+
+export async function deleteProject(
+  projectPath: string,
+): Promise<ProjectDeletion> {
+  return invoke<ProjectDeletion>("delete_project", { projectPath });
 }
 
 export async function listProjectTrash(): Promise<TrashedProject[]> {
@@ -65,8 +73,23 @@ export async function removeFromRecent(projectPath: string): Promise<void> {
   return invoke("remove_from_recent", { projectPath });
 }
 
-export async function openProject(projectPath: string): Promise<ProjectMeta> {
-  return invoke<ProjectMeta>("open_project", { projectPath });
+export async function rebuildRecentProjects(): Promise<number> {
+  return invoke<number>("rebuild_recent_projects");
+}
+
+export interface ProjectLockWarning {
+  lock: { sessionId: string; host: string; pid: number; acquiredAt: number };
+  message: string;
+}
+
+export type OpenedProject = ProjectMeta & { lockWarning?: ProjectLockWarning };
+
+export async function openProject(projectPath: string): Promise<OpenedProject> {
+  return invoke<OpenedProject>("open_project", { projectPath });
+}
+
+export async function releaseProjectLock(projectPath: string): Promise<boolean> {
+  return invoke<boolean>("release_project_lock", { projectPath });
 }
 
 export async function showOpenProjectDialog(): Promise<string | null> {
@@ -14,6 +14,22 @@ export interface SceneMetadataUpdates {
   archived?: boolean;
 }
 
+export interface SceneMetaUpdates {
+  title?: string;
+  status?: string;
+  povCharacter?: string;
+  order?: number;
+}
+
+export interface TextStats {
+  words: number;
+  characters: number;
+  charactersNoSpaces: number;
+  sentences: number;
+  paragraphs: number;
+  readingMinutes: number;
+}
+
 export async function loadScene(
   projectPath: string,
   sceneFile: string,
@@ -21,13 +37,16 @@ export async function loadScene(
   return invoke<LoadedSceneDto>("load_scene", { projectPath, sceneFile });
 }
 
+/** `save_scene` result: metadata plus words added (negative if removed) */
+export type SavedSceneMeta = SceneMeta & { word_delta: number };
+
 export async function saveScene(
   projectPath: string,
   sceneFile: string,
   content: TiptapContent,
   title?: string,
   wordCount: number = -1,
-): Promise<SceneMeta> {
+): Promise<SavedSceneMeta> {
   if (!content || typeof content !== "object" || !("type" in content)) {
     throw new Error("Invalid Tiptap content structure");
   }
@@ -35,7 +54,7 @@ export async function saveScene(
   // C-3 fix: Rust save_scene expects content as a JSON String, not an object.
   // C-4 fix: Rust save_scene requires word_count: i32; pass -1 to let Rust
   //          resolve the word count from the serialised content itself.
-  return invoke<SceneMeta>("save_scene", {
+  return invoke<SavedSceneMeta>("save_scene", {
     projectPath,
     sceneFile,
     content: JSON.stringify(content),
@@ -56,6 +75,18 @@ export async function updateSceneMetadata(
   });
 }
 
+export async function updateSceneMeta(
+  projectPath: string,
+  sceneId: string,
+  updates: SceneMetaUpdates,
+): Promise<SceneMeta> {
+  return invoke<SceneMeta>("update_scene_meta", {
+    projectPath,
+    sceneId,
+    updates,
+  });
+}
+
 export async function deleteScene(
   projectPath: string,
   sceneFile: string,
@@ -77,6 +108,31 @@ export async function saveSceneById(
   });
 }
 
+export interface DebouncedSave {
+  sceneId: string;
+  writeDueAt: number;
+}
+
+export async function saveSceneDebounced(
+  projectPath: string,
+  sceneId: string,
+  content: string,
+  wordCount: number,
+  debounceMs: number,
+): Promise<DebouncedSave> {
+  return invoke<DebouncedSave>("save_scene_debounced", {
+    projectPath,
+    sceneId,
+    content,
+    wordCount,
+    debounceMs,
+  });
+}
+
+export async function flushSceneSaves(projectPath?: string): Promise<number> {
+  return invoke<number>("flush_scene_saves", { projectPath: projectPath ?? null });
+}
+
 export async function getSceneNote(
   projectPath: string,
   sceneId: string,
@@ -104,3 +160,48 @@ export async function deleteYjsState(
 ): Promise<void> {
   return invoke("delete_yjs_state", { projectPath, sceneId });
 }
+
+// Counted by the backend so the live counter matches the stored word count.
+function textForCounting(text: string | TiptapContent): string {
+  return typeof text === "string" ? text : JSON.stringify(text);
+}
+
+export async function countTextWords(
+  text: string | TiptapContent,
+): Promise<number> {
+  return invoke<number>("count_text_words", { text: textForCounting(text) });
+}
+
+export async function countTextStats(
+  text: string | TiptapContent,
+): Promise<TextStats> {
+  return invoke<TextStats>("count_text_stats", {
+    text: textForCounting(text),
+  });
+}
+
+export interface WordCountDrift {
+  sceneId: string;
+  sceneFile: string;
+  title: string;
+  storedWordCount: number;
+  actualWordCount: number;
+  /** Set when the scene file couldn't be read; the count is left as stored */
+  error?: string;
+}
+
+export async function auditWordCounts(
+  projectPath: string,
+): Promise<WordCountDrift[]> {
+  return invoke<WordCountDrift[]>("audit_word_counts", { projectPath });
+}
+
+export async function recountWordCounts(
+  projectPath: string,
+  sceneIds?: string[],
+): Promise<WordCountDrift[]> {
+  return invoke<WordCountDrift[]>("recount_word_counts", {
+    projectPath,
+    sceneIds: sceneIds ?? null,
+  });
+}
@@ -4,7 +4,7 @@ import type {
   CodexRelation,
   Series,
 } from "@/domain/entities/types";
-import type { DeletedSeriesMeta } from "./types";
+import type { CodexCategoryDefinition, DeletedSeriesMeta } from "./types";
 
 export async function listSeries(): Promise<Series[]> {
   return invoke<Series[]>("list_series");
@@ -37,6 +37,30 @@ export async function deleteSeriesCascade(seriesId: string): Promise<number> {
   return invoke<number>("delete_series_cascade", { seriesId });
 }
 
+export async function isSeriesEmpty(seriesId: string): Promise<boolean> {
+  return invoke<boolean>("is_series_empty", { seriesId });
+}
+
+export async function listEmptySeries(): Promise<Series[]> {
+  return invoke<Series[]>("list_empty_series");
+}
+
+export async function getCodexCategories(
+  seriesId: string,
+): Promise<CodexCategoryDefinition[]> {
+  return invoke<CodexCategoryDefinition[]>("get_codex_categories", { seriesId });
+}
+
+export async function setCodexCategories(
+  seriesId: string,
+  categories: CodexCategoryDefinition[],
+): Promise<CodexCategoryDefinition[]> {
+  return invoke<CodexCategoryDefinition[]>("set_codex_categories", {
+    seriesId,
+    categories,
+  });
+}
+
 export async function listDeletedSeries(): Promise<DeletedSeriesMeta[]> {
   return invoke<DeletedSeriesMeta[]>("list_deleted_series");
 }
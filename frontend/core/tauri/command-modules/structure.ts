@@ -14,17 +14,33 @@ export async function saveStructure(
   return invoke("save_structure", { projectPath, structure });
 }
 
+export type StructureOperation =
+  | { op: "move"; nodeId: string; parentId: string | null; index: number }
+  | { op: "rename"; nodeId: string; title: string };
+
+export async function applyStructureOperations(
+  projectPath: string,
+  operations: StructureOperation[],
+): Promise<StructureNode[]> {
+  return invoke<StructureNode[]>("apply_structure_operations", {
+    projectPath,
+    operations,
+  });
+}
+
 export async function createNode(
   projectPath: string,
   parentId: string | null,
   nodeType: string,
   title: string,
+  templateId?: string,
 ): Promise<StructureNode> {
   return invoke<StructureNode>("create_node", {
     projectPath,
     parentId,
     nodeType,
     title,
+    templateId,
   });
 }
 
@@ -21,6 +21,12 @@ export interface RecentProject {
 
 export type { TrashedProject } from "@/shared/types/backup";
 
+export interface CodexCategoryDefinition {
+  id: string;
+  name: string;
+  icon?: string | null;
+}
+
 export interface DeletedSeriesMeta {
   oldSeriesId: string;
   title: string;
@@ -102,6 +108,13 @@ export interface AppInfo {
   version: string;
   platform: string;
   arch: string;
+  tauriVersion?: string;
+  appDataDir?: string | null;
+  projectCount?: number | null;
+  seriesCount?: number | null;
+  availableDiskBytes?: number | null;
+  keychainAvailable?: boolean;
+  secretStorage?: string;
 }
 
 export type {
@@ -112,6 +125,7 @@ export type {
   BackupPackageInfo,
   BackupImportOptions,
   BackupImportResult,
+  BatchImportItem,
 } from "@/shared/types/backup";
 
 export interface SaveDialogOptions {
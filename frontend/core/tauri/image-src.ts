@@ -0,0 +1,15 @@
+import { convertFileSrc } from "@tauri-apps/api/core";
+
+const INLINE_IMAGE_SOURCE = /^(data:|blob:|asset:|https?:)/i;
+
+/**
+ * `src` for a stored image reference. Codex images are saved as files, so
+ * local paths are served through the asset protocol; data and web URLs are
+ * used as-is.
+ */
+export function imageSrc(reference: string): string {
+  if (INLINE_IMAGE_SOURCE.test(reference)) {
+    return reference;
+  }
+  return convertFileSrc(reference);
+}
@@ -5,3 +5,4 @@
 
 export * from "./commands";
 export { TauriNotAvailableError } from "./invoke";
+export { imageSrc } from "./image-src";
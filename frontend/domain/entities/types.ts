@@ -90,7 +90,7 @@ export interface CodexEntry {
   tags: string[];
   references: string[];
   image?: string;
-  thumbnail?: string; // Base64 image for entry thumbnail
+  thumbnail?: string; // Image file path (a data URL until first saved)
   customDetails?: Record<string, unknown>; // User-defined fields
   aiContext?: AIContext; // AI inclusion setting
   trackMentions?: boolean; // Track mentions in manuscript
@@ -147,6 +147,7 @@ export interface Series {
   author?: string;
   genre?: string;
   status?: "planned" | "in-progress" | "completed" | "hiatus";
+  coverImage?: string; // Base64 or URL
   createdAt: number;
   updatedAt: number;
 }
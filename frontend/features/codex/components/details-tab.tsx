@@ -13,6 +13,7 @@ import {
 import { Sparkles, Plus, Trash2 } from "lucide-react";
 import { useAI } from "@/hooks/use-ai";
 import { toast } from "@/shared/utils/toast-service";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface DetailsTabProps {
   entity: Partial<CodexEntry>;
@@ -134,7 +135,7 @@ Generate now for: ${entity.name} (${entity.category})`;
           <div className="h-20 w-20 bg-muted rounded-lg flex items-center justify-center overflow-hidden border">
             {entity.thumbnail ? (
               <img
-                src={entity.thumbnail}
+                src={imageSrc(entity.thumbnail)}
                 alt="Thumbnail"
                 className="h-full w-full object-cover"
               />
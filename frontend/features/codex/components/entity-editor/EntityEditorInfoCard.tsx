@@ -11,6 +11,7 @@ import {
 import { Button } from "@/components/ui/button";
 import { User } from "lucide-react";
 import { CodexEntry, CodexCategory } from "@/domain/entities/types";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface EntityEditorInfoCardProps {
   formData: Partial<CodexEntry>;
@@ -40,7 +41,7 @@ export function EntityEditorInfoCard({
         <div className="h-16 w-16 bg-muted rounded-lg flex items-center justify-center flex-shrink-0">
           {formData.thumbnail ? (
             <img
-              src={formData.thumbnail}
+              src={imageSrc(formData.thumbnail)}
               alt={formData.name}
               className="h-full w-full object-cover rounded-lg"
             />
@@ -21,6 +21,7 @@ import {
 import { TippyPopover } from "@/components/ui/tippy-popover";
 import { Input } from "@/components/ui/input";
 import { ScrollArea } from "@/components/ui/scroll-area";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface CodexFilterBarProps {
   seriesId: string;
@@ -178,7 +179,7 @@ export function CodexFilterBar({
                     >
                       {entry.thumbnail ? (
                         <img
-                          src={entry.thumbnail}
+                          src={imageSrc(entry.thumbnail)}
                           alt=""
                           className="h-5 w-5 rounded-full object-cover"
                         />
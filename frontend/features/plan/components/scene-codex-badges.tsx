@@ -9,6 +9,7 @@ import {
   TooltipContent,
   TooltipTrigger,
 } from "@/components/ui/tooltip";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface SceneCodexBadgesProps {
   sceneId: string;
@@ -135,7 +136,7 @@ export function SceneCodexBadges({
               >
                 {entry.thumbnail ? (
                   <img
-                    src={entry.thumbnail}
+                    src={imageSrc(entry.thumbnail)}
                     alt={entry.name}
                     className="h-full w-full rounded-full object-cover"
                   />
@@ -41,6 +41,7 @@ import {
   extractSceneTextForIntelligence,
   getSceneSectionWarnings,
 } from "@/shared/utils/scene-sections";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface SceneLinkPanelProps {
   sceneId: string;
@@ -661,7 +662,7 @@ export function SceneLinkPanel({
                       >
                         {entry.thumbnail ? (
                           <img
-                            src={entry.thumbnail}
+                            src={imageSrc(entry.thumbnail)}
                             alt=""
                             className="h-full w-full rounded-full object-cover"
                           />
@@ -772,7 +773,7 @@ export function SceneLinkPanel({
                             >
                               {entry.thumbnail ? (
                                 <img
-                                  src={entry.thumbnail}
+                                  src={imageSrc(entry.thumbnail)}
                                   alt=""
                                   className="h-full w-full rounded-full object-cover"
                                 />
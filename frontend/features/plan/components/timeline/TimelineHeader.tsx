@@ -2,6 +2,7 @@
 
 import type { CodexEntry } from "@/domain/entities/types";
 import { CATEGORY_CONFIG } from "../../utils/timeline-utils";
+import { imageSrc } from "@/core/tauri/image-src";
 
 interface TimelineHeaderProps {
   lanes: CodexEntry[];
@@ -41,7 +42,7 @@ export function TimelineHeader({
               >
                 {lane.thumbnail ? (
                   <img
-                    src={lane.thumbnail}
+                    src={imageSrc(lane.thumbnail)}
                     alt=""
                     className="h-full w-full rounded-full object-cover"
                   />
@@ -230,7 +230,11 @@ describe("EmergencyBackupService Contract", () => {
 
   describe("Cleanup Expired - SPECIFICATION: Auto-purge after 24 hours", () => {
     it("MUST call Tauri cleanup command", async () => {
-      vi.mocked(invoke).mockResolvedValue(5);
+      vi.mocked(invoke).mockResolvedValue({
+        removedCount: 5,
+        bytesFreed: 20480,
+        remainingCount: 2,
+      });
 
       const count = await service.cleanupExpired();
 
@@ -239,7 +243,11 @@ describe("EmergencyBackupService Contract", () => {
     });
 
     it("MUST return 0 when backend reports no expired backups", async () => {
-      vi.mocked(invoke).mockResolvedValue(0);
+      vi.mocked(invoke).mockResolvedValue({
+        removedCount: 0,
+        bytesFreed: 0,
+        remainingCount: 3,
+      });
 
       const count = await service.cleanupExpired();
 
@@ -24,6 +24,12 @@ interface EmergencyBackup {
   expiresAt: number;
 }
 
+interface EmergencyBackupCleanup {
+  removedCount: number;
+  bytesFreed: number;
+  remainingCount: number;
+}
+
 export class EmergencyBackupService {
   /**
    * Save emergency backup to filesystem
@@ -95,11 +101,14 @@ export class EmergencyBackupService {
    */
   async cleanupExpired(): Promise<number> {
     try {
-      const count = await invoke<number>("cleanup_emergency_backups");
-      if (count > 0) {
-        log.debug(`Cleaned up ${count} expired emergency backups`);
+      const { removedCount, bytesFreed } =
+        await invoke<EmergencyBackupCleanup>("cleanup_emergency_backups");
+      if (removedCount > 0) {
+        log.debug(
+          `Cleaned up ${removedCount} expired emergency backups (${bytesFreed} bytes)`,
+        );
       }
-      return count;
+      return removedCount;
     } catch (error) {
       log.error("Failed to cleanup expired backups:", error);
       return 0;
@@ -22,7 +22,9 @@ class SaveCoordinator {
    * Ensures saves are serialized per scene to prevent race conditions
    * @param sceneId - Scene ID
    * @param getContent - Function to get current editor content
-   * @param wordCount - Word count from Tiptap CharacterCount (source of truth)
+   * @param wordCount - Word count from Tiptap CharacterCount, for logging only;
+   *   the backend counts the saved content itself so stored counts agree
+   *   with audit_word_counts
    */
   async scheduleSave(
     sceneId: string,
@@ -82,7 +84,8 @@ class SaveCoordinator {
           typeof cleanContent === "string"
             ? cleanContent
             : JSON.stringify(cleanContent),
-          wordCount,
+          // -1: let Rust count words with the same counter as the audit
+          -1,
         );
         log.debug(`✅ Save successful for scene: ${sceneId}`);
 
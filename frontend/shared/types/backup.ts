@@ -65,6 +65,8 @@ export interface BackupPackageInfo {
 export interface BackupImportOptions {
   targetSeriesId?: string;
   createSeriesTitle?: string;
+  /** Overrides the book number stored in a novel package */
+  seriesIndex?: string;
 }
 
 export interface BackupImportResult {
@@ -75,3 +77,11 @@ export interface BackupImportResult {
   checkpointPath?: string | null;
   requiresRelaunch: boolean;
 }
+
+export interface BatchImportItem {
+  path: string;
+  success: boolean;
+  projectId?: string | null;
+  seriesIndex?: string | null;
+  error?: string | null;
+}